@@ -542,6 +542,7 @@ export declare enum BuiltinPluginName {
   EsmLibraryPlugin = 'EsmLibraryPlugin',
   HotModuleReplacementPlugin = 'HotModuleReplacementPlugin',
   LimitChunkCountPlugin = 'LimitChunkCountPlugin',
+  AggressiveSplittingPlugin = 'AggressiveSplittingPlugin',
   WorkerPlugin = 'WorkerPlugin',
   WebWorkerTemplatePlugin = 'WebWorkerTemplatePlugin',
   MergeDuplicateChunksPlugin = 'MergeDuplicateChunksPlugin',
@@ -564,6 +565,8 @@ export declare enum BuiltinPluginName {
   NamedChunkIdsPlugin = 'NamedChunkIdsPlugin',
   DeterministicChunkIdsPlugin = 'DeterministicChunkIdsPlugin',
   OccurrenceChunkIdsPlugin = 'OccurrenceChunkIdsPlugin',
+  HashedModuleIdsPlugin = 'HashedModuleIdsPlugin',
+  RecordIdsPlugin = 'RecordIdsPlugin',
   RealContentHashPlugin = 'RealContentHashPlugin',
   RemoveEmptyChunksPlugin = 'RemoveEmptyChunksPlugin',
   EnsureChunkConditionsPlugin = 'EnsureChunkConditionsPlugin',
@@ -1379,6 +1382,7 @@ export interface JsStatsCompilation {
   hash?: string
   modules?: Array<JsStatsModule>
   namedChunkGroups?: Array<JsStatsChunkGroup>
+  profile?: JsStatsProfile
   warnings: Array<JsStatsError>
 }
 
@@ -1403,6 +1407,12 @@ export interface JsStatsGetAssets {
   assetsByChunkName: Array<JsStatsAssetsByChunkName>
 }
 
+export interface JsStatsLoaderProfile {
+  loader: string
+  pitch: number
+  normal: number
+}
+
 export interface JsStatsLogging {
   name: string
   type: string
@@ -1417,6 +1427,7 @@ export interface JsStatsModule {
   issuerPath?: Array<JsStatsModuleIssuer>
   usedExports?: string | Array<string>
   modules?: Array<JsStatsModule>
+  profile?: JsStatsModuleProfile
 }
 
 export interface JsStatsModuleCommonAttributes {
@@ -1453,8 +1464,14 @@ export interface JsStatsModuleIssuer {
 }
 
 export interface JsStatsModuleProfile {
+  total: number
+  resolving: number
   factory: number
   building: number
+  loaders: Array<JsStatsLoaderProfile>
+  parsing: number
+  codeGeneration: number
+  hashing: number
 }
 
 export interface JsStatsModuleReason {
@@ -1504,6 +1521,7 @@ export interface JsStatsOptions {
   moduleAssets: boolean
   nestedModules: boolean
   optimizationBailout: boolean
+  profile: boolean
   providedExports: boolean
   reasons: boolean
   source: boolean
@@ -1511,6 +1529,17 @@ export interface JsStatsOptions {
   warnings: boolean
 }
 
+export interface JsStatsProfile {
+  modules: Array<JsStatsProfileModule>
+  loaders: Array<JsStatsLoaderProfile>
+}
+
+export interface JsStatsProfileModule {
+  identifier: string
+  name: string
+  profile: JsStatsModuleProfile
+}
+
 export interface JsStatsSize {
   sourceType: string
   size: number
@@ -1751,6 +1780,10 @@ export interface NativeWatcherOptions {
   followSymlinks?: boolean
   pollInterval?: number
   aggregateTimeout?: number
+  /** How the watcher detects changes, defaults to "native". */
+  mode?: "native" | "polling" | "hybrid"
+  /** Log the statistics of each event batch and the paths skipped by `ignored`. */
+  debug?: boolean
   /**
    * The ignored paths for the watcher.
    * It can be a single path, an array of paths, or a regular expression.
@@ -1775,6 +1808,12 @@ export interface PathWithInfo {
   info: AssetInfo
 }
 
+export interface RawAggressiveSplittingPluginOptions {
+  minSize?: number | RawSplitChunkSizes
+  maxSize?: number | RawSplitChunkSizes
+  defaultSizeTypes: Array<string>
+}
+
 export interface RawAliasOptionItem {
   path: string
   redirect: Array<string | false>
@@ -1827,6 +1866,11 @@ export interface RawAssetResourceGeneratorOptions {
   binary?: boolean
 }
 
+export interface RawAssetSizeLimit {
+  test: string
+  maxSize: number
+}
+
 export interface RawBannerPluginOptions {
   banner: string | ((args: { hash: string, chunk: Chunk, filename: string }) => string)
   entryOnly?: boolean
@@ -1880,12 +1924,18 @@ export interface RawCacheOptionsPersistent {
   storage?: RawStorageOptions
   portable?: boolean
   readonly?: boolean
+  explain?: boolean
 }
 
 export interface RawCircularDependencyRspackPluginOptions {
   failOnError?: boolean
   exclude?: RegExp
   ignoredConnections?: Array<[string | RegExp, string | RegExp]>
+  mode?: 'cycles' | 'components'
+  initOnly?: boolean
+  maxCycles?: number
+  baselinePath?: string
+  updateBaseline?: boolean
   onDetected?: (entrypoint: Module, modules: string[]) => void
   onIgnored?: (entrypoint: Module, modules: string[]) => void
   onStart?: () => void
@@ -2050,7 +2100,14 @@ export interface RawCopyPattern {
    * Allows to modify the file contents.
    * @default undefined
    */
-  transform?: { transformer: (input: Buffer, absoluteFilename: string) => string | Buffer | Promise<string> | Promise<Buffer>  } | ((input: Buffer, absoluteFilename: string) => string | Buffer | Promise<string> | Promise<Buffer>)
+  transform?: { transformer: (input: Buffer, absoluteFilename: string) => string | Buffer | Promise<string> | Promise<Buffer>; cache?: boolean } | ((input: Buffer, absoluteFilename: string) => string | Buffer | Promise<string> | Promise<Buffer>)
+  /**
+   * Whether to reuse the results of the unchanged files on rebuild. The results of
+   * `transform` are only reused when this is `true`, as the transformer may depend on
+   * other inputs than the file content.
+   * @default `true` without `transform`, `false` otherwise
+   */
+  cache?: boolean
 }
 
 export interface RawCopyRspackPluginOptions {
@@ -2272,6 +2329,26 @@ export interface RawGeneratorOptions {
   json?: RawJsonGeneratorOptions
 }
 
+export interface RawHashedModuleIdsPluginOptions {
+  context?: string
+  hashFunction?: string
+  hashDigest?: string
+  hashDigestLength?: number
+}
+
+export interface RawHtmlInlineOptions {
+  runtimeChunk?: boolean
+  cssSizeLimit?: number
+  test?: string | RegExp | (string | RegExp)[]
+}
+
+export interface RawHtmlResourceHintsOptions {
+  preload?: boolean
+  prefetch?: boolean
+  include?: string | RegExp | (string | RegExp)[]
+  exclude?: string | RegExp | (string | RegExp)[]
+}
+
 export interface RawHtmlRspackPluginBaseOptions {
   href?: string
   target?: "_self" | "_blank" | "_parent" | "_top"
@@ -2294,7 +2371,9 @@ export interface RawHtmlRspackPluginOptions {
   /** entry_chunk_name (only entry chunks are supported) */
   chunks?: Array<string>
   excludeChunks?: Array<string>
-  chunksSortMode: "auto" | "manual"
+  chunksSortMode: "auto" | "manual" | "dependency" | ((chunkNames: string[]) => string[])
+  resourceHints?: RawHtmlResourceHintsOptions
+  inline?: RawHtmlInlineOptions
   minify?: boolean
   title?: string
   favicon?: string
@@ -2669,6 +2748,7 @@ incremental?: false | { [key: string]: boolean }
 node?: RawNodeOption
 amd?: string
 bail: boolean
+profile: boolean
 __references: Record<string, any>
 __virtual_files?: Array<JsVirtualFile>
 }
@@ -2762,6 +2842,11 @@ export interface RawProvideOptions {
   treeShakingMode?: string
 }
 
+export interface RawRecordIdsPluginOptions {
+  inputPath?: string
+  outputPath?: string
+}
+
 export interface RawRelated {
   sourceMap?: string
 }
@@ -2916,6 +3001,9 @@ export interface RawSizeLimitsPluginOptions {
   hints?: "error" | "warning"
   maxAssetSize?: number
   maxEntrypointSize?: number
+  maxAsyncChunkGroupSize?: number
+  assetSizeLimits?: Array<RawAssetSizeLimit>
+  compression?: "gzip" | "brotli" | "zstd"
 }
 
 export interface RawSnapshotOptions {
@@ -2965,12 +3053,16 @@ export interface RawStatsOptions {
 export interface RawStorageOptions {
   type: "filesystem"
   directory: string
+  compression?: "zstd" | "lz4"
+  maxSize?: number
+  seedDirectory?: string
 }
 
 export interface RawSubresourceIntegrityPluginOptions {
   integrityCallback?: (data: RawIntegrityData) => void
   hashFuncNames: Array<string>
   htmlPlugin: "JavaScript" | "Native" | "Disabled"
+  manifest?: string
 }
 
 export interface RawSwcJsMinimizerOptions {
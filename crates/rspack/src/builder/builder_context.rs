@@ -68,8 +68,7 @@ pub(super) enum BuiltinPluginOptions {
 
   // Output plugins
   EnableLibraryPlugin(LibraryType),
-  // TODO: support split chunks
-  // SplitChunksPlugin,
+  SplitChunksPlugin(Box<rspack_plugin_split_chunks::PluginOptions>),
   RemoveEmptyChunksPlugin,
   RealContentHashPlugin,
 
@@ -77,10 +76,12 @@ pub(super) enum BuiltinPluginOptions {
   NamedModuleIdsPlugin,
   NaturalModuleIdsPlugin,
   DeterministicModuleIdsPlugin,
+  HashedModuleIdsPlugin,
   NaturalChunkIdsPlugin,
   NamedChunkIdsPlugin,
   DeterministicChunkIdsPlugin,
   OccurrenceChunkIdsPlugin(rspack_ids::OccurrenceChunkIdsPluginOptions),
+  RecordIdsPlugin(rspack_ids::RecordIdsPluginOptions),
 
   // Define and optimization plugins
   DefinePlugin(rspack_plugin_javascript::define_plugin::DefineValue),
@@ -281,9 +282,9 @@ impl BuilderContext {
       BuiltinPluginOptions::EnableLibraryPlugin(library_type) => {
         rspack_plugin_library::enable_library_plugin(library_type, &mut plugins)
       }
-      // BuiltinPluginOptions::SplitChunksPlugin => {
-      // plugins.push(rspack_plugin_split_chunks::SplitChunksPlugin::default().boxed())
-      // }
+      BuiltinPluginOptions::SplitChunksPlugin(options) => {
+        plugins.push(rspack_plugin_split_chunks::SplitChunksPlugin::new(*options).boxed())
+      }
       BuiltinPluginOptions::RemoveEmptyChunksPlugin => {
         plugins.push(rspack_plugin_remove_empty_chunks::RemoveEmptyChunksPlugin::default().boxed())
       }
@@ -300,6 +301,10 @@ impl BuilderContext {
       BuiltinPluginOptions::DeterministicModuleIdsPlugin => {
         plugins.push(rspack_ids::DeterministicModuleIdsPlugin::default().boxed())
       }
+      BuiltinPluginOptions::HashedModuleIdsPlugin => plugins.push(
+        rspack_ids::HashedModuleIdsPlugin::new(rspack_ids::HashedModuleIdsPluginOptions::default())
+          .boxed(),
+      ),
       BuiltinPluginOptions::NaturalChunkIdsPlugin => {
         plugins.push(rspack_ids::NaturalChunkIdsPlugin::default().boxed())
       }
@@ -312,6 +317,9 @@ impl BuilderContext {
       BuiltinPluginOptions::OccurrenceChunkIdsPlugin(options) => {
         plugins.push(rspack_ids::OccurrenceChunkIdsPlugin::new(options).boxed())
       }
+      BuiltinPluginOptions::RecordIdsPlugin(options) => {
+        plugins.push(rspack_ids::RecordIdsPlugin::new(options).boxed())
+      }
 
       // Define and optimization plugins
       BuiltinPluginOptions::DefinePlugin(values) => {
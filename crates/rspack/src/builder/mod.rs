@@ -4,11 +4,13 @@ mod browserslist_target;
 mod builder_context;
 mod devtool;
 mod externals;
+mod split_chunks;
 mod target;
 
 pub use builder_context::BuilderContext;
 pub use devtool::Devtool;
 use rspack_tasks::CURRENT_COMPILER_CONTEXT;
+pub use split_chunks::{CacheGroupOptionsBuilder, SplitChunksOptionsBuilder};
 pub use target::Targets;
 
 macro_rules! d {
@@ -235,6 +237,14 @@ impl CompilerBuilder {
     self
   }
 
+  /// Set whether to collect the time spent on each module.
+  ///
+  /// See [`CompilerOptionsBuilder::profile`] for more details.
+  pub fn profile(&mut self, profile: bool) -> &mut Self {
+    self.options_builder.profile(profile);
+    self
+  }
+
   /// Set options for module configuration.
   ///
   /// Both are accepted:
@@ -368,6 +378,41 @@ impl CompilerBuilder {
     self
   }
 
+  /// Set the records file to read and write the module and chunk ids.
+  ///
+  /// See [`CompilerOptionsBuilder::records_path`] for more details.
+  pub fn records_path<V>(&mut self, records_path: V) -> &mut Self
+  where
+    V: Into<Utf8PathBuf>,
+  {
+    self.options_builder.records_path(records_path);
+    self
+  }
+
+  /// Set the records file to read the module and chunk ids from.
+  ///
+  /// See [`CompilerOptionsBuilder::records_input_path`] for more details.
+  pub fn records_input_path<V>(&mut self, records_input_path: V) -> &mut Self
+  where
+    V: Into<Utf8PathBuf>,
+  {
+    self.options_builder.records_input_path(records_input_path);
+    self
+  }
+
+  /// Set the records file to write the module and chunk ids to.
+  ///
+  /// See [`CompilerOptionsBuilder::records_output_path`] for more details.
+  pub fn records_output_path<V>(&mut self, records_output_path: V) -> &mut Self
+  where
+    V: Into<Utf8PathBuf>,
+  {
+    self
+      .options_builder
+      .records_output_path(records_output_path);
+    self
+  }
+
   /// Set options for experiments.
   ///
   /// Both are accepted:
@@ -583,6 +628,8 @@ pub struct CompilerOptionsBuilder {
   devtool: Option<Devtool>,
   /// Whether to fail on the first error.
   bail: Option<bool>,
+  /// Whether to collect the time spent on each module.
+  profile: Option<bool>,
   /// Performance optimization options.
   experiments: Option<ExperimentsBuilder>,
   /// Options for incremental builds.
@@ -599,6 +646,12 @@ pub struct CompilerOptionsBuilder {
   node: Option<NodeOptionBuilder>,
   /// The value of `require.amd` or `define.amd`.
   amd: Option<String>,
+  /// The records file to read and write the module and chunk ids.
+  records_path: Option<Utf8PathBuf>,
+  /// The records file to read the module and chunk ids from.
+  records_input_path: Option<Utf8PathBuf>,
+  /// The records file to write the module and chunk ids to.
+  records_output_path: Option<Utf8PathBuf>,
 }
 
 impl From<&mut CompilerOptionsBuilder> for CompilerOptionsBuilder {
@@ -617,6 +670,7 @@ impl From<&mut CompilerOptionsBuilder> for CompilerOptionsBuilder {
       resolve_loader: value.resolve_loader.take(),
       devtool: value.devtool.take(),
       bail: value.bail.take(),
+      profile: value.profile.take(),
       experiments: value.experiments.take(),
       incremental: value.incremental.take(),
       module: value.module.take(),
@@ -625,6 +679,9 @@ impl From<&mut CompilerOptionsBuilder> for CompilerOptionsBuilder {
       optimization: value.optimization.take(),
       node: value.node.take(),
       amd: value.amd.take(),
+      records_path: value.records_path.take(),
+      records_input_path: value.records_input_path.take(),
+      records_output_path: value.records_output_path.take(),
     }
   }
 }
@@ -720,6 +777,15 @@ impl CompilerOptionsBuilder {
     self
   }
 
+  /// Set whether to collect the time spent on each module.
+  ///
+  /// When enabled, the resolving, factory, loader, parsing, code generation and hashing time
+  /// of each module are available in the `profile` section of stats.
+  pub fn profile(&mut self, profile: bool) -> &mut Self {
+    self.profile = Some(profile);
+    self
+  }
+
   /// Set options for module configuration.
   ///
   /// Both are accepted:
@@ -842,6 +908,36 @@ impl CompilerOptionsBuilder {
     self
   }
 
+  /// Set the records file to read and write the module and chunk ids.
+  ///
+  /// The ids assigned in previous builds are kept, so they stay stable for long-term caching.
+  /// Relative paths are resolved against the context.
+  pub fn records_path<V>(&mut self, records_path: V) -> &mut Self
+  where
+    V: Into<Utf8PathBuf>,
+  {
+    self.records_path = Some(records_path.into());
+    self
+  }
+
+  /// Set the records file to read the module and chunk ids from, defaults to `records_path`.
+  pub fn records_input_path<V>(&mut self, records_input_path: V) -> &mut Self
+  where
+    V: Into<Utf8PathBuf>,
+  {
+    self.records_input_path = Some(records_input_path.into());
+    self
+  }
+
+  /// Set the records file to write the module and chunk ids to, defaults to `records_path`.
+  pub fn records_output_path<V>(&mut self, records_output_path: V) -> &mut Self
+  where
+    V: Into<Utf8PathBuf>,
+  {
+    self.records_output_path = Some(records_output_path.into());
+    self
+  }
+
   /// Set options for experiments.
   ///
   /// Both are accepted:
@@ -934,6 +1030,7 @@ impl CompilerOptionsBuilder {
     });
 
     let bail = d!(self.bail.take(), false);
+    let profile = d!(self.profile.take(), false);
     let cache = d!(self.cache.take(), {
       if development {
         CacheOptions::Memory { max_generations: 1 }
@@ -1240,6 +1337,28 @@ impl CompilerOptionsBuilder {
       .plugins
       .push(BuiltinPluginOptions::WorkerPlugin);
 
+    // apply records plugin
+    let records_path = self.records_path.take();
+    let records_input_path = self.records_input_path.take().or(records_path.clone());
+    let records_output_path = self.records_output_path.take().or(records_path);
+    if records_input_path.is_some() || records_output_path.is_some() {
+      let resolve = |path: Utf8PathBuf| {
+        if path.is_absolute() {
+          path
+        } else {
+          context.as_path().join(path)
+        }
+      };
+      builder_context
+        .plugins
+        .push(BuiltinPluginOptions::RecordIdsPlugin(
+          rspack_ids::RecordIdsPluginOptions {
+            input_path: records_input_path.map(resolve),
+            output_path: records_output_path.map(resolve),
+          },
+        ));
+    }
+
     // TODO: stats plugins
     let stats = d!(self.stats.take(), StatsOptions { colors: true });
 
@@ -1261,6 +1380,7 @@ impl CompilerOptionsBuilder {
       optimization,
       amd,
       bail,
+      profile,
       __references: Default::default(),
     })
   }
@@ -3216,7 +3336,8 @@ pub struct OptimizationOptionsBuilder {
   emit_on_errors: Option<bool>,
   /// Runtime chunk.
   runtime_chunk: Option<rspack_plugin_runtime_chunk::RuntimeChunkOptions>,
-  // TODO: split chunks
+  /// Split chunks.
+  split_chunks: Option<SplitChunksOptionsBuilder>,
 }
 
 impl From<Optimization> for OptimizationOptionsBuilder {
@@ -3241,6 +3362,7 @@ impl From<Optimization> for OptimizationOptionsBuilder {
       node_env: None,
       emit_on_errors: None,
       runtime_chunk: None,
+      split_chunks: None,
     }
   }
 }
@@ -3267,6 +3389,7 @@ impl From<&mut OptimizationOptionsBuilder> for OptimizationOptionsBuilder {
       node_env: value.node_env.take(),
       emit_on_errors: value.emit_on_errors.take(),
       runtime_chunk: value.runtime_chunk.take(),
+      split_chunks: value.split_chunks.take(),
     }
   }
 }
@@ -3421,6 +3544,43 @@ impl OptimizationOptionsBuilder {
     self
   }
 
+  /// Set options for `SplitChunksPlugin`.
+  ///
+  /// Default set to the defaults of `optimization.splitChunks` in the JavaScript API.
+  ///
+  /// # Examples
+  ///
+  /// ```rust
+  /// use rspack::builder::{
+  ///   Builder as _, CacheGroupOptionsBuilder, OptimizationOptionsBuilder, SplitChunksOptionsBuilder,
+  /// };
+  /// use rspack_core::Compiler;
+  /// use rspack_plugin_split_chunks::{CacheGroupTest, ChunkFilter, ChunkNameGetter};
+  /// use rspack_regex::RspackRegex;
+  ///
+  /// let compiler = Compiler::builder().optimization(
+  ///   OptimizationOptionsBuilder::default().split_chunks(
+  ///     SplitChunksOptionsBuilder::default()
+  ///       .chunks(ChunkFilter::All)
+  ///       .cache_group(
+  ///         "shared",
+  ///         CacheGroupOptionsBuilder::default()
+  ///           .test(CacheGroupTest::RegExp(RspackRegex::new("shared").unwrap()))
+  ///           .name(ChunkNameGetter::String("shared".into()))
+  ///           .enforce(true),
+  ///       )
+  ///       .disable_cache_group("default"),
+  ///   ),
+  /// );
+  /// ```
+  pub fn split_chunks<V>(&mut self, value: V) -> &mut Self
+  where
+    V: Into<SplitChunksOptionsBuilder>,
+  {
+    self.split_chunks = Some(value.into());
+    self
+  }
+
   /// Build [`Optimization`] from options.
   ///
   /// [`Optimization`]: rspack_core::options::Optimization
@@ -3476,6 +3636,11 @@ impl OptimizationOptionsBuilder {
           .plugins
           .push(BuiltinPluginOptions::NaturalModuleIdsPlugin);
       }
+      "hashed" => {
+        builder_context
+          .plugins
+          .push(BuiltinPluginOptions::HashedModuleIdsPlugin);
+      }
       _ => {
         return Err(
           BuilderError::Option(
@@ -3591,6 +3756,15 @@ impl OptimizationOptionsBuilder {
           used_exports.is_global(),
         ));
     }
+    let split_chunks = f!(self.split_chunks.take(), SplitChunksOptionsBuilder::default)
+      .build(production, used_exports == UsedExportsOption::True);
+    if let Some(split_chunks) = split_chunks {
+      builder_context
+        .plugins
+        .push(BuiltinPluginOptions::SplitChunksPlugin(Box::new(
+          split_chunks,
+        )));
+    }
     let inner_graph = d!(self.inner_graph, production);
     if !d!(self.emit_on_errors, !production) {
       builder_context
@@ -0,0 +1,446 @@
+use derive_more::Debug;
+use rspack_core::SourceType;
+use rspack_plugin_split_chunks::{
+  CacheGroup, CacheGroupTest, ChunkFilter, ChunkNameGetter, FallbackCacheGroup, PluginOptions,
+  SplitChunkSizes, create_all_chunk_filter, create_async_chunk_filter,
+  create_default_module_layer_filter, create_default_module_type_filter,
+};
+use rspack_regex::RspackRegex;
+
+/// Builder used to build options of `SplitChunksPlugin`.
+///
+/// The defaults are aligned with `optimization.splitChunks` of the JavaScript API, including the
+/// `default` and `defaultVendors` cache groups.
+#[derive(Debug, Default)]
+pub struct SplitChunksOptionsBuilder {
+  /// Whether to disable `SplitChunksPlugin`.
+  disabled: bool,
+  /// Which chunks will be selected for optimization.
+  #[debug(skip)]
+  chunks: Option<ChunkFilter>,
+  /// The minimum number of chunks that must share a module before splitting.
+  min_chunks: Option<u32>,
+  /// Minimum size in bytes for a chunk to be generated.
+  min_size: Option<f64>,
+  /// Maximum size in bytes a chunk will be split into.
+  max_size: Option<f64>,
+  /// Maximum number of parallel requests when loading an entry point.
+  max_initial_requests: Option<f64>,
+  /// Maximum number of parallel requests when loading on demand.
+  max_async_requests: Option<f64>,
+  /// The name of the split chunk.
+  #[debug(skip)]
+  name: Option<ChunkNameGetter>,
+  /// The delimiter used in the generated names.
+  automatic_name_delimiter: Option<String>,
+  /// Whether to hide path info when splitting chunks by `max_size`.
+  hide_path_info: Option<bool>,
+  /// Whether to split chunks by the used exports of modules.
+  used_exports: Option<bool>,
+  /// Size types used when a size is given as a number.
+  default_size_types: Option<Vec<SourceType>>,
+  /// Cache groups, `None` disables a default cache group.
+  cache_groups: Vec<(String, Option<CacheGroupOptionsBuilder>)>,
+}
+
+impl From<&mut SplitChunksOptionsBuilder> for SplitChunksOptionsBuilder {
+  fn from(value: &mut SplitChunksOptionsBuilder) -> Self {
+    std::mem::take(value)
+  }
+}
+
+impl SplitChunksOptionsBuilder {
+  /// Disable `SplitChunksPlugin`.
+  pub fn disabled(&mut self) -> &mut Self {
+    self.disabled = true;
+    self
+  }
+
+  /// Set which chunks will be selected for optimization.
+  ///
+  /// Default set to `ChunkFilter::Async`.
+  pub fn chunks(&mut self, chunks: ChunkFilter) -> &mut Self {
+    self.chunks = Some(chunks);
+    self
+  }
+
+  /// Set the minimum number of chunks that must share a module before splitting.
+  ///
+  /// Default set to `1`.
+  pub fn min_chunks(&mut self, min_chunks: u32) -> &mut Self {
+    self.min_chunks = Some(min_chunks);
+    self
+  }
+
+  /// Set the minimum size in bytes for a chunk to be generated.
+  ///
+  /// Default set to `20000` in production mode.
+  /// Default set to `10000` otherwise.
+  pub fn min_size(&mut self, min_size: f64) -> &mut Self {
+    self.min_size = Some(min_size);
+    self
+  }
+
+  /// Set the maximum size in bytes a chunk will be split into.
+  pub fn max_size(&mut self, max_size: f64) -> &mut Self {
+    self.max_size = Some(max_size);
+    self
+  }
+
+  /// Set the maximum number of parallel requests when loading an entry point.
+  ///
+  /// Default set to `30` in production mode.
+  /// Default set to `f64::INFINITY` otherwise.
+  pub fn max_initial_requests(&mut self, max_initial_requests: f64) -> &mut Self {
+    self.max_initial_requests = Some(max_initial_requests);
+    self
+  }
+
+  /// Set the maximum number of parallel requests when loading on demand.
+  ///
+  /// Default set to `30` in production mode.
+  /// Default set to `f64::INFINITY` otherwise.
+  pub fn max_async_requests(&mut self, max_async_requests: f64) -> &mut Self {
+    self.max_async_requests = Some(max_async_requests);
+    self
+  }
+
+  /// Set the name of the split chunk for all cache groups without a name.
+  pub fn name(&mut self, name: ChunkNameGetter) -> &mut Self {
+    self.name = Some(name);
+    self
+  }
+
+  /// Set the delimiter used in the generated names.
+  ///
+  /// Default set to `-`.
+  pub fn automatic_name_delimiter<V>(&mut self, delimiter: V) -> &mut Self
+  where
+    V: Into<String>,
+  {
+    self.automatic_name_delimiter = Some(delimiter.into());
+    self
+  }
+
+  /// Set whether to hide path info when splitting chunks by `max_size`.
+  ///
+  /// Default set to `true` in production mode.
+  /// Default set to `false` otherwise.
+  pub fn hide_path_info(&mut self, hide_path_info: bool) -> &mut Self {
+    self.hide_path_info = Some(hide_path_info);
+    self
+  }
+
+  /// Set whether to split chunks by the used exports of modules.
+  ///
+  /// Default set to `true` if `optimization.used_exports` is `UsedExportsOption::True`.
+  pub fn used_exports(&mut self, used_exports: bool) -> &mut Self {
+    self.used_exports = Some(used_exports);
+    self
+  }
+
+  /// Set the size types used when a size is given as a number.
+  ///
+  /// Default set to `javascript`, `css` and `unknown`.
+  pub fn default_size_types(&mut self, default_size_types: Vec<SourceType>) -> &mut Self {
+    self.default_size_types = Some(default_size_types);
+    self
+  }
+
+  /// Add a cache group, or replace the cache group with the same key.
+  ///
+  /// The `default` and `defaultVendors` cache groups are added unless they are replaced or disabled.
+  pub fn cache_group<K, V>(&mut self, key: K, cache_group: V) -> &mut Self
+  where
+    K: Into<String>,
+    V: Into<CacheGroupOptionsBuilder>,
+  {
+    self.set_cache_group(key.into(), Some(cache_group.into()));
+    self
+  }
+
+  /// Disable the cache group with the key, e.g. `default` or `defaultVendors`.
+  pub fn disable_cache_group<K>(&mut self, key: K) -> &mut Self
+  where
+    K: Into<String>,
+  {
+    self.set_cache_group(key.into(), None);
+    self
+  }
+
+  fn set_cache_group(&mut self, key: String, cache_group: Option<CacheGroupOptionsBuilder>) {
+    if let Some((_, existing)) = self.cache_groups.iter_mut().find(|(k, _)| *k == key) {
+      *existing = cache_group;
+    } else {
+      self.cache_groups.push((key, cache_group));
+    }
+  }
+
+  /// Build [`PluginOptions`] from options, returns `None` if disabled.
+  pub(super) fn build(&mut self, production: bool, used_exports: bool) -> Option<PluginOptions> {
+    if self.disabled {
+      return None;
+    }
+
+    for (key, default) in [
+      ("default", default_cache_group()),
+      ("defaultVendors", default_vendors_cache_group()),
+    ] {
+      if !self.cache_groups.iter().any(|(k, _)| k == key) {
+        self.cache_groups.push((key.to_string(), Some(default)));
+      }
+    }
+
+    let default_size_types = self
+      .default_size_types
+      .take()
+      .unwrap_or_else(|| vec![SourceType::JavaScript, SourceType::Css, SourceType::Unknown]);
+    let create_sizes = |size: Option<f64>| {
+      size.map_or_else(SplitChunkSizes::empty, |size| {
+        SplitChunkSizes::with_initial_value(&default_size_types, size)
+      })
+    };
+
+    let overall_chunk_filter = self.chunks.take();
+    let overall_min_chunks = self.min_chunks.unwrap_or(1);
+    let overall_name = self.name.take().unwrap_or(ChunkNameGetter::Disabled);
+    let min_size = self
+      .min_size
+      .unwrap_or(if production { 20000.0 } else { 10000.0 });
+    let overall_min_size = create_sizes(Some(min_size));
+    let overall_max_size = create_sizes(self.max_size);
+    let default_max_requests = if production { 30.0 } else { f64::INFINITY };
+    let overall_max_initial_requests = self.max_initial_requests.unwrap_or(default_max_requests);
+    let overall_max_async_requests = self.max_async_requests.unwrap_or(default_max_requests);
+    let automatic_name_delimiter = self
+      .automatic_name_delimiter
+      .take()
+      .unwrap_or_else(|| "-".to_string());
+    let used_exports = self.used_exports.unwrap_or(used_exports);
+    let empty_sizes = SplitChunkSizes::empty();
+
+    let cache_groups = self
+      .cache_groups
+      .drain(..)
+      .filter_map(|(key, cache_group)| Some((key, cache_group?)))
+      .map(|(key, mut cache_group)| {
+        let enforce = cache_group.enforce.unwrap_or(false);
+        let inherited = |sizes: &SplitChunkSizes| {
+          if enforce {
+            empty_sizes.clone()
+          } else {
+            sizes.clone()
+          }
+        };
+        let min_size = create_sizes(cache_group.min_size).merge(&inherited(&overall_min_size));
+        let max_size = create_sizes(cache_group.max_size).merge(&inherited(&overall_max_size));
+        let name = match cache_group.name.take() {
+          None | Some(ChunkNameGetter::Disabled) => overall_name.clone(),
+          Some(name) => name,
+        };
+        CacheGroup {
+          id_hint: cache_group.id_hint.take().unwrap_or_else(|| key.clone()),
+          key,
+          chunk_filter: cache_group.chunk_filter.take().unwrap_or_else(|| {
+            overall_chunk_filter
+              .clone()
+              .unwrap_or_else(create_async_chunk_filter)
+          }),
+          test: cache_group.test.take().unwrap_or(CacheGroupTest::Enabled),
+          r#type: create_default_module_type_filter(),
+          layer: create_default_module_layer_filter(),
+          name,
+          priority: cache_group.priority.unwrap_or(0.0),
+          min_size,
+          min_size_reduction: empty_sizes.clone(),
+          reuse_existing_chunk: cache_group.reuse_existing_chunk.unwrap_or(false),
+          min_chunks: cache_group.min_chunks.unwrap_or(if enforce {
+            1
+          } else {
+            overall_min_chunks
+          }),
+          max_initial_requests: cache_group.max_initial_requests.unwrap_or(if enforce {
+            f64::INFINITY
+          } else {
+            overall_max_initial_requests
+          }),
+          max_async_requests: cache_group.max_async_requests.unwrap_or(if enforce {
+            f64::INFINITY
+          } else {
+            overall_max_async_requests
+          }),
+          max_async_size: max_size.clone(),
+          max_initial_size: max_size,
+          filename: None,
+          automatic_name_delimiter: automatic_name_delimiter.clone(),
+          used_exports,
+        }
+      })
+      .collect();
+
+    Some(PluginOptions {
+      cache_groups,
+      fallback_cache_group: FallbackCacheGroup {
+        chunks_filter: overall_chunk_filter.unwrap_or_else(create_all_chunk_filter),
+        min_size: overall_min_size,
+        max_async_size: overall_max_size.clone(),
+        max_initial_size: overall_max_size,
+        automatic_name_delimiter,
+      },
+      hide_path_info: Some(self.hide_path_info.unwrap_or(production)),
+    })
+  }
+}
+
+/// The `default` cache group, aligned with `optimization.splitChunks.cacheGroups.default`.
+fn default_cache_group() -> CacheGroupOptionsBuilder {
+  let mut cache_group = CacheGroupOptionsBuilder::default();
+  cache_group
+    .id_hint("")
+    .reuse_existing_chunk(true)
+    .min_chunks(2)
+    .priority(-20.0);
+  cache_group
+}
+
+/// The `defaultVendors` cache group, aligned with `optimization.splitChunks.cacheGroups.defaultVendors`.
+fn default_vendors_cache_group() -> CacheGroupOptionsBuilder {
+  let mut cache_group = CacheGroupOptionsBuilder::default();
+  cache_group
+    .id_hint("vendors")
+    .reuse_existing_chunk(true)
+    .test(CacheGroupTest::RegExp(
+      RspackRegex::with_flags(r"[\\/]node_modules[\\/]", "i").expect("should be a valid regex"),
+    ))
+    .priority(-10.0);
+  cache_group
+}
+
+/// Builder used to build a [`CacheGroup`].
+///
+/// Options not set are inherited from [`SplitChunksOptionsBuilder`].
+#[derive(Debug, Default)]
+pub struct CacheGroupOptionsBuilder {
+  /// Which modules will be selected by this cache group.
+  #[debug(skip)]
+  test: Option<CacheGroupTest>,
+  /// Which chunks will be selected by this cache group.
+  #[debug(skip)]
+  chunk_filter: Option<ChunkFilter>,
+  /// Minimum size in bytes for a chunk to be generated.
+  min_size: Option<f64>,
+  /// Maximum size in bytes a chunk will be split into.
+  max_size: Option<f64>,
+  /// The minimum number of chunks that must share a module before splitting.
+  min_chunks: Option<u32>,
+  /// Maximum number of parallel requests when loading an entry point.
+  max_initial_requests: Option<f64>,
+  /// Maximum number of parallel requests when loading on demand.
+  max_async_requests: Option<f64>,
+  /// Priority of the cache group, a module is put into the cache group with the highest priority.
+  priority: Option<f64>,
+  /// The name of the split chunk.
+  #[debug(skip)]
+  name: Option<ChunkNameGetter>,
+  /// Hint of the chunk id.
+  id_hint: Option<String>,
+  /// Whether to reuse an existing chunk which contains exactly the selected modules.
+  reuse_existing_chunk: Option<bool>,
+  /// Whether to ignore `min_size`, `min_chunks`, `max_size` and the maximum requests of
+  /// [`SplitChunksOptionsBuilder`].
+  enforce: Option<bool>,
+}
+
+impl From<&mut CacheGroupOptionsBuilder> for CacheGroupOptionsBuilder {
+  fn from(value: &mut CacheGroupOptionsBuilder) -> Self {
+    std::mem::take(value)
+  }
+}
+
+impl CacheGroupOptionsBuilder {
+  /// Set which modules will be selected by this cache group.
+  ///
+  /// Default set to all modules.
+  pub fn test(&mut self, test: CacheGroupTest) -> &mut Self {
+    self.test = Some(test);
+    self
+  }
+
+  /// Set which chunks will be selected by this cache group.
+  pub fn chunk_filter(&mut self, chunk_filter: ChunkFilter) -> &mut Self {
+    self.chunk_filter = Some(chunk_filter);
+    self
+  }
+
+  /// Set the minimum size in bytes for a chunk to be generated.
+  pub fn min_size(&mut self, min_size: f64) -> &mut Self {
+    self.min_size = Some(min_size);
+    self
+  }
+
+  /// Set the maximum size in bytes a chunk will be split into.
+  pub fn max_size(&mut self, max_size: f64) -> &mut Self {
+    self.max_size = Some(max_size);
+    self
+  }
+
+  /// Set the minimum number of chunks that must share a module before splitting.
+  pub fn min_chunks(&mut self, min_chunks: u32) -> &mut Self {
+    self.min_chunks = Some(min_chunks);
+    self
+  }
+
+  /// Set the maximum number of parallel requests when loading an entry point.
+  pub fn max_initial_requests(&mut self, max_initial_requests: f64) -> &mut Self {
+    self.max_initial_requests = Some(max_initial_requests);
+    self
+  }
+
+  /// Set the maximum number of parallel requests when loading on demand.
+  pub fn max_async_requests(&mut self, max_async_requests: f64) -> &mut Self {
+    self.max_async_requests = Some(max_async_requests);
+    self
+  }
+
+  /// Set the priority of the cache group.
+  ///
+  /// Default set to `0`.
+  pub fn priority(&mut self, priority: f64) -> &mut Self {
+    self.priority = Some(priority);
+    self
+  }
+
+  /// Set the name of the split chunk.
+  pub fn name(&mut self, name: ChunkNameGetter) -> &mut Self {
+    self.name = Some(name);
+    self
+  }
+
+  /// Set the hint of the chunk id.
+  ///
+  /// Default set to the key of the cache group.
+  pub fn id_hint<V>(&mut self, id_hint: V) -> &mut Self
+  where
+    V: Into<String>,
+  {
+    self.id_hint = Some(id_hint.into());
+    self
+  }
+
+  /// Set whether to reuse an existing chunk which contains exactly the selected modules.
+  ///
+  /// Default set to `false`.
+  pub fn reuse_existing_chunk(&mut self, reuse_existing_chunk: bool) -> &mut Self {
+    self.reuse_existing_chunk = Some(reuse_existing_chunk);
+    self
+  }
+
+  /// Set whether to ignore `min_size`, `min_chunks`, `max_size` and the maximum requests of
+  /// [`SplitChunksOptionsBuilder`].
+  ///
+  /// Default set to `false`.
+  pub fn enforce(&mut self, enforce: bool) -> &mut Self {
+    self.enforce = Some(enforce);
+    self
+  }
+}
@@ -49,7 +49,7 @@
 //! Currently, there's still alot of features that are not implemented yet. Here's a list of features that are not implemented yet:
 //!
 //! - [x] `CompilerBuilder` API
-//! - [ ] `SplitChunksPlugin` API
+//! - [x] `SplitChunksPlugin` API
 //! - [ ] `BundlerInfoPlugin` API
 //! - [ ] `StatsPrinter` API
 //! - [ ] Stable `Compiler` API
@@ -0,0 +1,89 @@
+mod common;
+
+#[cfg(test)]
+mod tests {
+  use rspack::builder::Builder as _;
+  use rspack_core::{Compiler, Mode, PluginExt as _, SourceType};
+  use rspack_paths::Utf8Path;
+  use rspack_plugin_split_chunks::{
+    AggressiveSplittingPlugin, AggressiveSplittingPluginOptions, SplitChunkSizes,
+  };
+  use rspack_tasks::within_compiler_context_for_testing;
+  use serde_json::Value;
+
+  use crate::common::copy_fixture;
+
+  async fn build(context: &Utf8Path) -> Compiler {
+    let sizes = |size| SplitChunkSizes::with_initial_value(&[SourceType::JavaScript], size);
+    let mut compiler = Compiler::builder()
+      .context(context)
+      .mode(Mode::None)
+      .entry("main", "./src/index.js")
+      .records_path(context.join("records.json"))
+      .plugin(
+        AggressiveSplittingPlugin::new(AggressiveSplittingPluginOptions {
+          min_size: sizes(5000.0),
+          max_size: sizes(10000.0),
+        })
+        .boxed(),
+      )
+      .build()
+      .unwrap();
+    compiler.build().await.unwrap();
+    assert!(compiler.compilation.get_errors().next().is_none());
+    compiler
+  }
+
+  fn records(context: &Utf8Path) -> Value {
+    serde_json::from_str(&std::fs::read_to_string(context.join("records.json")).unwrap()).unwrap()
+  }
+
+  fn splits(context: &Utf8Path) -> Vec<Value> {
+    records(context)["aggressiveSplits"]
+      .as_array()
+      .unwrap()
+      .clone()
+  }
+
+  #[tokio::test(flavor = "multi_thread")]
+  async fn aggressive_splitting_keeps_splits() {
+    within_compiler_context_for_testing(async move {
+      let context = copy_fixture("aggressive-splitting", "keep_splits");
+      let compiler = build(&context).await;
+      let prev_splits = splits(&context);
+      assert!(!prev_splits.is_empty());
+      // the splits are kept in the same records as the ids
+      let records = records(&context);
+      assert!(records["modules"]["byIdentifier"].is_object());
+      assert!(records["chunks"]["byModules"].is_object());
+      assert!(
+        compiler
+          .compilation
+          .build_chunk_graph_artifact
+          .chunk_by_ukey
+          .len()
+          > 1
+      );
+
+      // only the split containing the changed module is dropped
+      std::fs::write(context.join("src/a.js"), "console.log('a');").unwrap();
+      build(&context).await;
+      let splits = splits(&context);
+      let unchanged = prev_splits
+        .iter()
+        .filter(|split| {
+          !split["modules"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .any(|module| module.as_str().unwrap().ends_with("a.js"))
+        })
+        .collect::<Vec<_>>();
+      assert!(!unchanged.is_empty());
+      for split in unchanged {
+        assert!(splits.contains(split));
+      }
+    })
+    .await;
+  }
+}
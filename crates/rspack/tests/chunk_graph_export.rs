@@ -0,0 +1,110 @@
+#[cfg(test)]
+mod tests {
+  use std::sync::Arc;
+
+  use rspack::builder::{Builder as _, OptimizationOptionsBuilder, SplitChunksOptionsBuilder};
+  use rspack_core::{ChunkGraphExport, Compiler, Mode, ModulePlacementReason};
+  use rspack_fs::MemoryFileSystem;
+  use rspack_paths::Utf8Path;
+  use rspack_plugin_split_chunks::ChunkFilter;
+  use rspack_tasks::within_compiler_context_for_testing;
+
+  fn find_module<'a>(export: &'a ChunkGraphExport, name: &str) -> (u32, &'a ModulePlacementReason) {
+    export
+      .chunks
+      .iter()
+      .find_map(|chunk| {
+        chunk
+          .modules
+          .iter()
+          .find(|module| module.name == name)
+          .map(|module| (chunk.ukey, &module.reason))
+      })
+      .unwrap_or_else(|| panic!("should have module {name}"))
+  }
+
+  #[tokio::test(flavor = "multi_thread")]
+  async fn chunk_graph_export() {
+    within_compiler_context_for_testing(async move {
+      // two entries sharing a large module, one of them loading a module on demand
+      let context =
+        Utf8Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/fixtures/chunk-graph-export");
+      let mut split_chunks = SplitChunksOptionsBuilder::default();
+      split_chunks.chunks(ChunkFilter::All);
+      let mut optimization = OptimizationOptionsBuilder::default();
+      optimization
+        .minimize(false)
+        .concatenate_modules(false)
+        .split_chunks(split_chunks);
+      let mut compiler = Compiler::builder()
+        .context(context)
+        .mode(Mode::Production)
+        .entry("a", "./src/a.js")
+        .entry("b", "./src/b.js")
+        .optimization(optimization)
+        .output_filesystem(Arc::new(MemoryFileSystem::default()))
+        .build()
+        .unwrap();
+      compiler.build().await.unwrap();
+      assert!(compiler.compilation.get_errors().next().is_none());
+
+      let export = ChunkGraphExport::new(&compiler.compilation);
+
+      let entrypoint = export.chunk_group("a").expect("should have entrypoint a");
+      assert!(entrypoint.entrypoint && entrypoint.initial);
+      assert_eq!(entrypoint.children.len(), 1);
+      let lazy = export
+        .chunk_groups
+        .iter()
+        .find(|chunk_group| chunk_group.ukey == entrypoint.children[0])
+        .unwrap();
+      assert!(!lazy.entrypoint && !lazy.initial);
+      assert_eq!(lazy.parents, vec![entrypoint.ukey]);
+
+      let (a_chunk, reason) = find_module(&export, "./src/a.js");
+      assert_eq!(reason, &ModulePlacementReason::Entry);
+      assert_eq!(entrypoint.runtime_chunk, Some(a_chunk));
+      assert!(export.chunk(a_chunk).unwrap().has_runtime);
+      assert!(
+        export
+          .chunk(a_chunk)
+          .unwrap()
+          .modules
+          .iter()
+          .any(|module| module.reason == ModulePlacementReason::Runtime)
+      );
+
+      let (shared_chunk, reason) = find_module(&export, "./src/shared.js");
+      assert_eq!(
+        reason,
+        &ModulePlacementReason::CacheGroup {
+          key: "default".to_string()
+        }
+      );
+      assert!(entrypoint.chunks.contains(&shared_chunk));
+      assert!(export.chunk(shared_chunk).unwrap().size > 30000.0);
+
+      let (lazy_chunk, reason) = find_module(&export, "./src/lazy.js");
+      assert_eq!(reason, &ModulePlacementReason::Dependency);
+      assert_eq!(lazy.chunks, vec![lazy_chunk]);
+
+      let dot = export.to_dot().unwrap();
+      assert!(dot.starts_with("digraph ChunkGraph {"));
+      assert!(dot.contains(&format!(
+        "g{} -> g{} [style=dashed, label=\"async\"];",
+        entrypoint.ukey, lazy.ukey
+      )));
+      assert!(dot.contains("./src/shared.js"));
+
+      let mermaid = export.to_mermaid().unwrap();
+      assert!(mermaid.starts_with("flowchart LR"));
+      assert!(mermaid.contains(&format!("g{} -. async .-> g{}", entrypoint.ukey, lazy.ukey)));
+      assert!(mermaid.contains("cache group: default"));
+
+      let json = export.to_json().unwrap();
+      assert!(json.contains("\"chunkGroups\""));
+      assert!(json.contains("\"type\": \"cacheGroup\""));
+    })
+    .await;
+  }
+}
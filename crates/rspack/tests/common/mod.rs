@@ -0,0 +1,36 @@
+use rspack_paths::{AssertUtf8, Utf8Path, Utf8PathBuf};
+
+fn copy_dir(from: &Utf8Path, to: &Utf8Path) {
+  std::fs::create_dir_all(to).expect("should create fixture dir");
+  for entry in from.read_dir_utf8().expect("should read fixture dir") {
+    let entry = entry.expect("should read fixture dir entry");
+    let to = to.join(entry.file_name());
+    if entry
+      .file_type()
+      .expect("should get fixture file type")
+      .is_dir()
+    {
+      copy_dir(entry.path(), &to);
+    } else {
+      std::fs::copy(entry.path(), to).expect("should copy fixture file");
+    }
+  }
+}
+
+/// Copy `tests/fixtures/{name}` to a temp directory for the cases which modify the files,
+/// so the fixture stays untouched and each case starts from a fresh copy.
+pub fn copy_fixture(name: &str, case: &str) -> Utf8PathBuf {
+  let context = std::env::temp_dir()
+    .join("rspack_test")
+    .join(name)
+    .join(case)
+    .assert_utf8();
+  let _ = std::fs::remove_dir_all(&context);
+  copy_dir(
+    &Utf8Path::new(env!("CARGO_MANIFEST_DIR"))
+      .join("tests/fixtures")
+      .join(name),
+    &context,
+  );
+  context
+}
@@ -0,0 +1,7 @@
+import './a';
+import './b';
+import './c';
+import './d';
+import './e';
+import './f';
+import './g';
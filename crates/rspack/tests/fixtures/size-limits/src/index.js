@@ -0,0 +1 @@
+import(/* webpackChunkName: "async" */ "./async");
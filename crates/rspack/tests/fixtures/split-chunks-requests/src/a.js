@@ -0,0 +1,4 @@
+import x from './x';
+import y from './y';
+import z from './z';
+console.log(x, y, z);
@@ -0,0 +1,2 @@
+import y from './y';
+console.log(y);
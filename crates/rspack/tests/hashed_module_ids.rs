@@ -0,0 +1,75 @@
+mod common;
+
+#[cfg(test)]
+mod tests {
+  use std::collections::{HashMap, HashSet};
+
+  use rspack::builder::Builder as _;
+  use rspack_core::{Compiler, Mode, Optimization};
+  use rspack_paths::Utf8Path;
+  use rspack_tasks::within_compiler_context_for_testing;
+
+  use crate::common::copy_fixture;
+
+  /// Module ids by the relative paths of the modules
+  async fn build(context: &Utf8Path) -> HashMap<String, String> {
+    let mut compiler = Compiler::builder()
+      .context(context)
+      .mode(Mode::None)
+      .entry("main", "./src/index.js")
+      .optimization(Optimization::builder().module_ids("hashed".to_string()))
+      .build()
+      .unwrap();
+    compiler.build().await.unwrap();
+    assert!(compiler.compilation.get_errors().next().is_none());
+
+    let module_graph = compiler.compilation.get_module_graph();
+    compiler
+      .compilation
+      .module_ids_artifact
+      .iter()
+      .map(|(identifier, id)| {
+        let module = module_graph
+          .module_by_identifier(identifier)
+          .expect("should have module");
+        let path = module
+          .name_for_condition()
+          .expect("should be a normal module");
+        let relative = Utf8Path::new(&path)
+          .strip_prefix(context)
+          .unwrap()
+          .to_string();
+        (relative, id.to_string())
+      })
+      .collect()
+  }
+
+  #[tokio::test(flavor = "multi_thread")]
+  async fn hashed_module_ids() {
+    within_compiler_context_for_testing(async move {
+      let context = copy_fixture("hashed-module-ids", "hashed_module_ids");
+      let prev_ids = build(&context).await;
+      assert_eq!(prev_ids.len(), 8);
+      assert!(prev_ids.values().all(|id| id.len() >= 4));
+      assert_eq!(
+        prev_ids.values().collect::<HashSet<_>>().len(),
+        prev_ids.len(),
+        "module ids should not collide"
+      );
+
+      // unlike natural ids, the ids don't change when modules are reordered or added
+      std::fs::write(
+        context.join("src/index.js"),
+        "import './h';\nimport './g';\nimport './a';\nimport './b';\nimport './c';\nimport './d';\nimport './e';\nimport './f';\n",
+      )
+      .unwrap();
+      let ids = build(&context).await;
+      assert_eq!(ids.len(), 9);
+      assert_eq!(ids.values().collect::<HashSet<_>>().len(), ids.len());
+      for (path, id) in &prev_ids {
+        assert_eq!(ids.get(path), Some(id), "the id of {path} should be stable");
+      }
+    })
+    .await;
+  }
+}
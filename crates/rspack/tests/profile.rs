@@ -0,0 +1,53 @@
+#[cfg(test)]
+mod tests {
+  use rspack::builder::Builder as _;
+  use rspack_core::Compiler;
+  use rspack_paths::Utf8Path;
+  use rspack_tasks::within_compiler_context_for_testing;
+
+  async fn build(profile: bool) -> Compiler {
+    let mut compiler = Compiler::builder()
+      .context(Utf8Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/fixtures/basic"))
+      .entry("main", "./src/index.js")
+      .profile(profile)
+      .build()
+      .unwrap();
+    compiler.build().await.unwrap();
+    assert!(compiler.compilation.get_errors().next().is_none());
+    compiler
+  }
+
+  #[tokio::test(flavor = "multi_thread")]
+  async fn profile() {
+    within_compiler_context_for_testing(async {
+      let compiler = build(true).await;
+      let stats = compiler.compilation.get_stats();
+      let profile = stats.get_profile().expect("should have profile");
+
+      assert_eq!(profile.modules.len(), 1);
+      let module = &profile.modules[0];
+      assert_eq!(module.name, "./src/index.js");
+      assert!(module.profile.factory >= module.profile.resolving);
+      assert!(module.profile.building >= module.profile.parsing);
+      assert!(module.profile.total > 0.0);
+      assert!(module.profile.loaders.is_empty());
+      assert!(profile.loaders.is_empty());
+    })
+    .await;
+  }
+
+  #[tokio::test(flavor = "multi_thread")]
+  async fn no_profile() {
+    within_compiler_context_for_testing(async {
+      let compiler = build(false).await;
+      assert!(compiler.compilation.get_stats().get_profile().is_none());
+      let module_graph = compiler.compilation.get_module_graph();
+      assert!(module_graph.modules().all(|(identifier, _)| {
+        module_graph
+          .module_graph_module_by_identifier(identifier)
+          .is_some_and(|mgm| mgm.profile.is_none())
+      }));
+    })
+    .await;
+  }
+}
@@ -0,0 +1,102 @@
+mod common;
+
+#[cfg(test)]
+mod tests {
+  use rspack::builder::Builder as _;
+  use rspack_core::{Compiler, Mode, Optimization};
+  use rspack_paths::Utf8Path;
+  use rspack_tasks::within_compiler_context_for_testing;
+  use serde_json::Value;
+
+  use crate::common::copy_fixture;
+
+  async fn build(context: &Utf8Path) -> Compiler {
+    let mut compiler = Compiler::builder()
+      .context(context)
+      .mode(Mode::None)
+      .entry("main", "./src/index.js")
+      .optimization(
+        Optimization::builder()
+          .module_ids("natural".to_string())
+          .chunk_ids("natural".to_string()),
+      )
+      .records_path("records.json")
+      .build()
+      .unwrap();
+    compiler.build().await.unwrap();
+    assert!(compiler.compilation.get_errors().next().is_none());
+    compiler
+  }
+
+  fn read_records(context: &Utf8Path) -> Value {
+    serde_json::from_str(&std::fs::read_to_string(context.join("records.json")).unwrap()).unwrap()
+  }
+
+  fn module_ids(context: &Utf8Path) -> serde_json::Map<String, Value> {
+    read_records(context)["modules"]["byIdentifier"]
+      .as_object()
+      .unwrap()
+      .clone()
+  }
+
+  /// Ids of the unnamed chunks, by the relative identifiers of their modules
+  fn chunk_ids(context: &Utf8Path) -> serde_json::Map<String, Value> {
+    read_records(context)["chunks"]["byModules"]
+      .as_object()
+      .unwrap()
+      .clone()
+  }
+
+  #[tokio::test(flavor = "multi_thread")]
+  async fn records_keep_module_ids() {
+    within_compiler_context_for_testing(async move {
+      let context = copy_fixture("records", "keep_module_ids");
+      build(&context).await;
+      let prev_ids = module_ids(&context);
+      assert_eq!(prev_ids.len(), 3);
+
+      // the new module is visited first, which would shift the natural ids without records
+      std::fs::write(context.join("src/index.js"), "import './c';\nimport './a';").unwrap();
+      let compiler = build(&context).await;
+      let ids = module_ids(&context);
+      assert_eq!(ids["./src/index.js"], prev_ids["./src/index.js"]);
+      assert_eq!(ids["./src/a.js"], prev_ids["./src/a.js"]);
+      assert_ne!(ids["./src/c.js"], ids["./src/index.js"]);
+      assert_ne!(ids["./src/c.js"], ids["./src/a.js"]);
+      assert!(!ids.contains_key("./src/b.js"));
+
+      assert!(compiler.compilation.get_warnings().any(|warning| {
+        warning.code.as_deref() == Some("Records Warning") && warning.message.contains("./src/b.js")
+      }));
+    })
+    .await;
+  }
+
+  #[tokio::test(flavor = "multi_thread")]
+  async fn records_keep_chunk_ids() {
+    within_compiler_context_for_testing(async move {
+      let context = copy_fixture("records", "keep_chunk_ids");
+      std::fs::write(
+        context.join("src/index.js"),
+        "import('./a');\nimport('./b');",
+      )
+      .unwrap();
+      build(&context).await;
+      let prev_ids = chunk_ids(&context);
+      assert!(prev_ids.contains_key("./src/b.js"));
+
+      // the new chunk is created first, which would shift the natural ids without records
+      std::fs::write(
+        context.join("src/index.js"),
+        "import('./c');\nimport('./a');",
+      )
+      .unwrap();
+      build(&context).await;
+      let ids = chunk_ids(&context);
+      assert_eq!(ids["./src/a.js"], prev_ids["./src/a.js"]);
+      assert_ne!(ids["./src/c.js"], ids["./src/a.js"]);
+      assert!(!ids.contains_key("./src/b.js"));
+    })
+    .await;
+  }
+}
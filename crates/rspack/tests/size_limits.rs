@@ -0,0 +1,164 @@
+#[cfg(test)]
+mod tests {
+  use std::sync::Arc;
+
+  use rspack::builder::Builder as _;
+  use rspack_core::{Compiler, Mode, PluginExt as _};
+  use rspack_fs::MemoryFileSystem;
+  use rspack_paths::Utf8Path;
+  use rspack_plugin_size_limits::{
+    AssetSizeLimit, SizeLimitsCompression, SizeLimitsPlugin, SizeLimitsPluginOptions,
+  };
+  use rspack_tasks::within_compiler_context_for_testing;
+
+  fn options() -> SizeLimitsPluginOptions {
+    SizeLimitsPluginOptions {
+      asset_filter: None,
+      hints: Some("warning".to_string()),
+      max_asset_size: None,
+      max_entrypoint_size: None,
+      max_async_chunk_group_size: None,
+      asset_size_limits: vec![],
+      compression: None,
+    }
+  }
+
+  async fn build(options: SizeLimitsPluginOptions) -> Compiler {
+    let mut compiler = Compiler::builder()
+      .context(Utf8Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/fixtures/size-limits"))
+      .mode(Mode::None)
+      .entry("main", "./src/index.js")
+      .output_filesystem(Arc::new(MemoryFileSystem::default()))
+      .plugin(SizeLimitsPlugin::new(options).boxed())
+      .build()
+      .unwrap();
+    compiler.build().await.unwrap();
+    assert!(compiler.compilation.get_errors().next().is_none());
+    compiler
+  }
+
+  fn warnings(compiler: &Compiler) -> Vec<String> {
+    compiler
+      .compilation
+      .get_warnings()
+      .map(|warning| warning.message.clone())
+      .collect()
+  }
+
+  fn is_over_size_limit(compiler: &Compiler, name: &str) -> Option<bool> {
+    compiler
+      .compilation
+      .assets()
+      .get(name)
+      .unwrap()
+      .info
+      .is_over_size_limit
+  }
+
+  fn async_asset(compiler: &Compiler) -> String {
+    compiler
+      .compilation
+      .assets()
+      .keys()
+      .find(|name| *name != "main.js")
+      .unwrap()
+      .clone()
+  }
+
+  #[tokio::test(flavor = "multi_thread")]
+  async fn asset_size_limits() {
+    within_compiler_context_for_testing(async {
+      let compiler = build(SizeLimitsPluginOptions {
+        asset_size_limits: vec![AssetSizeLimit {
+          test: "main.js".to_string(),
+          max_size: 10.0,
+        }],
+        ..options()
+      })
+      .await;
+
+      assert_eq!(is_over_size_limit(&compiler, "main.js"), Some(true));
+      assert_eq!(
+        is_over_size_limit(&compiler, &async_asset(&compiler)),
+        Some(false)
+      );
+      let messages = warnings(&compiler);
+      assert_eq!(messages.len(), 1);
+      assert!(messages[0].contains("main.js"));
+      assert!(messages[0].contains("exceeds its limit (10.000 bytes)"));
+    })
+    .await;
+  }
+
+  #[tokio::test(flavor = "multi_thread")]
+  async fn async_chunk_group_size() {
+    within_compiler_context_for_testing(async {
+      let compiler = build(SizeLimitsPluginOptions {
+        max_async_chunk_group_size: Some(1000.0),
+        ..options()
+      })
+      .await;
+      let async_asset = async_asset(&compiler);
+      let messages = warnings(&compiler);
+      assert_eq!(messages.len(), 1);
+      assert!(messages[0].starts_with("async chunk group size limit"));
+      assert!(messages[0].contains("\n  async ("));
+      assert!(messages[0].contains(&format!("      {async_asset}")));
+
+      let compiler = build(SizeLimitsPluginOptions {
+        max_async_chunk_group_size: Some(100_000.0),
+        ..options()
+      })
+      .await;
+      assert!(warnings(&compiler).is_empty());
+    })
+    .await;
+  }
+
+  #[tokio::test(flavor = "multi_thread")]
+  async fn compressed_size() {
+    within_compiler_context_for_testing(async {
+      let compiler = build(SizeLimitsPluginOptions {
+        max_asset_size: Some(4000.0),
+        ..options()
+      })
+      .await;
+      assert_eq!(
+        is_over_size_limit(&compiler, &async_asset(&compiler)),
+        Some(true)
+      );
+
+      for compression in [
+        SizeLimitsCompression::Gzip,
+        SizeLimitsCompression::Brotli,
+        SizeLimitsCompression::Zstd,
+      ] {
+        let compiler = build(SizeLimitsPluginOptions {
+          max_asset_size: Some(4000.0),
+          compression: Some(compression),
+          ..options()
+        })
+        .await;
+        assert_eq!(
+          is_over_size_limit(&compiler, &async_asset(&compiler)),
+          Some(false)
+        );
+        assert!(warnings(&compiler).is_empty());
+      }
+    })
+    .await;
+  }
+
+  #[test]
+  fn compressed_size_of_content() {
+    let content = "a".repeat(5000);
+    for compression in [
+      SizeLimitsCompression::Gzip,
+      SizeLimitsCompression::Brotli,
+      SizeLimitsCompression::Zstd,
+    ] {
+      let size = compression.compressed_size(content.as_bytes()).unwrap();
+      assert!(size > 0 && size < 100, "{}: {size}", compression.as_str());
+    }
+  }
+}
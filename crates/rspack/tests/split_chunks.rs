@@ -0,0 +1,207 @@
+mod common;
+
+#[cfg(test)]
+mod tests {
+  use std::sync::Arc;
+
+  use rspack::builder::{
+    Builder as _, CacheGroupOptionsBuilder, OptimizationOptionsBuilder, SplitChunksOptionsBuilder,
+  };
+  use rspack_core::{Compiler, Mode};
+  use rspack_fs::MemoryFileSystem;
+  use rspack_paths::{Utf8Path, Utf8PathBuf};
+  use rspack_plugin_split_chunks::{CacheGroupTest, ChunkFilter, ChunkNameGetter};
+  use rspack_tasks::within_compiler_context_for_testing;
+
+  use crate::common::copy_fixture;
+
+  /// Two entries sharing a module larger than the production `min_size`
+  fn fixture() -> Utf8PathBuf {
+    Utf8Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/fixtures/split-chunks")
+  }
+
+  async fn build(
+    context: &Utf8Path,
+    split_chunks: Option<SplitChunksOptionsBuilder>,
+  ) -> Vec<Vec<String>> {
+    let mut optimization = OptimizationOptionsBuilder::default();
+    optimization.minimize(false).concatenate_modules(false);
+    if let Some(split_chunks) = split_chunks {
+      optimization.split_chunks(split_chunks);
+    }
+    let mut compiler = Compiler::builder()
+      .context(context)
+      .mode(Mode::Production)
+      .entry("a", "./src/a.js")
+      .entry("b", "./src/b.js")
+      .optimization(optimization)
+      .output_filesystem(Arc::new(MemoryFileSystem::default()))
+      .build()
+      .unwrap();
+    compiler.build().await.unwrap();
+    assert!(compiler.compilation.get_errors().next().is_none());
+
+    let chunk_graph = &compiler.compilation.build_chunk_graph_artifact.chunk_graph;
+    let module_graph = compiler.compilation.get_module_graph();
+    let mut chunks = compiler
+      .compilation
+      .build_chunk_graph_artifact
+      .chunk_by_ukey
+      .keys()
+      .map(|chunk| {
+        let mut modules = chunk_graph
+          .get_chunk_modules(chunk, module_graph)
+          .into_iter()
+          .map(|module| {
+            module
+              .readable_identifier(&compiler.compilation.options.context)
+              .to_string()
+          })
+          .collect::<Vec<_>>();
+        modules.sort();
+        modules
+      })
+      .collect::<Vec<_>>();
+    chunks.sort();
+    chunks
+  }
+
+  /// The number of chunks loaded by the entry points, where `a` shares a module with each of `b`, `c`
+  /// and `d`
+  async fn build_initial_requests(split_chunks: SplitChunksOptionsBuilder) -> Vec<usize> {
+    let context =
+      Utf8Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/fixtures/split-chunks-requests");
+    let mut compiler = Compiler::builder()
+      .context(context)
+      .mode(Mode::Production)
+      .entry("a", "./src/a.js")
+      .entry("b", "./src/b.js")
+      .entry("c", "./src/c.js")
+      .entry("d", "./src/d.js")
+      .optimization(
+        OptimizationOptionsBuilder::default()
+          .minimize(false)
+          .concatenate_modules(false)
+          .split_chunks(split_chunks),
+      )
+      .output_filesystem(Arc::new(MemoryFileSystem::default()))
+      .build()
+      .unwrap();
+    compiler.build().await.unwrap();
+    assert!(compiler.compilation.get_errors().next().is_none());
+
+    let artifact = &compiler.compilation.build_chunk_graph_artifact;
+    ["a", "b", "c", "d"]
+      .into_iter()
+      .map(|name| {
+        artifact
+          .chunk_group_by_ukey
+          .expect_get(&artifact.entrypoints[name])
+          .chunks
+          .len()
+      })
+      .collect()
+  }
+
+  #[tokio::test(flavor = "multi_thread")]
+  async fn split_chunks_defaults_only_split_async_chunks() {
+    within_compiler_context_for_testing(async move {
+      let context = fixture();
+      let chunks = build(&context, None).await;
+      assert_eq!(
+        chunks,
+        vec![
+          vec!["./src/a.js".to_string(), "./src/shared.js".to_string()],
+          vec!["./src/b.js".to_string(), "./src/shared.js".to_string()],
+        ]
+      );
+    })
+    .await;
+  }
+
+  #[tokio::test(flavor = "multi_thread")]
+  async fn split_chunks_all_chunks() {
+    within_compiler_context_for_testing(async move {
+      let context = fixture();
+      let mut split_chunks = SplitChunksOptionsBuilder::default();
+      split_chunks.chunks(ChunkFilter::All);
+      let chunks = build(&context, Some(split_chunks)).await;
+      assert_eq!(
+        chunks,
+        vec![
+          vec!["./src/a.js".to_string()],
+          vec!["./src/b.js".to_string()],
+          vec!["./src/shared.js".to_string()],
+        ]
+      );
+    })
+    .await;
+  }
+
+  #[tokio::test(flavor = "multi_thread")]
+  async fn split_chunks_enforced_cache_group() {
+    within_compiler_context_for_testing(async move {
+      let context = copy_fixture("split-chunks", "enforced_cache_group");
+      std::fs::write(context.join("src/shared.js"), "export default 'shared';").unwrap();
+      let mut split_chunks = SplitChunksOptionsBuilder::default();
+      split_chunks
+        .disable_cache_group("default")
+        .disable_cache_group("defaultVendors")
+        .cache_group(
+          "shared",
+          CacheGroupOptionsBuilder::default()
+            .test(CacheGroupTest::String(
+              context.join("src/shared").to_string(),
+            ))
+            .chunk_filter(ChunkFilter::All)
+            .name(ChunkNameGetter::String("shared".to_string()))
+            .enforce(true),
+        );
+      let chunks = build(&context, Some(split_chunks)).await;
+      assert_eq!(
+        chunks,
+        vec![
+          vec!["./src/a.js".to_string()],
+          vec!["./src/b.js".to_string()],
+          vec!["./src/shared.js".to_string()],
+        ]
+      );
+    })
+    .await;
+  }
+
+  #[tokio::test(flavor = "multi_thread")]
+  async fn split_chunks_disabled() {
+    within_compiler_context_for_testing(async move {
+      let context = fixture();
+      let mut split_chunks = SplitChunksOptionsBuilder::default();
+      split_chunks.chunks(ChunkFilter::All).disabled();
+      let chunks = build(&context, Some(split_chunks)).await;
+      assert_eq!(chunks.len(), 2);
+    })
+    .await;
+  }
+
+  #[tokio::test(flavor = "multi_thread")]
+  async fn split_chunks_max_initial_requests() {
+    within_compiler_context_for_testing(async move {
+      let mut split_chunks = SplitChunksOptionsBuilder::default();
+      split_chunks.chunks(ChunkFilter::All).min_size(0.0);
+      // every shared module is split under the default limit of production
+      assert_eq!(build_initial_requests(split_chunks).await, vec![4, 2, 2, 2]);
+
+      let mut split_chunks = SplitChunksOptionsBuilder::default();
+      split_chunks
+        .chunks(ChunkFilter::All)
+        .min_size(0.0)
+        .max_initial_requests(2.0);
+      let requests = build_initial_requests(split_chunks).await;
+      assert!(
+        requests.iter().all(|requests| *requests <= 2),
+        "{requests:?}"
+      );
+      assert_eq!(requests[0], 2);
+    })
+    .await;
+  }
+}
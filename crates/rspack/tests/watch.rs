@@ -0,0 +1,104 @@
+mod common;
+
+#[cfg(test)]
+mod tests {
+  use std::{sync::Arc, time::Duration};
+
+  use rspack::builder::Builder as _;
+  use rspack_core::{Compiler, FsWatcherIgnored, FsWatcherOptions};
+  use rspack_fs::{NativeFileSystem, OverlayFileSystem, WritableFileSystem};
+  use rspack_tasks::within_compiler_context_for_testing;
+
+  use crate::common::copy_fixture;
+
+  fn main_js(compiler: &Compiler) -> String {
+    compiler
+      .compilation
+      .assets()
+      .get("main.js")
+      .unwrap()
+      .source
+      .as_ref()
+      .unwrap()
+      .source()
+      .into_string_lossy()
+      .into_owned()
+  }
+
+  #[tokio::test(flavor = "multi_thread")]
+  async fn watch_file_changes() {
+    within_compiler_context_for_testing(async move {
+      let context = copy_fixture("watch", "file_changes");
+      let mut compiler = Compiler::builder()
+        .context(context.clone())
+        .entry("main", "./src/index.js")
+        .build()
+        .unwrap();
+      let mut watching = compiler.watch(
+        FsWatcherOptions {
+          aggregate_timeout: Some(50),
+          ..Default::default()
+        },
+        FsWatcherIgnored::None,
+      );
+
+      let run = watching.next().await;
+      assert!(run.result.is_ok());
+      assert!(run.changed_files.is_empty() && run.deleted_files.is_empty());
+      assert_eq!(main_js(watching.compiler()), "console.log(1);");
+
+      tokio::time::sleep(Duration::from_millis(200)).await;
+      let index = context.join("src/index.js");
+      std::fs::write(&index, "console.log(2);").unwrap();
+
+      let run = tokio::time::timeout(Duration::from_secs(10), watching.next())
+        .await
+        .expect("should rebuild after the file changed");
+      assert!(run.result.is_ok());
+      assert!(run.changed_files.contains(index.as_str()));
+      assert_eq!(main_js(watching.compiler()), "console.log(2);");
+
+      watching.close().await.unwrap();
+    })
+    .await;
+  }
+
+  #[tokio::test(flavor = "multi_thread")]
+  async fn watch_in_memory_changes() {
+    within_compiler_context_for_testing(async move {
+      let context = copy_fixture("watch", "in_memory_changes");
+      let input_filesystem = Arc::new(OverlayFileSystem::new(Arc::new(NativeFileSystem::new(
+        false,
+      ))));
+      let mut compiler = Compiler::builder()
+        .context(context.clone())
+        .entry("main", "./src/index.js")
+        .input_filesystem(input_filesystem.clone())
+        .build()
+        .unwrap();
+      let mut watching = compiler.watch(Default::default(), FsWatcherIgnored::None);
+
+      assert!(watching.next().await.result.is_ok());
+      assert_eq!(main_js(watching.compiler()), "console.log(1);");
+
+      // e.g. an unsaved buffer of an editor
+      let index = context.join("src/index.js");
+      input_filesystem
+        .write(&index, b"console.log(3);")
+        .await
+        .unwrap();
+      let (changed_files, deleted_files) = input_filesystem.take_changes();
+      watching.invalidate(changed_files, deleted_files);
+
+      let run = watching.next().await;
+      assert!(run.result.is_ok());
+      assert!(run.changed_files.contains(index.as_str()));
+      assert_eq!(main_js(watching.compiler()), "console.log(3);");
+      // the file on disk is not modified
+      assert_eq!(std::fs::read_to_string(&index).unwrap(), "console.log(1);");
+
+      watching.close().await.unwrap();
+    })
+    .await;
+  }
+}
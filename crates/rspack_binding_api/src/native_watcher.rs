@@ -8,7 +8,7 @@ use napi::bindgen_prelude::*;
 use napi_derive::*;
 use rspack_paths::ArcPath;
 use rspack_regex::RspackRegex;
-use rspack_watcher::{FsEventKind, FsWatcher, FsWatcherIgnored, FsWatcherOptions};
+use rspack_watcher::{FsEventKind, FsWatcher, FsWatcherIgnored, FsWatcherMode, FsWatcherOptions};
 
 type JsWatcherIgnored = Either3<String, Vec<String>, RspackRegex>;
 
@@ -32,6 +32,13 @@ pub struct NativeWatcherOptions {
 
   pub aggregate_timeout: Option<u32>,
 
+  #[napi(ts_type = r#""native" | "polling" | "hybrid""#)]
+  /// How the watcher detects changes, defaults to "native".
+  pub mode: Option<String>,
+
+  /// Log the statistics of each event batch and the paths skipped by `ignored`.
+  pub debug: Option<bool>,
+
   #[napi(ts_type = "string | string[] | RegExp")]
   /// The ignored paths for the watcher.
   /// It can be a single path, an array of paths, or a regular expression.
@@ -57,20 +64,32 @@ fn timestamp_to_system_time(millis: u64) -> SystemTime {
 #[napi]
 impl NativeWatcher {
   #[napi(constructor)]
-  pub fn new(options: NativeWatcherOptions) -> Self {
+  pub fn new(options: NativeWatcherOptions) -> napi::Result<Self> {
+    let mode = match options.mode.as_deref() {
+      None | Some("native") => FsWatcherMode::Native,
+      Some("polling") => FsWatcherMode::Polling,
+      Some("hybrid") => FsWatcherMode::Hybrid,
+      Some(mode) => {
+        return Err(napi::Error::from_reason(format!(
+          "Invalid watcher mode \"{mode}\", expected \"native\", \"polling\" or \"hybrid\"."
+        )));
+      }
+    };
     let watcher = FsWatcher::new(
       FsWatcherOptions {
         follow_symlinks: options.follow_symlinks.unwrap_or(false),
         poll_interval: options.poll_interval,
         aggregate_timeout: options.aggregate_timeout,
+        mode,
+        debug: options.debug.unwrap_or(false),
       },
       to_fs_watcher_ignored(options.ignored),
     );
 
-    Self {
+    Ok(Self {
       watcher,
       closed: false,
-    }
+    })
   }
 
   #[napi]
@@ -65,6 +65,7 @@ pub struct RawOptions {
   pub node: Option<RawNodeOption>,
   pub amd: Option<String>,
   pub bail: bool,
+  pub profile: bool,
   #[napi(js_name = "__references", ts_type = "Record<string, any>")]
   pub __references: References,
   #[napi(js_name = "__virtual_files")]
@@ -81,7 +82,7 @@ impl TryFrom<RawOptions> for CompilerOptions {
     let resolve_loader = value.resolve_loader.try_into()?;
     let mode = value.mode.unwrap_or_default().into();
     let module: ModuleOptions = value.module.try_into()?;
-    let cache = normalize_raw_cache(value.cache);
+    let cache = normalize_raw_cache(value.cache)?;
     let experiments: Experiments = value.experiments.into();
     let mut incremental: IncrementalOptions = match value.incremental {
       Some(value) => match value {
@@ -146,6 +147,7 @@ impl TryFrom<RawOptions> for CompilerOptions {
       node,
       amd: value.amd,
       bail: value.bail,
+      profile: value.profile,
       __references: value.__references,
     })
   }
@@ -30,7 +30,9 @@ use napi::{
 };
 use napi_derive::napi;
 use raw_dll::{RawDllReferenceAgencyPluginOptions, RawFlagAllModulesAsUsedPluginOptions};
-use raw_ids::RawOccurrenceChunkIdsPluginOptions;
+use raw_ids::{
+  RawHashedModuleIdsPluginOptions, RawOccurrenceChunkIdsPluginOptions, RawRecordIdsPluginOptions,
+};
 use raw_lightning_css_minimizer::RawLightningCssMinimizerRspackPluginOptions;
 use raw_mf::{
   RawCollectShareEntryPluginOptions, RawModuleFederationManifestPluginOptions,
@@ -41,8 +43,9 @@ use raw_sri::RawSubresourceIntegrityPluginOptions;
 use rspack_core::{BoxPlugin, Plugin, PluginExt};
 use rspack_error::{Result, ToStringResultToRspackResultExt};
 use rspack_ids::{
-  DeterministicChunkIdsPlugin, DeterministicModuleIdsPlugin, NamedChunkIdsPlugin,
-  NamedModuleIdsPlugin, NaturalChunkIdsPlugin, NaturalModuleIdsPlugin, OccurrenceChunkIdsPlugin,
+  DeterministicChunkIdsPlugin, DeterministicModuleIdsPlugin, HashedModuleIdsPlugin,
+  NamedChunkIdsPlugin, NamedModuleIdsPlugin, NaturalChunkIdsPlugin, NaturalModuleIdsPlugin,
+  OccurrenceChunkIdsPlugin, RecordIdsPlugin,
 };
 use rspack_plugin_asset::AssetPlugin;
 use rspack_plugin_banner::BannerPlugin;
@@ -137,9 +140,10 @@ use crate::{
     JsRscServerPluginOptions,
   },
   raw_options::{
-    RawDynamicEntryPluginOptions, RawEvalDevToolModulePluginOptions, RawExternalItemWrapper,
-    RawExternalsPluginOptions, RawHttpExternalsRspackPluginOptions, RawSplitChunksOptions,
-    SourceMapDevToolPluginOptions, raw_builtins::raw_esm_lib::RawEsmLibraryPlugin,
+    RawAggressiveSplittingPluginOptions, RawDynamicEntryPluginOptions,
+    RawEvalDevToolModulePluginOptions, RawExternalItemWrapper, RawExternalsPluginOptions,
+    RawHttpExternalsRspackPluginOptions, RawSplitChunksOptions, SourceMapDevToolPluginOptions,
+    raw_builtins::raw_esm_lib::RawEsmLibraryPlugin,
   },
   rslib::RawRslibPluginOptions,
 };
@@ -170,6 +174,7 @@ pub enum BuiltinPluginName {
   EsmLibraryPlugin,
   HotModuleReplacementPlugin,
   LimitChunkCountPlugin,
+  AggressiveSplittingPlugin,
   WorkerPlugin,
   WebWorkerTemplatePlugin,
   MergeDuplicateChunksPlugin,
@@ -192,6 +197,8 @@ pub enum BuiltinPluginName {
   NamedChunkIdsPlugin,
   DeterministicChunkIdsPlugin,
   OccurrenceChunkIdsPlugin,
+  HashedModuleIdsPlugin,
+  RecordIdsPlugin,
   RealContentHashPlugin,
   RemoveEmptyChunksPlugin,
   EnsureChunkConditionsPlugin,
@@ -458,6 +465,13 @@ impl<'a> BuiltinPlugin<'a> {
         .boxed();
         plugins.push(plugin);
       }
+      BuiltinPluginName::AggressiveSplittingPlugin => {
+        use rspack_plugin_split_chunks::AggressiveSplittingPlugin;
+        let options = downcast_into::<RawAggressiveSplittingPluginOptions>(self.options)
+          .map_err(|report| napi::Error::from_reason(report.to_string()))?
+          .into();
+        plugins.push(AggressiveSplittingPlugin::new(options).boxed());
+      }
       BuiltinPluginName::WorkerPlugin => {
         plugins.push(WorkerPlugin::default().boxed());
       }
@@ -575,6 +589,24 @@ impl<'a> BuiltinPlugin<'a> {
         )
         .boxed(),
       ),
+      BuiltinPluginName::HashedModuleIdsPlugin => {
+        let plugin = HashedModuleIdsPlugin::new(
+          downcast_into::<RawHashedModuleIdsPluginOptions>(self.options)
+            .map_err(|report| napi::Error::from_reason(report.to_string()))?
+            .try_into()
+            .map_err(|report: rspack_error::Error| napi::Error::from_reason(report.to_string()))?,
+        )
+        .boxed();
+        plugins.push(plugin);
+      }
+      BuiltinPluginName::RecordIdsPlugin => plugins.push(
+        RecordIdsPlugin::new(
+          downcast_into::<RawRecordIdsPluginOptions>(self.options)
+            .map_err(|report| napi::Error::from_reason(report.to_string()))?
+            .into(),
+        )
+        .boxed(),
+      ),
       BuiltinPluginName::RealContentHashPlugin => {
         plugins.push(RealContentHashPlugin::default().boxed())
       }
@@ -683,7 +715,8 @@ impl<'a> BuiltinPlugin<'a> {
         let plugin = SizeLimitsPlugin::new(
           downcast_into::<RawSizeLimitsPluginOptions>(self.options)
             .map_err(|report| napi::Error::from_reason(report.to_string()))?
-            .into(),
+            .try_into()
+            .map_err(|report: rspack_error::Error| napi::Error::from_reason(report.to_string()))?,
         )
         .boxed();
         plugins.push(plugin)
@@ -760,7 +793,8 @@ impl<'a> BuiltinPlugin<'a> {
         CircularDependencyRspackPlugin::new(
           downcast_into::<RawCircularDependencyRspackPluginOptions>(self.options)
             .map_err(|report| napi::Error::from_reason(report.to_string()))?
-            .into(),
+            .try_into()
+            .map_err(|report: rspack_error::Error| napi::Error::from_reason(report.to_string()))?,
         )
         .boxed(),
       ),
@@ -1,10 +1,12 @@
 use derive_more::Debug;
 use napi::{Either, bindgen_prelude::FnArgs};
 use napi_derive::napi;
+use rspack_error::Result;
 use rspack_napi::threadsafe_function::ThreadsafeFunction;
 use rspack_plugin_circular_dependencies::{
   CircularDependencyIgnoredConnection, CircularDependencyIgnoredConnectionEntry,
-  CircularDependencyRspackPluginOptions, CompilationHookFn, CycleHandlerFn,
+  CircularDependencyReportMode, CircularDependencyRspackPluginOptions, CompilationHookFn,
+  CycleHandlerFn,
 };
 use rspack_regex::RspackRegex;
 
@@ -28,6 +30,12 @@ pub struct RawCircularDependencyRspackPluginOptions {
   pub exclude: Option<RspackRegex>,
   #[napi(ts_type = "Array<[string | RegExp, string | RegExp]>")]
   pub ignored_connections: Option<Vec<(ConnectionPattern, ConnectionPattern)>>,
+  #[napi(ts_type = "'cycles' | 'components'")]
+  pub mode: Option<String>,
+  pub init_only: Option<bool>,
+  pub max_cycles: Option<u32>,
+  pub baseline_path: Option<String>,
+  pub update_baseline: Option<bool>,
   #[debug(skip)]
   #[napi(ts_type = "(entrypoint: Module, modules: string[]) => void")]
   pub on_detected: Option<ThreadsafeFunction<FnArgs<CycleHookParams>, ()>>,
@@ -42,8 +50,10 @@ pub struct RawCircularDependencyRspackPluginOptions {
   pub on_end: Option<ThreadsafeFunction<(), ()>>,
 }
 
-impl From<RawCircularDependencyRspackPluginOptions> for CircularDependencyRspackPluginOptions {
-  fn from(value: RawCircularDependencyRspackPluginOptions) -> Self {
+impl TryFrom<RawCircularDependencyRspackPluginOptions> for CircularDependencyRspackPluginOptions {
+  type Error = rspack_error::Error;
+
+  fn try_from(value: RawCircularDependencyRspackPluginOptions) -> Result<Self> {
     // This explicit cast is needed because Rust otherwise infers an incompatible type
     // for the closure compared to the field in the options object.
 
@@ -98,7 +108,7 @@ impl From<RawCircularDependencyRspackPluginOptions> for CircularDependencyRspack
       _ => None,
     };
 
-    Self {
+    Ok(Self {
       fail_on_error: value.fail_on_error.unwrap_or(false),
       exclude: value.exclude,
       ignored_connections: value.ignored_connections.map(|connections| {
@@ -112,10 +122,19 @@ impl From<RawCircularDependencyRspackPluginOptions> for CircularDependencyRspack
           })
           .collect()
       }),
+      mode: value
+        .mode
+        .map(|mode| CircularDependencyReportMode::try_from(mode.as_str()))
+        .transpose()?
+        .unwrap_or_default(),
+      init_only: value.init_only.unwrap_or(false),
+      max_cycles: value.max_cycles.map(|max_cycles| max_cycles as usize),
+      baseline_path: value.baseline_path.map(Into::into),
+      update_baseline: value.update_baseline.unwrap_or(false),
       on_detected,
       on_ignored,
       on_start,
       on_end,
-    }
+    })
   }
 }
@@ -93,9 +93,14 @@ pub struct RawCopyPattern {
   /// @default undefined
   #[debug(skip)]
   #[napi(
-    ts_type = "{ transformer: (input: Buffer, absoluteFilename: string) => string | Buffer | Promise<string> | Promise<Buffer>  } | ((input: Buffer, absoluteFilename: string) => string | Buffer | Promise<string> | Promise<Buffer>)"
+    ts_type = "{ transformer: (input: Buffer, absoluteFilename: string) => string | Buffer | Promise<string> | Promise<Buffer>; cache?: boolean } | ((input: Buffer, absoluteFilename: string) => string | Buffer | Promise<string> | Promise<Buffer>)"
   )]
   pub transform: Option<RawTransformer>,
+  /// Whether to reuse the results of the unchanged files on rebuild. The results of
+  /// `transform` are only reused when this is `true`, as the transformer may depend on
+  /// other inputs than the file content.
+  /// @default `true` without `transform`, `false` otherwise
+  pub cache: Option<bool>,
 }
 
 #[derive(Debug, Clone)]
@@ -154,6 +159,7 @@ impl From<RawCopyPattern> for CopyPattern {
       info,
       copy_permissions,
       transform,
+      cache,
     } = value;
 
     Self {
@@ -213,7 +219,7 @@ impl From<RawCopyPattern> for CopyPattern {
           })
         })
       }),
-      cache: None,
+      cache,
     }
   }
 }
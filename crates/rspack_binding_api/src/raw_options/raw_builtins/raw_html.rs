@@ -1,18 +1,24 @@
 use std::str::FromStr;
 
-use napi::bindgen_prelude::{Either3, Promise};
+use napi::{
+  Either,
+  bindgen_prelude::{Either3, Promise},
+};
 use napi_derive::napi;
 use rspack_napi::threadsafe_function::ThreadsafeFunction;
 use rspack_plugin_html::config::{
-  HtmlChunkSortMode, HtmlInject, HtmlRspackPluginBaseOptions, HtmlRspackPluginOptions,
-  HtmlScriptLoading, TemplateParameterFn, TemplateParameters, TemplateRenderFn,
+  HtmlChunkSortFn, HtmlChunkSortMode, HtmlInject, HtmlInlineOptions, HtmlResourceHintsOptions,
+  HtmlRspackPluginBaseOptions, HtmlRspackPluginOptions, HtmlScriptLoading, TemplateParameterFn,
+  TemplateParameters, TemplateRenderFn,
 };
 use rustc_hash::FxHashMap as HashMap;
 
+use crate::asset_condition::{RawAssetConditions, into_asset_conditions};
+
 pub type RawHtmlScriptLoading = String;
 pub type RawHtmlInject = String;
 pub type RawHtmlFilename = Vec<String>;
-type RawChunkSortMode = String;
+type RawChunkSortMode = Either<String, ThreadsafeFunction<Vec<String>, Vec<String>>>;
 
 type RawTemplateRenderFn = ThreadsafeFunction<String, Promise<String>>;
 
@@ -44,8 +50,12 @@ pub struct RawHtmlRspackPluginOptions {
   /// entry_chunk_name (only entry chunks are supported)
   pub chunks: Option<Vec<String>>,
   pub exclude_chunks: Option<Vec<String>>,
-  #[napi(ts_type = "\"auto\" | \"manual\"")]
+  #[napi(
+    ts_type = "\"auto\" | \"manual\" | \"dependency\" | ((chunkNames: string[]) => string[])"
+  )]
   pub chunks_sort_mode: RawChunkSortMode,
+  pub resource_hints: Option<RawHtmlResourceHintsOptions>,
+  pub inline: Option<RawHtmlInlineOptions>,
 
   pub minify: Option<bool>,
   pub title: Option<String>,
@@ -63,8 +73,17 @@ impl From<RawHtmlRspackPluginOptions> for HtmlRspackPluginOptions {
     let script_loading =
       HtmlScriptLoading::from_str(&value.script_loading).expect("Invalid script_loading value");
 
-    let chunks_sort_mode =
-      HtmlChunkSortMode::from_str(&value.chunks_sort_mode).expect("Invalid chunks_sort_mode value");
+    let chunks_sort_mode = match value.chunks_sort_mode {
+      Either::A(mode) => {
+        HtmlChunkSortMode::from_str(&mode).expect("Invalid chunks_sort_mode value")
+      }
+      Either::B(func) => HtmlChunkSortMode::Function(HtmlChunkSortFn {
+        inner: Box::new(move |chunk_names| {
+          let f = func.clone();
+          Box::pin(async move { f.call_with_sync(chunk_names).await })
+        }),
+      }),
+    };
 
     HtmlRspackPluginOptions {
       filename: value
@@ -103,6 +122,8 @@ impl From<RawHtmlRspackPluginOptions> for HtmlRspackPluginOptions {
       chunks: value.chunks,
       exclude_chunks: value.exclude_chunks,
       chunks_sort_mode,
+      resource_hints: value.resource_hints.map(Into::into),
+      inline: value.inline.map(Into::into),
       minify: value.minify,
       title: value.title,
       favicon: value.favicon,
@@ -130,3 +151,45 @@ impl From<RawHtmlRspackPluginBaseOptions> for HtmlRspackPluginBaseOptions {
     }
   }
 }
+
+#[derive(Debug)]
+#[napi(object, object_to_js = false)]
+pub struct RawHtmlResourceHintsOptions {
+  pub preload: Option<bool>,
+  pub prefetch: Option<bool>,
+  #[napi(ts_type = "string | RegExp | (string | RegExp)[]")]
+  pub include: Option<RawAssetConditions>,
+  #[napi(ts_type = "string | RegExp | (string | RegExp)[]")]
+  pub exclude: Option<RawAssetConditions>,
+}
+
+impl From<RawHtmlResourceHintsOptions> for HtmlResourceHintsOptions {
+  fn from(value: RawHtmlResourceHintsOptions) -> Self {
+    let default = HtmlResourceHintsOptions::default();
+    HtmlResourceHintsOptions {
+      preload: value.preload.unwrap_or(default.preload),
+      prefetch: value.prefetch.unwrap_or(default.prefetch),
+      include: value.include.map(into_asset_conditions),
+      exclude: value.exclude.map(into_asset_conditions),
+    }
+  }
+}
+
+#[derive(Debug)]
+#[napi(object, object_to_js = false)]
+pub struct RawHtmlInlineOptions {
+  pub runtime_chunk: Option<bool>,
+  pub css_size_limit: Option<f64>,
+  #[napi(ts_type = "string | RegExp | (string | RegExp)[]")]
+  pub test: Option<RawAssetConditions>,
+}
+
+impl From<RawHtmlInlineOptions> for HtmlInlineOptions {
+  fn from(value: RawHtmlInlineOptions) -> Self {
+    HtmlInlineOptions {
+      runtime_chunk: value.runtime_chunk.unwrap_or_default(),
+      css_size_limit: value.css_size_limit,
+      test: value.test.map(into_asset_conditions),
+    }
+  }
+}
@@ -1,5 +1,10 @@
 use napi_derive::napi;
-use rspack_ids::OccurrenceChunkIdsPluginOptions;
+use rspack_error::Result;
+use rspack_hash::HashFunction;
+use rspack_ids::{
+  HashedModuleIdsDigest, HashedModuleIdsPluginOptions, OccurrenceChunkIdsPluginOptions,
+  RecordIdsPluginOptions,
+};
 
 #[derive(Debug)]
 #[napi(object, object_to_js = false)]
@@ -14,3 +19,50 @@ impl From<RawOccurrenceChunkIdsPluginOptions> for OccurrenceChunkIdsPluginOption
     }
   }
 }
+
+#[derive(Debug)]
+#[napi(object, object_to_js = false)]
+pub struct RawRecordIdsPluginOptions {
+  pub input_path: Option<String>,
+  pub output_path: Option<String>,
+}
+
+impl From<RawRecordIdsPluginOptions> for RecordIdsPluginOptions {
+  fn from(value: RawRecordIdsPluginOptions) -> Self {
+    Self {
+      input_path: value.input_path.map(Into::into),
+      output_path: value.output_path.map(Into::into),
+    }
+  }
+}
+
+#[derive(Debug)]
+#[napi(object, object_to_js = false)]
+pub struct RawHashedModuleIdsPluginOptions {
+  pub context: Option<String>,
+  pub hash_function: Option<String>,
+  pub hash_digest: Option<String>,
+  pub hash_digest_length: Option<u32>,
+}
+
+impl TryFrom<RawHashedModuleIdsPluginOptions> for HashedModuleIdsPluginOptions {
+  type Error = rspack_error::Error;
+
+  fn try_from(value: RawHashedModuleIdsPluginOptions) -> Result<Self> {
+    let default = Self::default();
+    Ok(Self {
+      context: value.context,
+      hash_function: match value.hash_function {
+        Some(hash_function) => HashFunction::try_from(hash_function.as_str())?,
+        None => default.hash_function,
+      },
+      hash_digest: match value.hash_digest {
+        Some(hash_digest) => HashedModuleIdsDigest::try_from(hash_digest.as_str())?,
+        None => default.hash_digest,
+      },
+      hash_digest_length: value
+        .hash_digest_length
+        .map_or(default.hash_digest_length, |length| length as usize),
+    })
+  }
+}
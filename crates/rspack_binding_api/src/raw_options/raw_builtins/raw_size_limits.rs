@@ -1,7 +1,23 @@
 use derive_more::Debug;
 use napi_derive::napi;
 use rspack_napi::threadsafe_function::ThreadsafeFunction;
-use rspack_plugin_size_limits::{AssetFilterFn, SizeLimitsPluginOptions};
+use rspack_plugin_size_limits::{AssetFilterFn, AssetSizeLimit, SizeLimitsPluginOptions};
+
+#[derive(Debug)]
+#[napi(object, object_to_js = false)]
+pub struct RawAssetSizeLimit {
+  pub test: String,
+  pub max_size: f64,
+}
+
+impl From<RawAssetSizeLimit> for AssetSizeLimit {
+  fn from(value: RawAssetSizeLimit) -> Self {
+    Self {
+      test: value.test,
+      max_size: value.max_size,
+    }
+  }
+}
 
 #[derive(Debug)]
 #[napi(object, object_to_js = false)]
@@ -13,11 +29,17 @@ pub struct RawSizeLimitsPluginOptions {
   pub hints: Option<String>,
   pub max_asset_size: Option<f64>,
   pub max_entrypoint_size: Option<f64>,
+  pub max_async_chunk_group_size: Option<f64>,
+  pub asset_size_limits: Option<Vec<RawAssetSizeLimit>>,
+  #[napi(ts_type = "\"gzip\" | \"brotli\" | \"zstd\"")]
+  pub compression: Option<String>,
 }
 
-impl From<RawSizeLimitsPluginOptions> for SizeLimitsPluginOptions {
-  fn from(value: RawSizeLimitsPluginOptions) -> Self {
-    SizeLimitsPluginOptions {
+impl TryFrom<RawSizeLimitsPluginOptions> for SizeLimitsPluginOptions {
+  type Error = rspack_error::Error;
+
+  fn try_from(value: RawSizeLimitsPluginOptions) -> Result<Self, rspack_error::Error> {
+    Ok(SizeLimitsPluginOptions {
       asset_filter: value.asset_filter.map(|asset_filter| {
         let asset_filter_fn: AssetFilterFn = Box::new(move |name| {
           let f = asset_filter.clone();
@@ -29,6 +51,14 @@ impl From<RawSizeLimitsPluginOptions> for SizeLimitsPluginOptions {
       hints: value.hints,
       max_asset_size: value.max_asset_size,
       max_entrypoint_size: value.max_entrypoint_size,
-    }
+      max_async_chunk_group_size: value.max_async_chunk_group_size,
+      asset_size_limits: value
+        .asset_size_limits
+        .unwrap_or_default()
+        .into_iter()
+        .map(Into::into)
+        .collect(),
+      compression: value.compression.map(TryInto::try_into).transpose()?,
+    })
   }
 }
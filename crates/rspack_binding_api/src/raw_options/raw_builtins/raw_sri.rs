@@ -20,6 +20,7 @@ pub struct RawSubresourceIntegrityPluginOptions {
   pub hash_func_names: Vec<String>,
   #[napi(ts_type = "\"JavaScript\" | \"Native\" | \"Disabled\"")]
   pub html_plugin: String,
+  pub manifest: Option<String>,
 }
 
 impl TryFrom<RawSubresourceIntegrityPluginOptions> for SubresourceIntegrityPluginOptions {
@@ -48,6 +49,7 @@ impl TryFrom<RawSubresourceIntegrityPluginOptions> for SubresourceIntegrityPlugi
       },
       hash_func_names,
       html_plugin,
+      manifest: options.manifest,
     })
   }
 }
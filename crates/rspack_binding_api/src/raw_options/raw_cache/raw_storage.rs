@@ -1,5 +1,6 @@
 use napi_derive::napi;
-use rspack_core::cache::persistent::storage::StorageOptions;
+use rspack_core::cache::persistent::storage::{StorageCompression, StorageOptions};
+use rspack_error::error;
 
 #[derive(Debug, Default)]
 #[napi(object)]
@@ -7,15 +8,31 @@ pub struct RawStorageOptions {
   #[napi(ts_type = r#""filesystem""#)]
   pub r#type: String,
   pub directory: String,
+  #[napi(ts_type = r#""zstd" | "lz4""#)]
+  pub compression: Option<String>,
+  pub max_size: Option<f64>,
+  pub seed_directory: Option<String>,
 }
 
-impl From<RawStorageOptions> for StorageOptions {
-  fn from(value: RawStorageOptions) -> Self {
+impl TryFrom<RawStorageOptions> for StorageOptions {
+  type Error = rspack_error::Error;
+
+  fn try_from(value: RawStorageOptions) -> Result<Self, Self::Error> {
     match value.r#type.as_str() {
-      "filesystem" => StorageOptions::FileSystem {
+      "filesystem" => Ok(StorageOptions::FileSystem {
         directory: value.directory.into(),
-      },
-      s => panic!("unsupported storage type {s}"),
+        compression: value
+          .compression
+          .map(|c| match c.as_str() {
+            "zstd" => Ok(StorageCompression::Zstd),
+            "lz4" => Ok(StorageCompression::Lz4),
+            s => Err(error!("invalid cache.storage.compression: {s}")),
+          })
+          .transpose()?,
+        max_size: value.max_size.map(|s| s as u64),
+        seed_directory: value.seed_directory.map(Into::into),
+      }),
+      s => Err(error!("invalid cache.storage.type: {s}")),
     }
   }
 }
@@ -1,3 +1,4 @@
+mod raw_aggressive_splitting;
 mod raw_split_chunk_cache_group_test;
 mod raw_split_chunk_chunks;
 mod raw_split_chunk_name;
@@ -8,6 +9,7 @@ use std::sync::Arc;
 use derive_more::Debug;
 use napi::{Either, JsString, bindgen_prelude::Either3};
 use napi_derive::napi;
+pub use raw_aggressive_splitting::RawAggressiveSplittingPluginOptions;
 use raw_split_chunk_name::{RawChunkOptionName, normalize_raw_chunk_name};
 use rspack_core::{DEFAULT_DELIMITER, Filename, SourceType};
 use rspack_napi::{string::JsStringExt, threadsafe_function::ThreadsafeFunction};
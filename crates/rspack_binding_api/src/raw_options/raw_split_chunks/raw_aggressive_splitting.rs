@@ -0,0 +1,35 @@
+use napi::Either;
+use napi_derive::napi;
+use rspack_core::SourceType;
+use rspack_plugin_split_chunks::{AggressiveSplittingPluginOptions, SplitChunkSizes};
+
+use super::raw_split_chunk_size::RawSplitChunkSizes;
+
+#[napi(object, object_to_js = false)]
+#[derive(Debug)]
+pub struct RawAggressiveSplittingPluginOptions {
+  pub min_size: Option<Either<f64, RawSplitChunkSizes>>,
+  pub max_size: Option<Either<f64, RawSplitChunkSizes>>,
+  pub default_size_types: Vec<String>,
+}
+
+impl From<RawAggressiveSplittingPluginOptions> for AggressiveSplittingPluginOptions {
+  fn from(value: RawAggressiveSplittingPluginOptions) -> Self {
+    let default = Self::default();
+    let default_size_types = value
+      .default_size_types
+      .iter()
+      .map(|size_type| SourceType::from(size_type.as_str()))
+      .collect::<Vec<_>>();
+    let create_sizes = |size: Option<Either<f64, RawSplitChunkSizes>>, default_sizes| match size {
+      Some(Either::A(size)) => SplitChunkSizes::with_initial_value(&default_size_types, size),
+      Some(Either::B(sizes)) => sizes.into(),
+      None => default_sizes,
+    };
+
+    Self {
+      min_size: create_sizes(value.min_size, default.min_size),
+      max_size: create_sizes(value.max_size, default.max_size),
+    }
+  }
+}
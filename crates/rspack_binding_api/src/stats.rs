@@ -562,6 +562,7 @@ pub struct JsStatsModule<'a> {
   #[napi(ts_type = "string | Array<string>")]
   pub used_exports: Option<Either<AtomWrapper, AtomVecWrapper>>,
   pub modules: Option<Vec<JsStatsModule<'a>>>,
+  pub profile: Option<JsStatsModuleProfile>,
 }
 
 impl<'a> TryFrom<StatsModule<'a>> for JsStatsModule<'a> {
@@ -665,23 +666,83 @@ impl<'a> TryFrom<StatsModule<'a>> for JsStatsModule<'a> {
         StatsUsedExports::Null => JsStatsUsedExports::A(AtomWrapper::new("null".into())),
       }),
       modules,
+      profile: stats.profile.map(Into::into),
     })
   }
 }
 
 #[napi(object, object_from_js = false)]
 pub struct JsStatsModuleProfile {
-  // use f64 to make js side as a number type
+  pub total: f64,
+  pub resolving: f64,
   pub factory: f64,
   pub building: f64,
+  pub loaders: Vec<JsStatsLoaderProfile>,
+  pub parsing: f64,
+  pub code_generation: f64,
+  pub hashing: f64,
 }
 
 impl From<rspack_core::StatsModuleProfile> for JsStatsModuleProfile {
   fn from(value: rspack_core::StatsModuleProfile) -> Self {
     Self {
-      // The time is short and no data will be lost when converting from u64 to f64
-      factory: value.factory as f64,
-      building: value.building as f64,
+      total: value.total,
+      resolving: value.resolving,
+      factory: value.factory,
+      building: value.building,
+      loaders: value.loaders.into_iter().map(Into::into).collect(),
+      parsing: value.parsing,
+      code_generation: value.code_generation,
+      hashing: value.hashing,
+    }
+  }
+}
+
+#[napi(object, object_from_js = false)]
+pub struct JsStatsLoaderProfile {
+  pub loader: String,
+  pub pitch: f64,
+  pub normal: f64,
+}
+
+impl From<rspack_core::StatsLoaderProfile> for JsStatsLoaderProfile {
+  fn from(value: rspack_core::StatsLoaderProfile) -> Self {
+    Self {
+      loader: value.loader,
+      pitch: value.pitch,
+      normal: value.normal,
+    }
+  }
+}
+
+#[napi(object, object_from_js = false)]
+pub struct JsStatsProfileModule<'a> {
+  #[napi(ts_type = "string")]
+  pub identifier: JsIdentifier,
+  #[napi(ts_type = "string")]
+  pub name: CowStrWrapper<'a>,
+  pub profile: JsStatsModuleProfile,
+}
+
+#[napi(object, object_from_js = false)]
+pub struct JsStatsProfile<'a> {
+  pub modules: Vec<JsStatsProfileModule<'a>>,
+  pub loaders: Vec<JsStatsLoaderProfile>,
+}
+
+impl<'a> From<rspack_core::StatsProfile<'a>> for JsStatsProfile<'a> {
+  fn from(value: rspack_core::StatsProfile<'a>) -> Self {
+    Self {
+      modules: value
+        .modules
+        .into_iter()
+        .map(|module| JsStatsProfileModule {
+          identifier: module.identifier.into(),
+          name: CowStrWrapper::new(module.name),
+          profile: module.profile.into(),
+        })
+        .collect(),
+      loaders: value.loaders.into_iter().map(Into::into).collect(),
     }
   }
 }
@@ -971,6 +1032,7 @@ pub struct JsStatsOptions {
   pub module_assets: bool,
   pub nested_modules: bool,
   pub optimization_bailout: bool,
+  pub profile: bool,
   pub provided_exports: bool,
   pub reasons: bool,
   pub source: bool,
@@ -1003,6 +1065,7 @@ impl From<JsStatsOptions> for ExtendedStatsOptions {
       module_assets: value.module_assets,
       nested_modules: value.nested_modules,
       optimization_bailout: value.optimization_bailout,
+      profile: value.profile,
       provided_exports: value.provided_exports,
       reasons: value.reasons,
       source: value.source,
@@ -1031,6 +1094,7 @@ pub struct JsStatsCompilation<'a> {
   #[napi(ts_type = "Array<JsStatsModule>")]
   pub modules: Option<napi_value>,
   pub named_chunk_groups: Option<Vec<JsStatsChunkGroup<'a>>>,
+  pub profile: Option<JsStatsProfile<'a>>,
   #[napi(ts_type = "Array<JsStatsError>")]
   pub warnings: napi_value,
 }
@@ -1112,6 +1176,11 @@ impl JsStats {
       self.named_chunk_groups(options.chunk_group_auxiliary, options.chunk_group_children)
     });
 
+    let profile = options
+      .profile
+      .then(|| self.inner.get_profile().map(Into::into))
+      .flatten();
+
     let errors = self.errors(env)?;
 
     let warnings = self.warnings(env)?;
@@ -1125,6 +1194,7 @@ impl JsStats {
       hash,
       modules,
       named_chunk_groups,
+      profile,
       warnings,
     }))
   }
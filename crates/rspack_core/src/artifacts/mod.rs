@@ -16,7 +16,9 @@ mod imported_by_defer_modules_artifact;
 mod module_graph_cache_artifact;
 mod module_ids_artifact;
 mod process_runtime_requirements_cache_artifact;
+mod records_artifact;
 mod side_effects_do_optimize_artifact;
+mod split_chunks_artifact;
 
 use std::mem;
 
@@ -99,4 +101,6 @@ pub use imported_by_defer_modules_artifact::ImportedByDeferModulesArtifact;
 pub use module_graph_cache_artifact::*;
 pub use module_ids_artifact::ModuleIdsArtifact;
 pub use process_runtime_requirements_cache_artifact::ProcessRuntimeRequirementsCacheArtifact;
+pub use records_artifact::RecordsArtifact;
 pub use side_effects_do_optimize_artifact::*;
+pub use split_chunks_artifact::SplitChunksArtifact;
@@ -0,0 +1,36 @@
+use std::collections::BTreeMap;
+
+use rspack_error::{Result, error};
+use serde::{Deserialize, Serialize, de::DeserializeOwned};
+use serde_json::Value;
+
+/// The records persisted between builds by `recordsPath`, shared by the plugins.
+///
+/// Each plugin keeps its records under its own key, e.g. `RecordIdsPlugin` reads and writes the
+/// file with the ids under `modules` and `chunks`, so the other plugins should update their
+/// records before `afterEmit`. The keys are sorted so the file is stable and diff-friendly.
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct RecordsArtifact {
+  records: BTreeMap<String, Value>,
+}
+
+impl RecordsArtifact {
+  /// Returns the records of the key, or `None` if there are no records of the key.
+  pub fn get<T: DeserializeOwned>(&self, key: &str) -> Result<Option<T>> {
+    self
+      .records
+      .get(key)
+      .map(|value| {
+        T::deserialize(value).map_err(|e| error!("Failed to parse records of {key}: {e}"))
+      })
+      .transpose()
+  }
+
+  pub fn set<T: Serialize>(&mut self, key: &str, value: &T) -> Result<()> {
+    let value = serde_json::to_value(value)
+      .map_err(|e| error!("Failed to serialize records of {key}: {e}"))?;
+    self.records.insert(key.to_string(), value);
+    Ok(())
+  }
+}
@@ -0,0 +1,47 @@
+use rspack_collections::{IdentifierMap, UkeyMap};
+
+use crate::{ChunkUkey, ModuleIdentifier};
+
+/// The split chunks cache group key of each module moved into a chunk by `SplitChunksPlugin`.
+#[derive(Debug, Default, Clone)]
+pub struct SplitChunksArtifact {
+  cache_groups: UkeyMap<ChunkUkey, IdentifierMap<String>>,
+}
+
+impl SplitChunksArtifact {
+  pub fn cache_groups(&self, chunk: &ChunkUkey) -> Option<&IdentifierMap<String>> {
+    self.cache_groups.get(chunk)
+  }
+
+  pub fn cache_group(&self, chunk: &ChunkUkey, module: &ModuleIdentifier) -> Option<&str> {
+    self
+      .cache_groups
+      .get(chunk)
+      .and_then(|cache_groups| cache_groups.get(module))
+      .map(String::as_str)
+  }
+
+  pub fn set_cache_group(
+    &mut self,
+    chunk: ChunkUkey,
+    module: ModuleIdentifier,
+    cache_group: String,
+  ) {
+    self
+      .cache_groups
+      .entry(chunk)
+      .or_default()
+      .insert(module, cache_group);
+  }
+
+  /// Moves the cache groups of chunk `b` into chunk `a`, when `b` is integrated into `a`.
+  pub fn integrate_chunks(&mut self, a: &ChunkUkey, b: &ChunkUkey) {
+    if let Some(cache_groups) = self.cache_groups.remove(b) {
+      self
+        .cache_groups
+        .entry(*a)
+        .or_default()
+        .extend(cache_groups);
+    }
+  }
+}
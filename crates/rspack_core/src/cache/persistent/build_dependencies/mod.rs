@@ -102,6 +102,7 @@ mod test {
   use std::{path::PathBuf, sync::Arc};
 
   use rspack_fs::{MemoryFileSystem, WritableFileSystem};
+  use rspack_paths::ArcPath;
   use rspack_storage::Storage;
 
   use super::{
@@ -156,6 +157,7 @@ mod test {
       fs.clone(),
       storage.clone(),
       codec,
+      true,
     ));
 
     let mut build_deps = BuildDeps::new(&options, fs.clone(), snapshot.clone());
@@ -175,6 +177,13 @@ mod test {
       .await
       .expect("should validate success");
     assert!(!validate_result);
+    let invalidations = snapshot.take_invalidations();
+    assert_eq!(invalidations.len(), 1);
+    assert_eq!(
+      invalidations[0].path,
+      ArcPath::from(std::path::Path::new("/b.js"))
+    );
+    assert!(matches!(invalidations[0].scope, SnapshotScope::BUILD));
     storage.reset().await;
 
     let data = storage.load(scope).await.expect("should load success");
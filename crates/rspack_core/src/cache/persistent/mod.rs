@@ -39,6 +39,9 @@ pub struct PersistentCacheOptions {
   pub portable: bool,
   #[cacheable(with=Skip)]
   pub readonly: bool,
+  /// Log the invalidated paths and the strategies which decided them
+  #[cacheable(with=Skip)]
+  pub explain: bool,
 }
 
 /// Persistent cache implementation
@@ -47,6 +50,7 @@ pub struct PersistentCache {
   initialized: bool,
   valid: bool,
   readonly: bool,
+  explain: bool,
   build_deps: BuildDeps,
   snapshot: Arc<Snapshot>,
   make_occasion: MakeOccasion,
@@ -92,12 +96,14 @@ impl PersistentCache {
       input_filesystem.clone(),
       storage.clone(),
       codec.clone(),
+      option.explain,
     ));
 
     Self {
       initialized: false,
       valid: false,
       readonly: option.readonly,
+      explain: option.explain,
       build_deps: BuildDeps::new(
         &option.build_dependencies,
         input_filesystem,
@@ -252,6 +258,39 @@ impl Cache for PersistentCache {
     }
 
     let logger = compilation.get_logger("rspack.persistentCache");
+    // the eviction of an async save is reported by the next compilation
+    let evicted_size = self.storage.take_evicted_size();
+    if evicted_size > 0 {
+      logger.info(format!(
+        "evicted {} of least recently used cache to fit storage.maxSize",
+        rspack_util::size::format_size(evicted_size as f64)
+      ));
+    }
+    let invalidations = self.snapshot.take_invalidations();
+    if self.explain && !invalidations.is_empty() {
+      let (build_invalidations, invalidations): (Vec<_>, Vec<_>) = invalidations
+        .into_iter()
+        .partition(|item| matches!(item.scope, SnapshotScope::BUILD));
+      if !build_invalidations.is_empty() {
+        logger.group("cache fully invalidated by build dependencies");
+        for item in build_invalidations {
+          logger.info(format!("{}: {}", item.path.display(), item.reason));
+        }
+        logger.group_end();
+      }
+      if !invalidations.is_empty() {
+        logger.group(format!("cache invalidated {} paths", invalidations.len()));
+        for item in invalidations {
+          logger.info(format!(
+            "{}: {} ({})",
+            item.path.display(),
+            item.reason,
+            item.scope.name()
+          ));
+        }
+        logger.group_end();
+      }
+    }
     for msg in std::mem::take(&mut self.warnings) {
       logger.warn(msg);
     }
@@ -289,3 +328,40 @@ impl Cache for PersistentCache {
     }
   }
 }
+
+#[cfg(test)]
+mod tests {
+  use std::path::PathBuf;
+
+  use super::{
+    PersistentCacheOptions, codec::CacheCodec, snapshot::SnapshotOptions, storage::StorageOptions,
+  };
+
+  fn options(max_size: Option<u64>) -> PersistentCacheOptions {
+    PersistentCacheOptions {
+      build_dependencies: vec![],
+      version: String::new(),
+      snapshot: SnapshotOptions::new(vec![], vec![], vec![]),
+      storage: StorageOptions::FileSystem {
+        directory: PathBuf::from("/cache"),
+        compression: None,
+        max_size,
+        seed_directory: None,
+      },
+      portable: false,
+      readonly: false,
+      explain: false,
+    }
+  }
+
+  #[test]
+  fn should_max_size_not_change_version() {
+    let codec = CacheCodec::new(None);
+    let without_budget = codec.encode(&options(None)).unwrap();
+    assert_eq!(codec.encode(&options(Some(1024))).unwrap(), without_budget);
+    assert_eq!(
+      codec.encode(&options(Some(1024 * 1024))).unwrap(),
+      without_budget
+    );
+  }
+}
@@ -2,7 +2,7 @@ mod option;
 mod scope;
 mod strategy;
 
-use std::sync::Arc;
+use std::sync::{Arc, Mutex};
 
 use rspack_error::Result;
 use rspack_fs::ReadableFileSystem;
@@ -12,11 +12,19 @@ use self::strategy::{StrategyHelper, ValidateResult};
 pub use self::{
   option::{PathMatcher, SnapshotOptions},
   scope::SnapshotScope,
-  strategy::Strategy,
+  strategy::{InvalidateReason, Strategy},
 };
 use super::{codec::CacheCodec, storage::Storage};
 use crate::FutureConsumer;
 
+/// A path invalidated by snapshot
+#[derive(Debug, Clone)]
+pub struct Invalidation {
+  pub scope: SnapshotScope,
+  pub path: ArcPath,
+  pub reason: InvalidateReason,
+}
+
 /// Snapshot is used to check if files have been modified or deleted.
 ///
 /// Snapshot will generate `Strategy` for target file, and check the modification
@@ -27,6 +35,10 @@ pub struct Snapshot {
   fs: Arc<dyn ReadableFileSystem>,
   storage: Arc<dyn Storage>,
   codec: Arc<CacheCodec>,
+  /// Whether to collect the invalidated paths found by `calc_modified_paths`
+  explain: bool,
+  /// The invalidated paths found by `calc_modified_paths`, used to explain cache misses.
+  invalidations: Mutex<Vec<Invalidation>>,
 }
 
 impl Snapshot {
@@ -35,12 +47,15 @@ impl Snapshot {
     fs: Arc<dyn ReadableFileSystem>,
     storage: Arc<dyn Storage>,
     codec: Arc<CacheCodec>,
+    explain: bool,
   ) -> Self {
     Self {
       options: Arc::new(options),
       fs,
       storage,
       codec,
+      explain,
+      invalidations: Default::default(),
     }
   }
 
@@ -109,8 +124,10 @@ impl Snapshot {
     let mut modified_path = ArcPathSet::default();
     let mut deleted_path = ArcPathSet::default();
     let mut no_change_path = ArcPathSet::default();
+    let mut invalidations = vec![];
     let helper = Arc::new(StrategyHelper::new(self.fs.clone(), self.options.clone()));
     let codec = self.codec.clone();
+    let explain = self.explain;
 
     let data = self.storage.load(scope.name()).await?;
     let is_hot_start = !data.is_empty();
@@ -123,24 +140,55 @@ impl Snapshot {
           let path: ArcPath = codec.decode(&key).expect("should decode success");
           let strategy: Strategy = codec.decode(&value).expect("should decode success");
           let validate = helper.validate(&path, &strategy).await;
-          (path, validate)
+          let reason = if explain {
+            InvalidateReason::new(&strategy, &validate)
+          } else {
+            None
+          };
+          (path, validate, reason)
         }
       })
-      .fut_consume(|(path, validate)| match validate {
-        ValidateResult::Modified => {
-          modified_path.insert(path);
+      .fut_consume(|(path, validate, reason)| {
+        if let Some(reason) = reason {
+          invalidations.push(Invalidation {
+            scope,
+            path: path.clone(),
+            reason,
+          });
         }
-        ValidateResult::Deleted => {
-          deleted_path.insert(path);
-        }
-        ValidateResult::NoChanged => {
-          no_change_path.insert(path);
+        match validate {
+          ValidateResult::Modified => {
+            modified_path.insert(path);
+          }
+          ValidateResult::Deleted => {
+            deleted_path.insert(path);
+          }
+          ValidateResult::NoChanged => {
+            no_change_path.insert(path);
+          }
         }
       })
       .await;
 
+    if !invalidations.is_empty() {
+      self
+        .invalidations
+        .lock()
+        .expect("should get lock")
+        .extend(invalidations);
+    }
     Ok((is_hot_start, modified_path, deleted_path, no_change_path))
   }
+
+  /// Take the invalidated paths found since the last call, sorted by path
+  ///
+  /// The paths are only collected when the snapshot is created with `explain`.
+  pub fn take_invalidations(&self) -> Vec<Invalidation> {
+    let mut invalidations =
+      std::mem::take(&mut *self.invalidations.lock().expect("should get lock"));
+    invalidations.sort_by(|a, b| a.path.cmp(&b.path));
+    invalidations
+  }
 }
 
 #[cfg(test)]
@@ -152,7 +200,7 @@ mod tests {
 
   use super::{
     super::{codec::CacheCodec, storage::MemoryStorage},
-    PathMatcher, Snapshot, SnapshotOptions, SnapshotScope,
+    InvalidateReason, PathMatcher, Snapshot, SnapshotOptions, SnapshotScope,
   };
 
   macro_rules! p {
@@ -199,7 +247,13 @@ mod tests {
       .await
       .unwrap();
 
-    let snapshot = Snapshot::new(options, fs.clone(), storage, codec);
+    let snapshot = Snapshot::new(
+      options.clone(),
+      fs.clone(),
+      storage.clone(),
+      codec.clone(),
+      true,
+    );
 
     snapshot
       .add(
@@ -236,6 +290,12 @@ mod tests {
     assert!(modified_paths.contains(&p!("/node_modules/project/file1")));
     assert!(!modified_paths.contains(&p!("/node_modules/lib/file1")));
     assert_eq!(no_change_paths.len(), 1);
+    let invalidations = snapshot.take_invalidations();
+    assert_eq!(invalidations.len(), 2);
+    assert_eq!(invalidations[0].path, p!("/file1"));
+    assert_eq!(invalidations[0].reason, InvalidateReason::ContentHash);
+    assert_eq!(invalidations[1].path, p!("/node_modules/project/file1"));
+    assert_eq!(invalidations[1].reason, InvalidateReason::ContentHash);
 
     fs.write(
       "/node_modules/lib/package.json".into(),
@@ -257,5 +317,20 @@ mod tests {
     assert!(modified_paths.contains(&p!("/node_modules/project/file1")));
     assert!(modified_paths.contains(&p!("/node_modules/lib/file1")));
     assert_eq!(no_change_paths.len(), 1);
+    let invalidations = snapshot.take_invalidations();
+    assert_eq!(invalidations.len(), 2);
+    assert_eq!(invalidations[0].path, p!("/node_modules/lib/file1"));
+    assert_eq!(invalidations[0].reason, InvalidateReason::PackageVersion);
+    assert!(snapshot.take_invalidations().is_empty());
+
+    // the invalidated paths are not collected without explain
+    let snapshot = Snapshot::new(options, fs.clone(), storage, codec, false);
+    fs.write("/file1".into(), "abcde".as_bytes()).await.unwrap();
+    let (_, modified_paths, _, _) = snapshot
+      .calc_modified_paths(SnapshotScope::FILE)
+      .await
+      .unwrap();
+    assert!(modified_paths.contains(&p!("/file1")));
+    assert!(snapshot.take_invalidations().is_empty());
   }
 }
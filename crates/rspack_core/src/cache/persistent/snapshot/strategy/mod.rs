@@ -71,6 +71,56 @@ pub enum ValidateResult {
   NoChanged,
 }
 
+/// The reason why a path is invalidated
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum InvalidateReason {
+  /// The version of the package containing the path has changed
+  PackageVersion,
+  /// The modified time of the file has changed and the content hash is different
+  ContentHash,
+  /// The content hash of the directory has changed
+  DirHash,
+  /// The path was missing and now exists
+  Missing,
+  /// The path has been deleted
+  Deleted,
+  /// The snapshot of the path could not be created
+  Failed,
+}
+
+impl InvalidateReason {
+  /// Get the invalidate reason from the strategy and its validate result
+  ///
+  /// Return None if the path has not changed.
+  pub fn new(strategy: &Strategy, result: &ValidateResult) -> Option<Self> {
+    match result {
+      ValidateResult::NoChanged => None,
+      ValidateResult::Deleted => Some(Self::Deleted),
+      ValidateResult::Modified => Some(match strategy {
+        Strategy::PackageVersion(_) => Self::PackageVersion,
+        Strategy::FileHash { .. } => Self::ContentHash,
+        Strategy::DirHash { .. } => Self::DirHash,
+        Strategy::Missing => Self::Missing,
+        Strategy::Failed => Self::Failed,
+      }),
+    }
+  }
+}
+
+impl std::fmt::Display for InvalidateReason {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    let reason = match self {
+      Self::PackageVersion => "package version changed",
+      Self::ContentHash => "mtime and content hash changed",
+      Self::DirHash => "directory hash changed",
+      Self::Missing => "missing file created",
+      Self::Deleted => "deleted",
+      Self::Failed => "snapshot failed",
+    };
+    write!(f, "{reason}")
+  }
+}
+
 pub struct StrategyHelper {
   fs: Arc<dyn ReadableFileSystem>,
   package_helper: Arc<PackageHelper>,
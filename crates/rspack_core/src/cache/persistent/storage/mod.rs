@@ -4,10 +4,34 @@ mod memory;
 use std::{path::PathBuf, sync::Arc};
 
 pub use memory::MemoryStorage;
-use rspack_cacheable::{cacheable, utils::PortablePath, with::As};
+use rspack_cacheable::{
+  cacheable,
+  utils::PortablePath,
+  with::{As, Skip},
+};
 use rspack_fs::IntermediateFileSystem;
 pub use rspack_storage::Storage;
-use rspack_storage::{BridgeFileSystem, PackStorage, PackStorageOptions};
+use rspack_storage::{
+  BridgeFileSystem, LoadOnlyPackStorageOptions, PackCompression, PackStorage, PackStorageOptions,
+  SeedStorage,
+};
+
+/// Codec used to compress the pack files of the filesystem storage
+#[cacheable]
+#[derive(Debug, Clone, Copy, Hash)]
+pub enum StorageCompression {
+  Zstd,
+  Lz4,
+}
+
+impl From<StorageCompression> for PackCompression {
+  fn from(value: StorageCompression) -> Self {
+    match value {
+      StorageCompression::Zstd => PackCompression::Zstd,
+      StorageCompression::Lz4 => PackCompression::Lz4,
+    }
+  }
+}
 
 /// Storage Options
 ///
@@ -19,6 +43,17 @@ pub enum StorageOptions {
   FileSystem {
     #[cacheable(with=As<PortablePath>)]
     directory: PathBuf,
+    compression: Option<StorageCompression>,
+    /// Max total size of the cache directory in bytes
+    ///
+    /// This is skipped in the cache version, so changing the budget keeps the cache.
+    #[cacheable(with=Skip)]
+    max_size: Option<u64>,
+    /// Read-only cache directory to start from when the cache directory is empty
+    ///
+    /// This is skipped in the cache version, so the seed produced without it can be used.
+    #[cacheable(with=Skip)]
+    seed_directory: Option<PathBuf>,
   },
 }
 
@@ -28,20 +63,44 @@ pub fn create_storage(
   fs: Arc<dyn IntermediateFileSystem>,
 ) -> Arc<dyn Storage> {
   match options {
-    StorageOptions::FileSystem { directory } => {
+    StorageOptions::FileSystem {
+      directory,
+      compression,
+      max_size,
+      seed_directory,
+    } => {
+      let fs = Arc::new(BridgeFileSystem(fs));
       let option = PackStorageOptions {
         temp_root: directory.join(".temp"),
         root: directory,
         clean: true,
+        max_size,
         bucket_size: 20,
         pack_size: 500 * 1024,
+        compression: compression.map(Into::into).unwrap_or_default(),
         expire: 7 * 24 * 60 * 60 * 1000,
-        fs: Arc::new(BridgeFileSystem(fs)),
+        fs: fs.clone(),
         fresh_generation: Some(1),
         release_generation: Some(2),
+        version: version.clone(),
+      };
+      let local = Arc::new(PackStorage::new(option));
+      let Some(seed_directory) = seed_directory else {
+        return local;
+      };
+      // the seed may be a read-only directory, so it is loaded without any writing
+      let seed_option = LoadOnlyPackStorageOptions {
+        root: seed_directory,
+        fs,
+        bucket_size: 20,
+        pack_size: 500 * 1024,
+        compression: compression.map(Into::into).unwrap_or_default(),
         version,
       };
-      Arc::new(PackStorage::new(option))
+      Arc::new(SeedStorage::new(
+        Arc::new(PackStorage::new_load_only(seed_option)),
+        local,
+      ))
     }
   }
 }
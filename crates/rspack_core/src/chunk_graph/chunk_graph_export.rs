@@ -0,0 +1,396 @@
+use std::fmt::{self, Write};
+
+use cow_utils::CowUtils;
+use itertools::Itertools;
+use rspack_collections::DatabaseItem;
+use serde::Serialize;
+
+use crate::{ChunkGroupUkey, ChunkUkey, Compilation};
+
+/// The reason a module was placed in a chunk.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(tag = "type", rename_all = "camelCase")]
+pub enum ModulePlacementReason {
+  /// The module is an entry module of the chunk.
+  Entry,
+  /// The module was moved into the chunk by a split chunks cache group.
+  CacheGroup { key: String },
+  /// The module is a runtime module of the chunk.
+  Runtime,
+  /// The module was reached from the modules of the chunk group while building the chunk graph.
+  Dependency,
+}
+
+impl fmt::Display for ModulePlacementReason {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    match self {
+      Self::Entry => write!(f, "entry"),
+      Self::CacheGroup { key } => write!(f, "cache group: {key}"),
+      Self::Runtime => write!(f, "runtime"),
+      Self::Dependency => write!(f, "dependency"),
+    }
+  }
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ChunkGraphExportModule {
+  pub identifier: String,
+  pub name: String,
+  pub size: f64,
+  pub reason: ModulePlacementReason,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ChunkGraphExportChunk {
+  pub ukey: u32,
+  pub id: Option<String>,
+  pub name: Option<String>,
+  pub reason: Option<String>,
+  pub initial: bool,
+  pub has_runtime: bool,
+  pub size: f64,
+  pub files: Vec<String>,
+  pub groups: Vec<u32>,
+  pub modules: Vec<ChunkGraphExportModule>,
+}
+
+impl ChunkGraphExportChunk {
+  fn label(&self) -> String {
+    self
+      .name
+      .clone()
+      .or_else(|| self.id.clone())
+      .unwrap_or_else(|| self.ukey.to_string())
+  }
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ChunkGraphExportChunkGroup {
+  pub ukey: u32,
+  pub name: Option<String>,
+  pub entrypoint: bool,
+  pub initial: bool,
+  pub chunks: Vec<u32>,
+  pub parents: Vec<u32>,
+  /// Chunk groups loaded on demand from this chunk group, e.g. by `import()`.
+  pub children: Vec<u32>,
+  /// Entrypoints started from this chunk group, e.g. by `new Worker()`.
+  pub async_entrypoints: Vec<u32>,
+  pub origins: Vec<String>,
+  pub runtime_chunk: Option<u32>,
+}
+
+impl ChunkGraphExportChunkGroup {
+  fn label(&self) -> String {
+    let name = self.name.clone().unwrap_or_else(|| self.origins.join(", "));
+    if self.entrypoint {
+      format!("entrypoint {name}")
+    } else {
+      format!("async {name}")
+    }
+  }
+}
+
+/// A snapshot of the chunk graph of a compilation, which can be rendered to
+/// Graphviz DOT, Mermaid or JSON.
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ChunkGraphExport {
+  pub chunk_groups: Vec<ChunkGraphExportChunkGroup>,
+  pub chunks: Vec<ChunkGraphExportChunk>,
+}
+
+impl ChunkGraphExport {
+  pub fn new(compilation: &Compilation) -> Self {
+    let chunk_graph = &compilation.build_chunk_graph_artifact.chunk_graph;
+    let chunk_by_ukey = &compilation.build_chunk_graph_artifact.chunk_by_ukey;
+    let chunk_group_by_ukey = &compilation.build_chunk_graph_artifact.chunk_group_by_ukey;
+    let module_graph = compilation.get_module_graph();
+    let context = &compilation.options.context;
+
+    let chunk_groups = chunk_group_by_ukey
+      .values()
+      .sorted_by_key(|chunk_group| chunk_group.ukey)
+      .map(|chunk_group| {
+        let origins = chunk_group
+          .origins()
+          .iter()
+          .filter_map(|record| {
+            let request = record.request.as_deref()?;
+            Some(match &record.module {
+              Some(module) => {
+                let module = module_graph.module_by_identifier(module)?;
+                format!("{} {request}", module.readable_identifier(context))
+              }
+              None => request.to_string(),
+            })
+          })
+          .sorted()
+          .collect();
+        let runtime_chunk = chunk_group
+          .kind
+          .is_entrypoint()
+          .then(|| chunk_group.get_runtime_chunk(chunk_group_by_ukey).as_u32());
+        ChunkGraphExportChunkGroup {
+          ukey: chunk_group.ukey.as_u32(),
+          name: chunk_group.name().map(ToString::to_string),
+          entrypoint: chunk_group.kind.is_entrypoint(),
+          initial: chunk_group.is_initial(),
+          chunks: chunk_group.chunks.iter().map(ChunkUkey::as_u32).collect(),
+          parents: chunk_group
+            .parents_iterable()
+            .map(ChunkGroupUkey::as_u32)
+            .sorted()
+            .collect(),
+          children: chunk_group
+            .children_iterable()
+            .map(ChunkGroupUkey::as_u32)
+            .collect(),
+          async_entrypoints: chunk_group
+            .async_entrypoints_iterable()
+            .map(ChunkGroupUkey::as_u32)
+            .collect(),
+          origins,
+          runtime_chunk,
+        }
+      })
+      .collect();
+
+    let chunks = chunk_by_ukey
+      .values()
+      .sorted_by_key(|chunk| chunk.ukey())
+      .map(|chunk| {
+        let chunk_ukey = chunk.ukey();
+        let entry_modules =
+          chunk_graph.get_chunk_entry_modules_with_chunk_group_iterable(&chunk_ukey);
+        let mut modules = chunk_graph
+          .get_chunk_modules(&chunk_ukey, module_graph)
+          .into_iter()
+          .map(|module| {
+            let identifier = module.identifier();
+            let reason = if entry_modules.contains_key(&identifier) {
+              ModulePlacementReason::Entry
+            } else if let Some(key) = compilation
+              .split_chunks_artifact
+              .cache_group(&chunk_ukey, &identifier)
+            {
+              ModulePlacementReason::CacheGroup {
+                key: key.to_string(),
+              }
+            } else {
+              ModulePlacementReason::Dependency
+            };
+            ChunkGraphExportModule {
+              identifier: identifier.to_string(),
+              name: module.readable_identifier(context).to_string(),
+              size: module
+                .source_types(module_graph)
+                .iter()
+                .map(|source_type| module.size(Some(source_type), Some(compilation)))
+                .sum(),
+              reason,
+            }
+          })
+          .collect::<Vec<_>>();
+        modules.extend(
+          chunk_graph
+            .get_chunk_runtime_modules_iterable(&chunk_ukey)
+            .filter_map(|identifier| {
+              let module = compilation.runtime_modules.get(identifier)?;
+              Some(ChunkGraphExportModule {
+                identifier: identifier.to_string(),
+                name: module.readable_identifier(context).to_string(),
+                size: compilation
+                  .runtime_modules_code_generation_source
+                  .get(identifier)
+                  .map_or(0.0, |source| source.size() as f64),
+                reason: ModulePlacementReason::Runtime,
+              })
+            }),
+        );
+        modules.sort_by(|a, b| a.name.cmp(&b.name));
+
+        ChunkGraphExportChunk {
+          ukey: chunk_ukey.as_u32(),
+          id: chunk.id().map(ToString::to_string),
+          name: chunk.name().map(ToString::to_string),
+          reason: chunk.chunk_reason().map(ToString::to_string),
+          initial: chunk.can_be_initial(chunk_group_by_ukey),
+          has_runtime: chunk.has_runtime(chunk_group_by_ukey),
+          size: modules.iter().map(|module| module.size).sum(),
+          files: chunk.files().iter().sorted().cloned().collect(),
+          groups: chunk
+            .groups()
+            .iter()
+            .map(ChunkGroupUkey::as_u32)
+            .sorted()
+            .collect(),
+          modules,
+        }
+      })
+      .collect();
+
+    Self {
+      chunk_groups,
+      chunks,
+    }
+  }
+
+  pub fn chunk_group(&self, name: &str) -> Option<&ChunkGraphExportChunkGroup> {
+    self
+      .chunk_groups
+      .iter()
+      .find(|chunk_group| chunk_group.name.as_deref() == Some(name))
+  }
+
+  pub fn chunk(&self, ukey: u32) -> Option<&ChunkGraphExportChunk> {
+    self.chunks.iter().find(|chunk| chunk.ukey == ukey)
+  }
+
+  pub fn to_json(&self) -> serde_json::Result<String> {
+    serde_json::to_string_pretty(self)
+  }
+
+  // Chunk groups are rendered as boxes and chunks as records listing their modules,
+  // async boundaries are rendered as dashed edges
+  pub fn to_dot(&self) -> Result<String, fmt::Error> {
+    let escape = |s: &str| {
+      s.cow_replace('\\', "\\\\")
+        .cow_replace('"', "\\\"")
+        .into_owned()
+    };
+    let mut dot = String::new();
+    writeln!(&mut dot, "digraph ChunkGraph {{")?;
+    writeln!(&mut dot, "  rankdir=LR;")?;
+    writeln!(&mut dot, "  node [fontname=monospace];")?;
+
+    for chunk_group in &self.chunk_groups {
+      writeln!(
+        &mut dot,
+        "  g{} [shape=box, style=filled, fillcolor=\"{}\", label=\"{}\"];",
+        chunk_group.ukey,
+        if chunk_group.initial {
+          "palegreen"
+        } else {
+          "orange"
+        },
+        escape(&chunk_group.label())
+      )?;
+    }
+
+    for chunk in &self.chunks {
+      let mut label = format!("chunk {} ({} B)", escape(&chunk.label()), chunk.size);
+      if let Some(reason) = &chunk.reason {
+        write!(&mut label, "\\n{}", escape(reason))?;
+      }
+      label.push_str("\\l");
+      for module in &chunk.modules {
+        write!(
+          &mut label,
+          "{} ({} B, {})\\l",
+          escape(&module.name),
+          module.size,
+          escape(&module.reason.to_string())
+        )?;
+      }
+      writeln!(
+        &mut dot,
+        "  c{} [shape=note, label=\"{label}\"];",
+        chunk.ukey
+      )?;
+    }
+
+    for chunk_group in &self.chunk_groups {
+      for chunk in &chunk_group.chunks {
+        let style = if chunk_group.runtime_chunk == Some(*chunk) {
+          " [label=\"runtime\"]"
+        } else {
+          ""
+        };
+        writeln!(&mut dot, "  g{} -> c{chunk}{style};", chunk_group.ukey)?;
+      }
+      for child in &chunk_group.children {
+        writeln!(
+          &mut dot,
+          "  g{} -> g{child} [style=dashed, label=\"async\"];",
+          chunk_group.ukey
+        )?;
+      }
+      for async_entrypoint in &chunk_group.async_entrypoints {
+        writeln!(
+          &mut dot,
+          "  g{} -> g{async_entrypoint} [style=dashed, label=\"async entrypoint\"];",
+          chunk_group.ukey
+        )?;
+      }
+    }
+
+    writeln!(&mut dot, "}}")?;
+    Ok(dot)
+  }
+
+  pub fn to_mermaid(&self) -> Result<String, fmt::Error> {
+    let escape = |s: &str| s.cow_replace('"', "#quot;").into_owned();
+    let mut mermaid = String::new();
+    writeln!(&mut mermaid, "flowchart LR")?;
+
+    for chunk_group in &self.chunk_groups {
+      writeln!(
+        &mut mermaid,
+        "  g{}[\"{}\"]",
+        chunk_group.ukey,
+        escape(&chunk_group.label())
+      )?;
+    }
+
+    for chunk in &self.chunks {
+      let mut label = format!("chunk {} ({} B)", escape(&chunk.label()), chunk.size);
+      if let Some(reason) = &chunk.reason {
+        write!(&mut label, "<br/>{}", escape(reason))?;
+      }
+      for module in &chunk.modules {
+        write!(
+          &mut label,
+          "<br/>{} ({} B, {})",
+          escape(&module.name),
+          module.size,
+          escape(&module.reason.to_string())
+        )?;
+      }
+      writeln!(&mut mermaid, "  c{}[\"{label}\"]", chunk.ukey)?;
+    }
+
+    for chunk_group in &self.chunk_groups {
+      for chunk in &chunk_group.chunks {
+        if chunk_group.runtime_chunk == Some(*chunk) {
+          writeln!(
+            &mut mermaid,
+            "  g{} -- runtime --> c{chunk}",
+            chunk_group.ukey
+          )?;
+        } else {
+          writeln!(&mut mermaid, "  g{} --> c{chunk}", chunk_group.ukey)?;
+        }
+      }
+      for child in &chunk_group.children {
+        writeln!(
+          &mut mermaid,
+          "  g{} -. async .-> g{child}",
+          chunk_group.ukey
+        )?;
+      }
+      for async_entrypoint in &chunk_group.async_entrypoints {
+        writeln!(
+          &mut mermaid,
+          "  g{} -. async entrypoint .-> g{async_entrypoint}",
+          chunk_group.ukey
+        )?;
+      }
+    }
+
+    Ok(mermaid)
+  }
+}
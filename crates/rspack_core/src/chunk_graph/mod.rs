@@ -11,8 +11,13 @@ use crate::{
 };
 
 pub mod chunk_graph_chunk;
+pub mod chunk_graph_export;
 pub mod chunk_graph_module;
 pub use chunk_graph_chunk::{ChunkGraphChunk, ChunkSizeOptions};
+pub use chunk_graph_export::{
+  ChunkGraphExport, ChunkGraphExportChunk, ChunkGraphExportChunkGroup, ChunkGraphExportModule,
+  ModulePlacementReason,
+};
 pub use chunk_graph_module::{ChunkGraphModule, ModuleId};
 
 #[derive(Debug, Clone, Default)]
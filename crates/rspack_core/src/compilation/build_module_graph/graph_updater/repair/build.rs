@@ -1,4 +1,4 @@
-use std::{collections::VecDeque, sync::Arc};
+use std::{collections::VecDeque, sync::Arc, time::Instant};
 
 use rspack_fs::ReadableFileSystem;
 use rustc_hash::FxHashSet;
@@ -8,8 +8,8 @@ use super::{
 };
 use crate::{
   AsyncDependenciesBlock, BoxDependency, BoxModule, BuildContext, BuildResult, CompilationId,
-  CompilerId, CompilerOptions, DependencyParents, ModuleCodeTemplate, ResolverFactory,
-  SharedPluginDriver,
+  CompilerId, CompilerOptions, DependencyParents, ModuleCodeTemplate, ModuleProfile,
+  ResolverFactory, SharedPluginDriver,
   compilation::build_module_graph::{ForwardedIdSet, HasLazyDependencies, LazyDependencies},
   utils::{
     ResourceId,
@@ -54,6 +54,7 @@ impl Task<TaskContext> for BuildTask {
       .call(compiler_id, compilation_id, &mut module)
       .await?;
 
+    let start = compiler_options.profile.then(Instant::now);
     let result = module
       .build(
         BuildContext {
@@ -69,11 +70,21 @@ impl Task<TaskContext> for BuildTask {
       )
       .await;
 
-    result.map::<Vec<Box<dyn Task<TaskContext>>>, _>(|build_result| {
+    result.map::<Vec<Box<dyn Task<TaskContext>>>, _>(|mut build_result| {
+      let profile = start.map(|start| {
+        let mut profile = build_result
+          .module
+          .as_normal_module_mut()
+          .and_then(|module| module.take_profile())
+          .unwrap_or_default();
+        profile.building = start.elapsed();
+        profile
+      });
       vec![Box::new(BuildResultTask {
         build_result: Box::new(build_result),
         plugin_driver,
         forwarded_ids,
+        profile,
       })]
     })
   }
@@ -84,6 +95,7 @@ struct BuildResultTask {
   pub build_result: Box<BuildResult>,
   pub plugin_driver: SharedPluginDriver,
   pub forwarded_ids: ForwardedIdSet,
+  pub profile: Option<Box<ModuleProfile>>,
 }
 
 #[async_trait::async_trait]
@@ -96,6 +108,7 @@ impl Task<TaskContext> for BuildResultTask {
       build_result,
       plugin_driver,
       mut forwarded_ids,
+      profile,
     } = *self;
     let mut module = build_result.module;
 
@@ -183,6 +196,12 @@ impl Task<TaskContext> for BuildResultTask {
     {
       let mgm = module_graph.module_graph_module_by_identifier_mut(&module.identifier());
       mgm.all_dependencies.clone_from(&all_dependencies);
+      if let Some(build_profile) = profile {
+        let profile = mgm.profile.get_or_insert_default();
+        profile.building = build_profile.building;
+        profile.loaders = build_profile.loaders;
+        profile.parsing = build_profile.parsing;
+      }
     }
 
     let module_identifier = module.identifier();
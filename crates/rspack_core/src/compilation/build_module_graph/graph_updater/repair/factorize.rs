@@ -1,4 +1,4 @@
-use std::sync::Arc;
+use std::{sync::Arc, time::Instant};
 
 use rspack_error::Diagnostic;
 use rspack_sources::BoxSource;
@@ -6,8 +6,8 @@ use rspack_sources::BoxSource;
 use super::{TaskContext, add::AddTask};
 use crate::{
   BoxDependency, CompilationId, CompilerId, CompilerOptions, Context, FactorizeInfo, ModuleFactory,
-  ModuleFactoryCreateData, ModuleFactoryResult, ModuleIdentifier, ModuleLayer, Resolve,
-  ResolverFactory,
+  ModuleFactoryCreateData, ModuleFactoryResult, ModuleIdentifier, ModuleLayer, ModuleProfile,
+  Resolve, ResolverFactory,
   module_graph::ModuleGraphModule,
   utils::{
     ResourceId,
@@ -86,7 +86,9 @@ impl Task<TaskContext> for FactorizeTask {
       missing_dependencies: Default::default(),
       context_dependencies: Default::default(),
       diagnostics: Default::default(),
+      profile: self.options.profile.then(Default::default),
     };
+    let start = create_data.profile.is_some().then(Instant::now);
     let factory_result = match self.module_factory.create(&mut create_data).await {
       Ok(result) => Some(result),
       Err(mut e) => {
@@ -108,6 +110,11 @@ impl Task<TaskContext> for FactorizeTask {
         None
       }
     };
+    if let Some(profile) = &mut create_data.profile
+      && let Some(start) = start
+    {
+      profile.factory = start.elapsed();
+    }
 
     let factorize_info = FactorizeInfo::new(
       create_data.diagnostics,
@@ -126,6 +133,7 @@ impl Task<TaskContext> for FactorizeTask {
       dependencies: create_data.dependencies,
       factorize_info,
       from_unlazy: self.from_unlazy,
+      profile: create_data.profile,
     })])
   }
 }
@@ -139,6 +147,7 @@ pub struct FactorizeResultTask {
   pub dependencies: Vec<BoxDependency>,
   pub factorize_info: FactorizeInfo,
   pub from_unlazy: bool,
+  pub profile: Option<Box<ModuleProfile>>,
 }
 
 #[async_trait::async_trait]
@@ -153,6 +162,7 @@ impl Task<TaskContext> for FactorizeResultTask {
       mut dependencies,
       mut factorize_info,
       from_unlazy,
+      profile,
     } = *self;
 
     let artifact = &mut context.artifact;
@@ -211,6 +221,7 @@ impl Task<TaskContext> for FactorizeResultTask {
     let module_identifier = module.identifier();
     let mut mgm = ModuleGraphModule::new(module.identifier());
     mgm.set_issuer_if_unset(original_module_identifier);
+    mgm.profile = profile;
 
     tracing::trace!("Module created: {}", &module_identifier);
 
@@ -1,3 +1,5 @@
+use std::time::{Duration, Instant};
+
 use async_trait::async_trait;
 
 use super::*;
@@ -169,6 +171,7 @@ impl Compilation {
           let module = module_graph
             .module_by_identifier(&job.module)
             .expect("should have module");
+          let start = options.profile.then(Instant::now);
           let codegen_res = this
             .code_generate_cache_artifact
             .use_cache(&job, || async {
@@ -197,7 +200,8 @@ impl Compilation {
             })
             .await;
 
-          (job.module, job.runtimes, codegen_res)
+          let elapsed = start.map(|start| start.elapsed());
+          (job.module, job.runtimes, codegen_res, elapsed)
         })
       })
     })
@@ -207,7 +211,8 @@ impl Compilation {
       .map(|res| res.to_rspack_result())
       .collect::<Result<Vec<_>>>()?;
 
-    for (module, runtimes, (codegen_res, from_cache)) in results {
+    let mut code_generation_times: IdentifierMap<Duration> = IdentifierMap::default();
+    for (module, runtimes, (codegen_res, from_cache), elapsed) in results {
       if let Some(counter) = cache_counter {
         if from_cache {
           counter.hit();
@@ -234,6 +239,17 @@ impl Compilation {
         .code_generation_results
         .insert(module, codegen_res, runtimes);
       self.code_generated_modules.insert(module);
+      if let Some(elapsed) = elapsed {
+        *code_generation_times.entry(module).or_default() += elapsed;
+      }
+    }
+    let module_graph = self.get_module_graph_mut();
+    for (module, elapsed) in code_generation_times {
+      module_graph
+        .module_graph_module_by_identifier_mut(&module)
+        .profile
+        .get_or_insert_default()
+        .code_generation = elapsed;
     }
     Ok(())
   }
@@ -1,3 +1,5 @@
+use std::time::Instant;
+
 use async_trait::async_trait;
 
 use super::*;
@@ -133,6 +135,7 @@ impl Compilation {
         let s = unsafe { token.used((&*self, &mg, chunk_graph, chunk_by_ukey)) };
         s.spawn(
           move |(compilation, mg, chunk_graph, chunk_by_ukey)| async move {
+            let start = compilation.options.profile.then(Instant::now);
             let mut hashes = RuntimeSpecMap::new();
             let module = mg
               .module_by_identifier(&module_identifier)
@@ -141,7 +144,8 @@ impl Compilation {
               let hash = module.get_runtime_hash(compilation, Some(runtime)).await?;
               hashes.set(runtime.clone(), hash);
             }
-            Ok((module_identifier, hashes))
+            let elapsed = start.map(|start| start.elapsed());
+            Ok((module_identifier, hashes, elapsed))
           },
         );
       }
@@ -152,7 +156,15 @@ impl Compilation {
     .collect::<Result<Vec<_>>>()?;
 
     for result in results {
-      let (module, hashes) = result?;
+      let (module, hashes, elapsed) = result?;
+      if let Some(elapsed) = elapsed {
+        self
+          .get_module_graph_mut()
+          .module_graph_module_by_identifier_mut(&module)
+          .profile
+          .get_or_insert_default()
+          .hashing = elapsed;
+      }
       if ChunkGraph::set_module_hashes(self, module, hashes)
         && let Some(mut mutations) = self.incremental.mutations_write()
       {
@@ -75,9 +75,10 @@ use crate::{
   ExportsInfoArtifact, ExtendedReferencedExport, Filename, ImportPhase, ImportVarMap,
   ImportedByDeferModulesArtifact, MemoryGCStorage, ModuleFactory, ModuleGraph,
   ModuleGraphCacheArtifact, ModuleIdentifier, ModuleIdsArtifact, ModuleStaticCache, PathData,
-  ProcessRuntimeRequirementsCacheArtifact, ResolverFactory, RuntimeGlobals, RuntimeKeyMap,
-  RuntimeMode, RuntimeModule, RuntimeSpec, RuntimeSpecMap, RuntimeTemplate, SharedPluginDriver,
-  SideEffectsOptimizeArtifact, SourceType, Stats, StealCell, ValueCacheVersions,
+  ProcessRuntimeRequirementsCacheArtifact, RecordsArtifact, ResolverFactory, RuntimeGlobals,
+  RuntimeKeyMap, RuntimeMode, RuntimeModule, RuntimeSpec, RuntimeSpecMap, RuntimeTemplate,
+  SharedPluginDriver, SideEffectsOptimizeArtifact, SourceType, SplitChunksArtifact, Stats,
+  StealCell, ValueCacheVersions,
   compilation::build_module_graph::{
     BuildModuleGraphArtifact, ModuleExecutor, UpdateParam, update_module_graph,
   },
@@ -265,6 +266,10 @@ pub struct Compilation {
   pub code_generated_modules: IdentifierSet,
   pub build_time_executed_modules: IdentifierSet,
   pub build_chunk_graph_artifact: BuildChunkGraphArtifact,
+  // artifact for split_chunks_plugin
+  pub split_chunks_artifact: SplitChunksArtifact,
+  // artifact for the records persisted by recordsPath
+  pub records_artifact: RecordsArtifact,
   pub incremental: Incremental,
 
   pub hash: Option<RspackHashDigest>,
@@ -394,6 +399,8 @@ impl Compilation {
       build_time_executed_modules: Default::default(),
       incremental,
       build_chunk_graph_artifact: Default::default(),
+      split_chunks_artifact: Default::default(),
+      records_artifact: Default::default(),
 
       hash: None,
 
@@ -1,4 +1,5 @@
 mod rebuild;
+mod watch;
 use std::sync::{Arc, atomic::AtomicU32};
 
 use futures::future::join_all;
@@ -13,7 +14,10 @@ use rspack_util::{node_path::NodePath, tracing_preset::TRACING_BENCH_TARGET};
 use rustc_hash::FxHashMap as HashMap;
 use tracing::instrument;
 
-pub use self::rebuild::CompilationRecords;
+pub use self::{
+  rebuild::CompilationRecords,
+  watch::{FsWatcherIgnored, FsWatcherMode, FsWatcherOptions, WatchRun, Watching},
+};
 use crate::{
   BoxPlugin, CleanOptions, Compilation, CompilationAsset, CompilerOptions, CompilerPlatform,
   ContextModuleFactory, Filename, KeepPattern, NormalModuleFactory, PluginDriver, ResolverFactory,
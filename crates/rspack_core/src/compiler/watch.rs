@@ -0,0 +1,224 @@
+use std::{collections::HashSet, time::SystemTime};
+
+use rspack_error::Result;
+use rspack_paths::{ArcPath, ArcPathSet};
+use rspack_util::fx_hash::FxHashSet;
+use rspack_watcher::{EventAggregateHandler, EventHandler, FsWatcher};
+pub use rspack_watcher::{FsWatcherIgnored, FsWatcherMode, FsWatcherOptions};
+use tokio::sync::mpsc::{UnboundedReceiver, UnboundedSender, unbounded_channel};
+
+use super::Compiler;
+
+enum WatchEvent {
+  Invalidate {
+    changed_files: HashSet<String>,
+    deleted_files: HashSet<String>,
+  },
+  Error(rspack_error::Error),
+}
+
+struct AggregateHandler(UnboundedSender<WatchEvent>);
+
+impl EventAggregateHandler for AggregateHandler {
+  fn on_event_handle(&self, changed_files: FxHashSet<String>, deleted_files: FxHashSet<String>) {
+    let _ = self.0.send(WatchEvent::Invalidate {
+      changed_files: changed_files.into_iter().collect(),
+      deleted_files: deleted_files.into_iter().collect(),
+    });
+  }
+
+  fn on_error(&self, error: rspack_error::Error) {
+    let _ = self.0.send(WatchEvent::Error(error));
+  }
+}
+
+struct NoopEventHandler;
+
+impl EventHandler for NoopEventHandler {}
+
+/// The result of a compilation in watch mode.
+#[derive(Debug)]
+pub struct WatchRun {
+  /// The changed files which invalidated the previous compilation, empty for the initial build.
+  pub changed_files: HashSet<String>,
+  /// The deleted files which invalidated the previous compilation, empty for the initial build.
+  pub deleted_files: HashSet<String>,
+  /// The result of the build or rebuild, or the error of the watcher.
+  ///
+  /// The errors of the compilation itself are in `compiler.compilation`.
+  pub result: Result<()>,
+}
+
+#[derive(Debug, Default)]
+struct WatchedPaths {
+  files: ArcPathSet,
+  directories: ArcPathSet,
+  missing: ArcPathSet,
+}
+
+/// Returns the (added, removed) paths compared with the previous watched paths.
+fn diff_paths(prev: &mut ArcPathSet, current: ArcPathSet) -> (Vec<ArcPath>, Vec<ArcPath>) {
+  let added = current.difference(prev).cloned().collect();
+  let removed = prev.difference(&current).cloned().collect();
+  *prev = current;
+  (added, removed)
+}
+
+/// `Watching` rebuilds the compiler when the dependencies of the compilation change.
+///
+/// It is created by [`Compiler::watch`], and each call of [`Watching::next`] waits for the
+/// changes aggregated by the file system watcher, rebuilds the compiler, and returns the
+/// result with the files invalidating the previous compilation.
+pub struct Watching<'a> {
+  compiler: &'a mut Compiler,
+  watcher: FsWatcher,
+  tx: UnboundedSender<WatchEvent>,
+  rx: UnboundedReceiver<WatchEvent>,
+  watched: WatchedPaths,
+  started: bool,
+}
+
+impl Watching<'_> {
+  /// Run the initial build, or wait for the next changes and rebuild.
+  pub async fn next(&mut self) -> WatchRun {
+    if !self.started {
+      self.started = true;
+      let start_time = SystemTime::now();
+      let result = self.compiler.build().await;
+      return self
+        .finish(start_time, Default::default(), Default::default(), result)
+        .await;
+    }
+
+    let event = self.rx.recv().await.expect("should have sender");
+    let (mut changed_files, mut deleted_files) = match event {
+      WatchEvent::Invalidate {
+        changed_files,
+        deleted_files,
+      } => (changed_files, deleted_files),
+      WatchEvent::Error(error) => {
+        return WatchRun {
+          changed_files: Default::default(),
+          deleted_files: Default::default(),
+          result: Err(error),
+        };
+      }
+    };
+    // merge the changes arrived during the previous build
+    while let Ok(event) = self.rx.try_recv() {
+      match event {
+        WatchEvent::Invalidate {
+          changed_files: changed,
+          deleted_files: deleted,
+        } => {
+          for file in changed {
+            deleted_files.remove(&file);
+            changed_files.insert(file);
+          }
+          for file in deleted {
+            changed_files.remove(&file);
+            deleted_files.insert(file);
+          }
+        }
+        WatchEvent::Error(error) => {
+          // keep the merged changes for the next run
+          self.invalidate(changed_files, deleted_files);
+          return WatchRun {
+            changed_files: Default::default(),
+            deleted_files: Default::default(),
+            result: Err(error),
+          };
+        }
+      }
+    }
+
+    let start_time = SystemTime::now();
+    let result = self
+      .compiler
+      .rebuild(changed_files.clone(), deleted_files.clone())
+      .await;
+    self
+      .finish(start_time, changed_files, deleted_files, result)
+      .await
+  }
+
+  /// Invalidate the compilation with the given files, e.g. the files changed in an in-memory
+  /// input file system which the watcher can not detect.
+  pub fn invalidate(&self, changed_files: HashSet<String>, deleted_files: HashSet<String>) {
+    let _ = self.tx.send(WatchEvent::Invalidate {
+      changed_files,
+      deleted_files,
+    });
+  }
+
+  /// The compiler being watched, e.g. to read the compilation of the last run.
+  pub fn compiler(&self) -> &Compiler {
+    self.compiler
+  }
+
+  /// Close the watcher and the compiler.
+  pub async fn close(mut self) -> Result<()> {
+    self.watcher.close().await?;
+    self.compiler.close().await
+  }
+
+  /// Watch the dependencies of the current compilation.
+  async fn finish(
+    &mut self,
+    start_time: SystemTime,
+    changed_files: HashSet<String>,
+    deleted_files: HashSet<String>,
+    result: Result<()>,
+  ) -> WatchRun {
+    let compilation = &self.compiler.compilation;
+    let files = diff_paths(
+      &mut self.watched.files,
+      compilation.file_dependencies().0.cloned().collect(),
+    );
+    let directories = diff_paths(
+      &mut self.watched.directories,
+      compilation.context_dependencies().0.cloned().collect(),
+    );
+    let missing = diff_paths(
+      &mut self.watched.missing,
+      compilation.missing_dependencies().0.cloned().collect(),
+    );
+
+    self
+      .watcher
+      .watch(
+        (files.0.into_iter(), files.1.into_iter()),
+        (directories.0.into_iter(), directories.1.into_iter()),
+        (missing.0.into_iter(), missing.1.into_iter()),
+        start_time,
+        Box::new(AggregateHandler(self.tx.clone())),
+        Box::new(NoopEventHandler),
+      )
+      .await;
+
+    WatchRun {
+      changed_files,
+      deleted_files,
+      result,
+    }
+  }
+}
+
+impl Compiler {
+  /// Build the compiler in watch mode with [`rspack_watcher::FsWatcher`].
+  ///
+  /// The returned [`Watching`] runs the initial build on the first call of [`Watching::next`],
+  /// then rebuilds the compiler each time the file, context or missing dependencies of the
+  /// compilation change. Changes are aggregated with `options.aggregate_timeout`.
+  pub fn watch(&mut self, options: FsWatcherOptions, ignored: FsWatcherIgnored) -> Watching<'_> {
+    let (tx, rx) = unbounded_channel();
+    Watching {
+      compiler: self,
+      watcher: FsWatcher::new(options, ignored),
+      tx,
+      rx,
+      watched: Default::default(),
+      started: false,
+    }
+  }
+}
@@ -52,6 +52,8 @@ mod init_fragment;
 pub use init_fragment::*;
 mod module_factory;
 pub use module_factory::*;
+mod module_profile;
+pub use module_profile::*;
 mod normal_module_factory;
 pub use normal_module_factory::*;
 mod ignore_error_module_factory;
@@ -5,7 +5,7 @@ use rspack_paths::{ArcPath, ArcPathSet};
 
 use crate::{
   BoxDependency, BoxModule, CompilationId, CompilerId, CompilerOptions, Context, ModuleIdentifier,
-  ModuleLayer, Resolve, ResolverFactory,
+  ModuleLayer, ModuleProfile, Resolve, ResolverFactory,
 };
 
 #[derive(Debug, Clone)]
@@ -26,6 +26,8 @@ pub struct ModuleFactoryCreateData {
   pub context_dependencies: ArcPathSet,
   pub missing_dependencies: ArcPathSet,
   pub diagnostics: Vec<Diagnostic>,
+  /// Only available when `profile` is enabled.
+  pub profile: Option<Box<ModuleProfile>>,
 }
 
 impl ModuleFactoryCreateData {
@@ -1,7 +1,7 @@
 use rspack_cacheable::{cacheable, with::Skip};
 use rustc_hash::FxHashSet as HashSet;
 
-use crate::{DependencyId, ModuleIdentifier, ModuleIssuer};
+use crate::{DependencyId, ModuleIdentifier, ModuleIssuer, ModuleProfile};
 
 #[cacheable]
 #[derive(Debug, Clone)]
@@ -23,6 +23,8 @@ pub struct ModuleGraphModule {
   pub post_order_index: Option<u32>,
   pub depth: Option<usize>,
   pub optimization_bailout: Vec<String>,
+  #[cacheable(with=Skip)]
+  pub profile: Option<Box<ModuleProfile>>,
 }
 
 impl ModuleGraphModule {
@@ -38,6 +40,7 @@ impl ModuleGraphModule {
       post_order_index: None,
       depth: None,
       optimization_bailout: vec![],
+      profile: None,
     }
   }
 
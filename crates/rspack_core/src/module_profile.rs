@@ -0,0 +1,28 @@
+use std::time::Duration;
+
+pub use rspack_loader_runner::LoaderProfile;
+
+/// Time spent on a module in each phase of the compilation.
+///
+/// Only collected when `profile` is enabled, see [crate::CompilerOptions::profile].
+#[derive(Debug, Default, Clone)]
+pub struct ModuleProfile {
+  /// Time spent resolving the request, included in `factory`.
+  pub resolving: Duration,
+  /// Time spent creating the module in the module factory.
+  pub factory: Duration,
+  /// Time spent building the module, including `loaders` and `parsing`.
+  pub building: Duration,
+  pub loaders: Vec<LoaderProfile>,
+  pub parsing: Duration,
+  /// Time spent generating code for all runtimes of the module.
+  pub code_generation: Duration,
+  /// Time spent hashing the module for all runtimes.
+  pub hashing: Duration,
+}
+
+impl ModuleProfile {
+  pub fn total(&self) -> Duration {
+    self.factory + self.building + self.code_generation + self.hashing
+  }
+}
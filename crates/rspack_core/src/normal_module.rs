@@ -5,13 +5,14 @@ use std::{
     Arc,
     atomic::{AtomicUsize, Ordering},
   },
+  time::Instant,
 };
 
 use dashmap::DashMap;
 use derive_more::Debug;
 use rspack_cacheable::{
   cacheable, cacheable_dyn,
-  with::{AsMap, AsOption, AsPreset},
+  with::{AsMap, AsOption, AsPreset, Skip},
 };
 use rspack_collections::{Identifiable, IdentifierMap, IdentifierSet};
 use rspack_error::{Diagnosable, Diagnostic, Result, error};
@@ -37,9 +38,10 @@ use crate::{
   CodeGenerationResult, Compilation, ConnectionState, Context, DependenciesBlock, DependencyId,
   FactoryMeta, GenerateContext, GeneratorOptions, LibIdentOptions, Module,
   ModuleCodeGenerationContext, ModuleGraph, ModuleGraphCacheArtifact, ModuleIdentifier,
-  ModuleLayer, ModuleType, OutputOptions, ParseContext, ParseResult, ParserAndGenerator,
-  ParserOptions, Resolve, RspackLoaderRunnerPlugin, RunnerContext, RuntimeGlobals, RuntimeSpec,
-  SourceType, contextify, diagnostics::ModuleBuildError, get_context, module_update_hash,
+  ModuleLayer, ModuleProfile, ModuleType, OutputOptions, ParseContext, ParseResult,
+  ParserAndGenerator, ParserOptions, Resolve, RspackLoaderRunnerPlugin, RunnerContext,
+  RuntimeGlobals, RuntimeSpec, SourceType, contextify, diagnostics::ModuleBuildError, get_context,
+  module_update_hash,
 };
 
 #[cacheable]
@@ -150,6 +152,10 @@ pub struct NormalModule {
   parsed: bool,
 
   source_map_kind: SourceMapKind,
+
+  /// Loader and parse time of the last build, only collected when `profile` is enabled.
+  #[cacheable(with=Skip)]
+  profile: Option<Box<ModuleProfile>>,
 }
 
 static DEBUG_ID: AtomicUsize = AtomicUsize::new(1);
@@ -218,6 +224,7 @@ impl NormalModule {
       build_meta: Default::default(),
       parsed: false,
       source_map_kind: SourceMapKind::empty(),
+      profile: None,
     }
   }
 
@@ -257,6 +264,10 @@ impl NormalModule {
     &self.loaders
   }
 
+  pub fn take_profile(&mut self) -> Option<Box<ModuleProfile>> {
+    self.profile.take()
+  }
+
   pub fn parser_and_generator(&self) -> &dyn ParserAndGenerator {
     &*self.parser_and_generator
   }
@@ -396,6 +407,7 @@ impl Module for NormalModule {
     let compiler_options = build_context.compiler_options.clone();
     let resolver_factory = build_context.resolver_factory.clone();
     let fs = build_context.fs.clone();
+    let profile = build_context.compiler_options.profile;
     let (mut loader_result, err) = run_loaders(
       self.loaders.clone(),
       self.resource_data.clone(),
@@ -409,10 +421,17 @@ impl Module for NormalModule {
         module: self,
       },
       fs,
+      profile,
     )
     .instrument(info_span!("NormalModule:run_loaders",))
     .await;
     self = loader_result.context.module;
+    self.profile = loader_result.profile.take().map(|loaders| {
+      Box::new(ModuleProfile {
+        loaders,
+        ..Default::default()
+      })
+    });
 
     if let Some(err) = err {
       self.build_info.cacheable = loader_result.cacheable;
@@ -526,6 +545,7 @@ impl Module for NormalModule {
       });
     }
 
+    let parse_start = self.profile.is_some().then(Instant::now);
     let (
       ParseResult {
         source,
@@ -560,6 +580,11 @@ impl Module for NormalModule {
       })
       .await?
       .split_into_parts();
+    if let Some(profile) = &mut self.profile
+      && let Some(parse_start) = parse_start
+    {
+      profile.parsing = parse_start.elapsed();
+    }
     if diagnostics.iter().any(|d| d.is_error()) {
       self.build_meta = Default::default();
     }
@@ -1,4 +1,4 @@
-use std::{borrow::Cow, sync::Arc};
+use std::{borrow::Cow, sync::Arc, time::Instant};
 
 use rspack_error::{Result, error};
 use rspack_hook::define_hook;
@@ -330,7 +330,13 @@ impl NormalModuleFactory {
           missing_dependencies: &mut missing_dependencies,
         };
 
+        let start = data.profile.is_some().then(Instant::now);
         let resource_data = resolve(resolve_args, plugin_driver).await;
+        if let Some(profile) = &mut data.profile
+          && let Some(start) = start
+        {
+          profile.resolving += start.elapsed();
+        }
 
         match resource_data {
           Ok(ResolveResult::Resource(resource)) => resource.into(),
@@ -20,6 +20,8 @@ pub struct CompilerOptions {
   pub optimization: Optimization,
   pub amd: Option<String>,
   pub bail: bool,
+  /// Collect the time spent on each module, see [crate::ModuleProfile].
+  pub profile: bool,
   pub __references: References,
 }
 
@@ -16,8 +16,8 @@ pub use r#struct::*;
 use crate::{
   BoxModule, BoxRuntimeModule, Chunk, ChunkGraph, ChunkGroupOrderKey, ChunkGroupUkey, ChunkUkey,
   Compilation, ExportsInfoArtifact, LogType, ModuleGraph, ModuleGraphCacheArtifact,
-  ModuleIdentifier, PrefetchExportsInfoMode, ProvidedExports, RuntimeSpec, SourceType, UsedExports,
-  compilation::build_module_graph::ExecutedRuntimeModule,
+  ModuleIdentifier, ModuleProfile, PrefetchExportsInfoMode, ProvidedExports, RuntimeSpec,
+  SourceType, UsedExports, compilation::build_module_graph::ExecutedRuntimeModule, contextify,
 };
 
 #[derive(Debug, Clone)]
@@ -825,6 +825,75 @@ impl Stats<'_> {
     self.compilation.get_hash()
   }
 
+  /// Modules and loaders sorted by the time spent on them, only available when `profile` is enabled.
+  pub fn get_profile(&self) -> Option<StatsProfile<'_>> {
+    if !self.compilation.options.profile {
+      return None;
+    }
+    let module_graph = self.compilation.get_module_graph();
+    let mut loaders: HashMap<&str, (f64, f64)> = HashMap::default();
+    let mut modules = module_graph
+      .modules()
+      .filter_map(|(identifier, module)| {
+        let profile = module_graph
+          .module_graph_module_by_identifier(identifier)?
+          .profile
+          .as_deref()?;
+        for loader in &profile.loaders {
+          let (pitch, normal) = loaders.entry(loader.loader.as_str()).or_default();
+          *pitch += duration_to_ms(loader.pitch);
+          *normal += duration_to_ms(loader.normal);
+        }
+        Some(StatsProfileModule {
+          identifier: *identifier,
+          name: module.readable_identifier(&self.compilation.options.context),
+          profile: self.get_module_profile(profile),
+        })
+      })
+      .collect_vec();
+    modules.sort_by(|a, b| {
+      b.profile
+        .total
+        .total_cmp(&a.profile.total)
+        .then_with(|| a.identifier.cmp(&b.identifier))
+    });
+    let mut loaders = loaders
+      .into_iter()
+      .map(|(loader, (pitch, normal))| StatsLoaderProfile {
+        loader: contextify(self.compilation.options.context.as_path(), loader),
+        pitch,
+        normal,
+      })
+      .collect_vec();
+    loaders.sort_by(|a, b| {
+      (b.pitch + b.normal)
+        .total_cmp(&(a.pitch + a.normal))
+        .then_with(|| a.loader.cmp(&b.loader))
+    });
+    Some(StatsProfile { modules, loaders })
+  }
+
+  fn get_module_profile(&self, profile: &ModuleProfile) -> StatsModuleProfile {
+    StatsModuleProfile {
+      total: duration_to_ms(profile.total()),
+      resolving: duration_to_ms(profile.resolving),
+      factory: duration_to_ms(profile.factory),
+      building: duration_to_ms(profile.building),
+      loaders: profile
+        .loaders
+        .iter()
+        .map(|loader| StatsLoaderProfile {
+          loader: contextify(self.compilation.options.context.as_path(), &loader.loader),
+          pitch: duration_to_ms(loader.pitch),
+          normal: duration_to_ms(loader.normal),
+        })
+        .collect(),
+      parsing: duration_to_ms(profile.parsing),
+      code_generation: duration_to_ms(profile.code_generation),
+      hashing: duration_to_ms(profile.hashing),
+    }
+  }
+
   #[allow(clippy::too_many_arguments)]
   fn get_module<'a>(
     &'a self,
@@ -913,6 +982,7 @@ impl Stats<'_> {
       provided_exports: None,
       used_exports: None,
       optimization_bailout: None,
+      profile: None,
       depth: None,
       pre_order_index: None,
       post_order_index: None,
@@ -992,6 +1062,10 @@ impl Stats<'_> {
       stats.failed = Some(errors > 0);
       stats.errors = Some(errors);
       stats.warnings = Some(warnings);
+      stats.profile = mgm
+        .profile
+        .as_deref()
+        .map(|profile| self.get_module_profile(profile));
     }
 
     if options.ids {
@@ -1254,6 +1328,7 @@ impl Stats<'_> {
       provided_exports: None,
       used_exports: None,
       optimization_bailout: None,
+      profile: None,
       depth: None,
       pre_order_index: None,
       post_order_index: None,
@@ -1373,6 +1448,7 @@ impl Stats<'_> {
       provided_exports: None,
       used_exports: None,
       optimization_bailout: None,
+      profile: None,
       depth: None,
       pre_order_index: None,
       post_order_index: None,
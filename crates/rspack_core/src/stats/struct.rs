@@ -30,6 +30,7 @@ pub struct ExtendedStatsOptions {
   pub module_assets: bool,
   pub nested_modules: bool,
   pub optimization_bailout: bool,
+  pub profile: bool,
   pub provided_exports: bool,
   pub reasons: bool,
   pub source: bool,
@@ -59,6 +60,7 @@ impl Default for ExtendedStatsOptions {
       module_assets: false,
       nested_modules: false,
       optimization_bailout: false,
+      profile: false,
       provided_exports: false,
       reasons: false,
       source: false,
@@ -174,6 +176,7 @@ pub struct StatsModule<'a> {
   pub provided_exports: Option<Vec<Atom>>,
   pub used_exports: Option<StatsUsedExports>,
   pub optimization_bailout: Option<&'a [String]>,
+  pub profile: Option<StatsModuleProfile>,
   pub depth: Option<usize>,
   pub pre_order_index: Option<u32>,
   pub post_order_index: Option<u32>,
@@ -195,10 +198,39 @@ pub enum StatsUsedExports {
   Null,
 }
 
+/// Time spent on a module in milliseconds, see [crate::ModuleProfile].
 #[derive(Debug)]
 pub struct StatsModuleProfile {
-  pub factory: u64,
-  pub building: u64,
+  pub total: f64,
+  pub resolving: f64,
+  pub factory: f64,
+  pub building: f64,
+  pub loaders: Vec<StatsLoaderProfile>,
+  pub parsing: f64,
+  pub code_generation: f64,
+  pub hashing: f64,
+}
+
+#[derive(Debug)]
+pub struct StatsLoaderProfile {
+  pub loader: String,
+  pub pitch: f64,
+  pub normal: f64,
+}
+
+#[derive(Debug)]
+pub struct StatsProfileModule<'a> {
+  pub identifier: ModuleIdentifier,
+  pub name: Cow<'a, str>,
+  pub profile: StatsModuleProfile,
+}
+
+/// The `profile` section of stats, sorted by cost in descending order.
+#[derive(Debug)]
+pub struct StatsProfile<'a> {
+  pub modules: Vec<StatsProfileModule<'a>>,
+  /// Loader time summed over all modules.
+  pub loaders: Vec<StatsLoaderProfile>,
 }
 
 #[derive(Debug)]
@@ -1,4 +1,4 @@
-use std::borrow::Cow;
+use std::{borrow::Cow, time::Duration};
 
 use itertools::Itertools;
 use rayon::iter::{IntoParallelRefIterator, ParallelIterator};
@@ -22,6 +22,10 @@ pub fn get_asset_size(file: &str, compilation: &Compilation) -> usize {
     .unwrap_or(0)
 }
 
+pub fn duration_to_ms(duration: Duration) -> f64 {
+  duration.as_secs_f64() * 1000.0
+}
+
 pub fn sort_modules(modules: &mut [StatsModule]) {
   modules.sort_unstable_by(|a, b| {
     // align with MODULES_SORTER
@@ -75,6 +75,16 @@ impl MemoryFileSystem {
     files.clear();
   }
 
+  /// Returns the paths of all files.
+  pub(crate) fn files(&self) -> Vec<Utf8PathBuf> {
+    let files = self.files.lock().expect("should get lock");
+    files
+      .iter()
+      .filter(|(_, ft)| matches!(ft, FileType::File { .. }))
+      .map(|(path, _)| path.clone())
+      .collect()
+  }
+
   fn contains_dir(&self, dir: &Utf8Path) -> Result<bool> {
     let files = self.files.lock().expect("should get lock");
     if let Some(ft) = files.get(dir) {
@@ -0,0 +1,491 @@
+use std::{
+  collections::HashSet,
+  sync::{Arc, Mutex},
+};
+
+use rspack_paths::{Utf8Path, Utf8PathBuf};
+
+use crate::{
+  Error, FileMetadata, MemoryFileSystem, ReadableFileSystem, Result, WritableFileSystem,
+  file_metadata::FilePermissions,
+};
+
+fn not_found() -> Error {
+  Error::new(std::io::ErrorKind::NotFound, "file not exist")
+}
+
+fn new_error(msg: &str) -> Error {
+  Error::Io(std::io::Error::other(msg))
+}
+
+#[derive(Debug, Default)]
+struct Changes {
+  modified: HashSet<String>,
+  removed: HashSet<String>,
+}
+
+/// A copy-on-write file system which layers an in-memory file system over a readable one.
+///
+/// Reads hit the upper in-memory layer first and fall back to the lower file system.
+/// Writes only go to the upper layer, and removing a path of the lower file system records
+/// a whiteout which hides the path and all of its descendants. The lower file system is
+/// never modified.
+///
+/// The written and removed paths are collected and can be taken by [`Self::take_changes`],
+/// e.g. to pass them to `Compiler::rebuild` as modified and removed files.
+#[derive(Debug, Clone)]
+pub struct OverlayFileSystem {
+  lower: Arc<dyn ReadableFileSystem>,
+  upper: MemoryFileSystem,
+  whiteouts: Arc<Mutex<HashSet<Utf8PathBuf>>>,
+  changes: Arc<Mutex<Changes>>,
+}
+
+impl OverlayFileSystem {
+  pub fn new(lower: Arc<dyn ReadableFileSystem>) -> Self {
+    Self {
+      lower,
+      upper: MemoryFileSystem::default(),
+      whiteouts: Default::default(),
+      changes: Default::default(),
+    }
+  }
+
+  /// Take the paths written and removed since the last call, returns `(modified, removed)`.
+  pub fn take_changes(&self) -> (HashSet<String>, HashSet<String>) {
+    let changes = std::mem::take(&mut *self.changes.lock().expect("should get lock"));
+    (changes.modified, changes.removed)
+  }
+
+  /// Drop all of the writes and whiteouts, so the lower file system is exposed again.
+  ///
+  /// The files of the upper layer and the removed paths are reported as modified.
+  pub fn reset(&self) {
+    let mut changes = self.changes.lock().expect("should get lock");
+    let mut whiteouts = self.whiteouts.lock().expect("should get lock");
+    for path in whiteouts.drain() {
+      changes.removed.remove(path.as_str());
+      changes.modified.insert(path.into_string());
+    }
+    for path in self.upper.files() {
+      changes.removed.remove(path.as_str());
+      changes.modified.insert(path.into_string());
+    }
+    self.upper.clear();
+  }
+
+  /// Whether the path or one of its ancestors has been removed from the lower file system.
+  fn is_whiteout(&self, path: &Utf8Path) -> bool {
+    let whiteouts = self.whiteouts.lock().expect("should get lock");
+    !whiteouts.is_empty() && path.ancestors().any(|p| whiteouts.contains(p))
+  }
+
+  fn lower_metadata(&self, path: &Utf8Path) -> Option<FileMetadata> {
+    if self.is_whiteout(path) {
+      return None;
+    }
+    self.lower.metadata_sync(path).ok()
+  }
+
+  fn merged_metadata(&self, path: &Utf8Path) -> Option<FileMetadata> {
+    self
+      .upper
+      .metadata_sync(path)
+      .ok()
+      .or_else(|| self.lower_metadata(path))
+  }
+
+  fn merged_read_dir(&self, dir: &Utf8Path) -> Result<Vec<String>> {
+    let upper = ReadableFileSystem::read_dir_sync(&self.upper, dir);
+    let lower = if self.is_whiteout(dir) {
+      Err(not_found())
+    } else {
+      self.lower.read_dir_sync(dir)
+    };
+    if upper.is_err() && lower.is_err() {
+      return Err(Error::new(std::io::ErrorKind::NotFound, "dir not exist"));
+    }
+
+    let mut entries: Vec<String> = lower
+      .unwrap_or_default()
+      .into_iter()
+      .filter(|name| !self.is_whiteout(&dir.join(name)))
+      .chain(upper.unwrap_or_default())
+      .collect();
+    entries.sort_unstable();
+    entries.dedup();
+    Ok(entries)
+  }
+
+  /// Remove the whiteout of a path which is created again.
+  ///
+  /// The entries of a recreated directory in the lower file system keep hidden,
+  /// as the directory was removed with them.
+  fn remove_whiteout(&self, path: &Utf8Path) {
+    let mut whiteouts = self.whiteouts.lock().expect("should get lock");
+    if !whiteouts.remove(path) {
+      return;
+    }
+    if let Ok(entries) = self.lower.read_dir_sync(path) {
+      whiteouts.extend(entries.into_iter().map(|name| path.join(name)));
+    }
+  }
+
+  fn add_whiteout(&self, path: &Utf8Path) {
+    if self.lower_metadata(path).is_some() {
+      let mut whiteouts = self.whiteouts.lock().expect("should get lock");
+      whiteouts.insert(path.to_path_buf());
+    }
+  }
+
+  /// Make sure the directory exists in the upper layer, the directory must exist in the merged view.
+  async fn copy_up_dir(&self, dir: &Utf8Path) -> Result<()> {
+    match self.merged_metadata(dir) {
+      Some(metadata) if metadata.is_directory => {
+        WritableFileSystem::create_dir_all(&self.upper, dir).await
+      }
+      Some(_) => Err(new_error("invalid dir path")),
+      None => Err(new_error("parent dir not exist")),
+    }
+  }
+
+  fn record_modified(&self, path: &Utf8Path) {
+    let mut changes = self.changes.lock().expect("should get lock");
+    changes.removed.remove(path.as_str());
+    changes.modified.insert(path.to_string());
+  }
+
+  fn record_removed(&self, path: &Utf8Path) {
+    let mut changes = self.changes.lock().expect("should get lock");
+    changes.modified.remove(path.as_str());
+    changes.removed.insert(path.to_string());
+  }
+}
+
+#[async_trait::async_trait]
+impl WritableFileSystem for OverlayFileSystem {
+  async fn create_dir(&self, dir: &Utf8Path) -> Result<()> {
+    match self.merged_metadata(dir) {
+      Some(metadata) if metadata.is_directory => return Ok(()),
+      Some(_) => return Err(new_error("invalid dir path")),
+      None => {}
+    }
+    if let Some(p) = dir.parent() {
+      self.copy_up_dir(p).await?;
+    }
+    WritableFileSystem::create_dir(&self.upper, dir).await?;
+    self.remove_whiteout(dir);
+    self.record_modified(dir);
+    Ok(())
+  }
+
+  async fn create_dir_all(&self, dir: &Utf8Path) -> Result<()> {
+    match self.merged_metadata(dir) {
+      Some(metadata) if metadata.is_directory => return Ok(()),
+      Some(_) => return Err(new_error("invalid dir path")),
+      None => {}
+    }
+    if let Some(p) = dir.parent() {
+      WritableFileSystem::create_dir_all(self, p).await?;
+    }
+    WritableFileSystem::create_dir(self, dir).await
+  }
+
+  async fn write(&self, file: &Utf8Path, data: &[u8]) -> Result<()> {
+    if self
+      .merged_metadata(file)
+      .is_some_and(|metadata| metadata.is_directory)
+    {
+      return Err(new_error("invalid file path"));
+    }
+    let p = file.parent().expect("should have parent dir");
+    self.copy_up_dir(p).await?;
+    WritableFileSystem::write(&self.upper, file, data).await?;
+    self.remove_whiteout(file);
+    self.record_modified(file);
+    Ok(())
+  }
+
+  async fn remove_file(&self, file: &Utf8Path) -> Result<()> {
+    match self.merged_metadata(file) {
+      Some(metadata) if metadata.is_directory => return Err(new_error("invalid file path")),
+      Some(_) => {}
+      None => return Ok(()),
+    }
+    WritableFileSystem::remove_file(&self.upper, file).await?;
+    self.add_whiteout(file);
+    self.record_removed(file);
+    Ok(())
+  }
+
+  async fn remove_dir_all(&self, dir: &Utf8Path) -> Result<()> {
+    match self.merged_metadata(dir) {
+      Some(metadata) if !metadata.is_directory => return Err(new_error("invalid dir path")),
+      Some(_) => {}
+      None => return Ok(()),
+    }
+    WritableFileSystem::remove_dir_all(&self.upper, dir).await?;
+    self.add_whiteout(dir);
+    self.record_removed(dir);
+    Ok(())
+  }
+
+  async fn read_dir(&self, dir: &Utf8Path) -> Result<Vec<String>> {
+    self.merged_read_dir(dir)
+  }
+
+  async fn read_file(&self, file: &Utf8Path) -> Result<Vec<u8>> {
+    ReadableFileSystem::read(self, file).await
+  }
+
+  async fn stat(&self, file: &Utf8Path) -> Result<FileMetadata> {
+    ReadableFileSystem::metadata(self, file).await
+  }
+
+  async fn set_permissions(&self, _path: &Utf8Path, _perm: FilePermissions) -> Result<()> {
+    Ok(())
+  }
+}
+
+#[async_trait::async_trait]
+impl ReadableFileSystem for OverlayFileSystem {
+  async fn read(&self, path: &Utf8Path) -> Result<Vec<u8>> {
+    if self.upper.metadata_sync(path).is_ok() {
+      return self.upper.read_sync(path);
+    }
+    if self.is_whiteout(path) {
+      return Err(not_found());
+    }
+    self.lower.read(path).await
+  }
+
+  fn read_sync(&self, path: &Utf8Path) -> Result<Vec<u8>> {
+    if self.upper.metadata_sync(path).is_ok() {
+      return self.upper.read_sync(path);
+    }
+    if self.is_whiteout(path) {
+      return Err(not_found());
+    }
+    self.lower.read_sync(path)
+  }
+
+  async fn metadata(&self, path: &Utf8Path) -> Result<FileMetadata> {
+    if let Ok(metadata) = self.upper.metadata_sync(path) {
+      return Ok(metadata);
+    }
+    if self.is_whiteout(path) {
+      return Err(not_found());
+    }
+    self.lower.metadata(path).await
+  }
+
+  fn metadata_sync(&self, path: &Utf8Path) -> Result<FileMetadata> {
+    self.merged_metadata(path).ok_or_else(not_found)
+  }
+
+  async fn symlink_metadata(&self, path: &Utf8Path) -> Result<FileMetadata> {
+    if let Ok(metadata) = self.upper.metadata_sync(path) {
+      return Ok(metadata);
+    }
+    if self.is_whiteout(path) {
+      return Err(not_found());
+    }
+    self.lower.symlink_metadata(path).await
+  }
+
+  /// The paths in the upper layer have no symlinks, so they are returned as is.
+  async fn canonicalize(&self, path: &Utf8Path) -> Result<Utf8PathBuf> {
+    if self.upper.metadata_sync(path).is_ok() {
+      return Ok(path.to_path_buf());
+    }
+    if self.is_whiteout(path) {
+      return Err(not_found());
+    }
+    self.lower.canonicalize(path).await
+  }
+
+  async fn read_dir(&self, dir: &Utf8Path) -> Result<Vec<String>> {
+    self.merged_read_dir(dir)
+  }
+
+  fn read_dir_sync(&self, dir: &Utf8Path) -> Result<Vec<String>> {
+    self.merged_read_dir(dir)
+  }
+
+  async fn permissions(&self, path: &Utf8Path) -> Result<Option<FilePermissions>> {
+    if self.upper.metadata_sync(path).is_ok() {
+      return Ok(None);
+    }
+    if self.is_whiteout(path) {
+      return Err(not_found());
+    }
+    self.lower.permissions(path).await
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use std::sync::Arc;
+
+  use rspack_paths::Utf8Path;
+
+  use super::OverlayFileSystem;
+  use crate::{MemoryFileSystem, ReadableFileSystem, WritableFileSystem};
+
+  async fn create_lower() -> Arc<MemoryFileSystem> {
+    let fs = Arc::new(MemoryFileSystem::default());
+    WritableFileSystem::create_dir_all(fs.as_ref(), Utf8Path::new("/src/utils"))
+      .await
+      .unwrap();
+    WritableFileSystem::write(fs.as_ref(), Utf8Path::new("/src/index.js"), b"lower")
+      .await
+      .unwrap();
+    WritableFileSystem::write(fs.as_ref(), Utf8Path::new("/src/utils/a.js"), b"a")
+      .await
+      .unwrap();
+    fs
+  }
+
+  #[tokio::test]
+  async fn should_read_upper_first() {
+    let lower = create_lower().await;
+    let fs = OverlayFileSystem::new(lower.clone());
+    assert_eq!(
+      ReadableFileSystem::read(&fs, Utf8Path::new("/src/index.js"))
+        .await
+        .unwrap(),
+      b"lower"
+    );
+
+    WritableFileSystem::write(&fs, Utf8Path::new("/src/index.js"), b"upper")
+      .await
+      .unwrap();
+    WritableFileSystem::write(&fs, Utf8Path::new("/src/utils/b.js"), b"b")
+      .await
+      .unwrap();
+    assert_eq!(
+      fs.read_sync(Utf8Path::new("/src/index.js")).unwrap(),
+      b"upper"
+    );
+    assert_eq!(
+      fs.read_sync(Utf8Path::new("/src/utils/b.js")).unwrap(),
+      b"b"
+    );
+    assert!(
+      fs.metadata_sync(Utf8Path::new("/src/utils/b.js"))
+        .unwrap()
+        .is_file
+    );
+    assert_eq!(
+      fs.read_dir_sync(Utf8Path::new("/src/utils")).unwrap(),
+      vec!["a.js".to_string(), "b.js".to_string()]
+    );
+    assert_eq!(
+      fs.canonicalize(Utf8Path::new("/src/utils/b.js"))
+        .await
+        .unwrap(),
+      Utf8Path::new("/src/utils/b.js")
+    );
+    // the lower file system is not modified
+    assert_eq!(
+      lower.read_sync(Utf8Path::new("/src/index.js")).unwrap(),
+      b"lower"
+    );
+    assert!(lower.read_sync(Utf8Path::new("/src/utils/b.js")).is_err());
+
+    // writing into a file or a missing directory fails
+    assert!(
+      WritableFileSystem::write(&fs, Utf8Path::new("/src/index.js/c.js"), b"c")
+        .await
+        .is_err()
+    );
+    assert!(
+      WritableFileSystem::write(&fs, Utf8Path::new("/lib/c.js"), b"c")
+        .await
+        .is_err()
+    );
+  }
+
+  #[tokio::test]
+  async fn should_hide_removed_paths() {
+    let lower = create_lower().await;
+    let fs = OverlayFileSystem::new(lower.clone());
+
+    WritableFileSystem::remove_file(&fs, Utf8Path::new("/src/index.js"))
+      .await
+      .unwrap();
+    assert!(fs.read_sync(Utf8Path::new("/src/index.js")).is_err());
+    assert!(fs.metadata_sync(Utf8Path::new("/src/index.js")).is_err());
+    assert_eq!(
+      fs.read_dir_sync(Utf8Path::new("/src")).unwrap(),
+      vec!["utils".to_string()]
+    );
+    assert!(lower.read_sync(Utf8Path::new("/src/index.js")).is_ok());
+
+    WritableFileSystem::remove_dir_all(&fs, Utf8Path::new("/src/utils"))
+      .await
+      .unwrap();
+    assert!(fs.read_sync(Utf8Path::new("/src/utils/a.js")).is_err());
+    assert!(fs.read_dir_sync(Utf8Path::new("/src/utils")).is_err());
+    assert!(fs.read_dir_sync(Utf8Path::new("/src")).unwrap().is_empty());
+
+    // the lower entries of a recreated directory keep hidden
+    WritableFileSystem::create_dir(&fs, Utf8Path::new("/src/utils"))
+      .await
+      .unwrap();
+    assert!(
+      fs.read_dir_sync(Utf8Path::new("/src/utils"))
+        .unwrap()
+        .is_empty()
+    );
+    WritableFileSystem::write(&fs, Utf8Path::new("/src/index.js"), b"new")
+      .await
+      .unwrap();
+    assert_eq!(
+      fs.read_sync(Utf8Path::new("/src/index.js")).unwrap(),
+      b"new"
+    );
+  }
+
+  #[tokio::test]
+  async fn should_report_changes() {
+    let lower = create_lower().await;
+    let fs = OverlayFileSystem::new(lower);
+
+    WritableFileSystem::write(&fs, Utf8Path::new("/src/index.js"), b"upper")
+      .await
+      .unwrap();
+    WritableFileSystem::write(&fs, Utf8Path::new("/src/utils/b.js"), b"b")
+      .await
+      .unwrap();
+    WritableFileSystem::remove_file(&fs, Utf8Path::new("/src/utils/b.js"))
+      .await
+      .unwrap();
+    WritableFileSystem::remove_file(&fs, Utf8Path::new("/src/utils/a.js"))
+      .await
+      .unwrap();
+
+    let (modified, removed) = fs.take_changes();
+    assert_eq!(modified.len(), 1);
+    assert!(modified.contains("/src/index.js"));
+    assert_eq!(removed.len(), 2);
+    assert!(removed.contains("/src/utils/a.js"));
+    assert!(removed.contains("/src/utils/b.js"));
+    let (modified, removed) = fs.take_changes();
+    assert!(modified.is_empty() && removed.is_empty());
+
+    fs.reset();
+    assert_eq!(
+      fs.read_sync(Utf8Path::new("/src/index.js")).unwrap(),
+      b"lower"
+    );
+    assert_eq!(
+      fs.read_sync(Utf8Path::new("/src/utils/a.js")).unwrap(),
+      b"a"
+    );
+    let (modified, removed) = fs.take_changes();
+    assert!(modified.contains("/src/index.js"));
+    assert!(modified.contains("/src/utils/a.js"));
+    assert!(removed.is_empty());
+  }
+}
@@ -5,23 +5,32 @@ use std::{
 
 use md4::Digest;
 use rspack_cacheable::{cacheable, with::AsPreset};
+use rspack_error::Error;
 use smol_str::SmolStr;
-use xxhash_rust::xxh64::Xxh64;
+use xxhash_rust::{xxh3::Xxh3Default, xxh64::Xxh64};
 
 #[derive(Debug, Clone, Copy)]
 pub enum HashFunction {
   Xxhash64,
+  Xxhash128,
   MD4,
   SHA256,
+  Blake3,
 }
 
-impl From<&str> for HashFunction {
-  fn from(value: &str) -> Self {
+impl TryFrom<&str> for HashFunction {
+  type Error = Error;
+
+  fn try_from(value: &str) -> Result<Self, Self::Error> {
     match value {
-      "xxhash64" => HashFunction::Xxhash64,
-      "md4" => HashFunction::MD4,
-      "sha256" => HashFunction::SHA256,
-      _ => panic!("Unsupported hash function: '{value}'. Expected one of: xxhash64, md4, sha256"),
+      "xxhash64" => Ok(HashFunction::Xxhash64),
+      "xxhash128" => Ok(HashFunction::Xxhash128),
+      "md4" => Ok(HashFunction::MD4),
+      "sha256" => Ok(HashFunction::SHA256),
+      "blake3" => Ok(HashFunction::Blake3),
+      _ => Err(Error::error(format!(
+        "Unsupported hash function: '{value}'. Expected one of: xxhash64, xxhash128, md4, sha256, blake3"
+      ))),
     }
   }
 }
@@ -29,13 +38,23 @@ impl From<&str> for HashFunction {
 #[derive(Debug, Clone, Copy)]
 pub enum HashDigest {
   Hex,
+  Base64Url,
+  Base36,
+  Base62,
 }
 
-impl From<&str> for HashDigest {
-  fn from(value: &str) -> Self {
+impl TryFrom<&str> for HashDigest {
+  type Error = Error;
+
+  fn try_from(value: &str) -> Result<Self, Self::Error> {
     match value {
-      "hex" => HashDigest::Hex,
-      _ => panic!("Unsupported hash digest: '{value}'. Expected: hex"),
+      "hex" => Ok(HashDigest::Hex),
+      "base64url" => Ok(HashDigest::Base64Url),
+      "base36" => Ok(HashDigest::Base36),
+      "base62" => Ok(HashDigest::Base62),
+      _ => Err(Error::error(format!(
+        "Unsupported hash digest: '{value}'. Expected one of: hex, base64url, base36, base62"
+      ))),
     }
   }
 }
@@ -58,16 +77,20 @@ impl From<Option<String>> for HashSalt {
 #[derive(Clone)]
 pub enum RspackHash {
   Xxhash64(Box<Xxh64>),
+  Xxhash128(Box<Xxh3Default>),
   MD4(Box<md4::Md4>),
   SHA256(Box<sha2::Sha256>),
+  Blake3(Box<blake3::Hasher>),
 }
 
 impl fmt::Debug for RspackHash {
   fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
     match self {
       Self::Xxhash64(_) => write!(f, "RspackHash(Xxhash64)"),
+      Self::Xxhash128(_) => write!(f, "RspackHash(Xxhash128)"),
       Self::MD4(_) => write!(f, "RspackHash(MD4)"),
-      Self::SHA256(_) => write!(f, "RspackHash(SHA256"),
+      Self::SHA256(_) => write!(f, "RspackHash(SHA256)"),
+      Self::Blake3(_) => write!(f, "RspackHash(Blake3)"),
     }
   }
 }
@@ -76,8 +99,10 @@ impl RspackHash {
   pub fn new(function: &HashFunction) -> Self {
     match function {
       HashFunction::Xxhash64 => Self::Xxhash64(Box::new(Xxh64::new(0))),
+      HashFunction::Xxhash128 => Self::Xxhash128(Box::default()),
       HashFunction::MD4 => Self::MD4(Box::new(md4::Md4::new())),
       HashFunction::SHA256 => Self::SHA256(Box::new(sha2::Sha256::new())),
+      HashFunction::Blake3 => Self::Blake3(Box::default()),
     }
   }
 
@@ -90,7 +115,7 @@ impl RspackHash {
   }
 
   pub fn digest(self, digest: &HashDigest) -> RspackHashDigest {
-    // The maximum value of sha256, the largest possible hash
+    // The maximum value of sha256 and blake3, the largest possible hash
     let mut result = [0; 32];
     let len;
 
@@ -100,6 +125,11 @@ impl RspackHash {
         len = buf.len();
         result[..len].copy_from_slice(&buf);
       }
+      RspackHash::Xxhash128(hasher) => {
+        let buf = hasher.digest128().to_be_bytes();
+        len = buf.len();
+        result[..len].copy_from_slice(&buf);
+      }
       RspackHash::MD4(hash) => {
         let buf = hash.finalize();
         len = buf.len();
@@ -110,6 +140,11 @@ impl RspackHash {
         len = buf.len();
         result[..len].copy_from_slice(&buf);
       }
+      RspackHash::Blake3(hash) => {
+        let buf = hash.finalize();
+        len = buf.as_bytes().len();
+        result[..len].copy_from_slice(buf.as_bytes());
+      }
     }
 
     RspackHashDigest::new(&result[..len], digest)
@@ -120,6 +155,7 @@ impl Hasher for RspackHash {
   fn finish(&self) -> u64 {
     match self {
       RspackHash::Xxhash64(hasher) => hasher.finish(),
+      RspackHash::Xxhash128(hasher) => (hasher.digest128() >> 64) as u64,
       RspackHash::MD4(hasher) => {
         // finalize take ownership, so we need to clone it
         let hash = (**hasher).clone().finalize();
@@ -145,14 +181,24 @@ impl Hasher for RspackHash {
           | (hash[7] as u64);
         msb_u64
       }
+      RspackHash::Blake3(hasher) => {
+        let hash = hasher.finalize();
+        let mut msb = [0; 8];
+        msb.copy_from_slice(&hash.as_bytes()[..8]);
+        u64::from_be_bytes(msb)
+      }
     }
   }
 
   fn write(&mut self, bytes: &[u8]) {
     match self {
       RspackHash::Xxhash64(hasher) => hasher.write(bytes),
+      RspackHash::Xxhash128(hasher) => hasher.update(bytes),
       RspackHash::MD4(hasher) => hasher.update(bytes),
       RspackHash::SHA256(hasher) => hasher.update(bytes),
+      RspackHash::Blake3(hasher) => {
+        hasher.update(bytes);
+      }
     }
   }
 }
@@ -175,12 +221,12 @@ impl From<&str> for RspackHashDigest {
 impl RspackHashDigest {
   /// `inner ` must be empty or come from a hash up to 256 bits
   pub fn new(inner: &[u8], digest: &HashDigest) -> Self {
+    let mut buf = [0; 64];
     let encoded = match digest {
-      HashDigest::Hex => {
-        let mut buf = [0; 64];
-        let s = hex(inner, &mut buf);
-        s.into()
-      }
+      HashDigest::Hex => hex(inner, &mut buf).into(),
+      HashDigest::Base64Url => base64url(inner, &mut buf).into(),
+      HashDigest::Base36 => base_n(inner, BASE36_TABLE, &mut buf).into(),
+      HashDigest::Base62 => base_n(inner, BASE62_TABLE, &mut buf).into(),
     };
     Self { encoded }
   }
@@ -228,3 +274,115 @@ fn hex<'a>(data: &[u8], output: &'a mut [u8]) -> &'a str {
   // hex is always ascii
   unsafe { std::str::from_utf8_unchecked(&output[..i]) }
 }
+
+/// Unpadded base64url, so the digest can be used in filenames and urls as is.
+#[inline]
+fn base64url<'a>(data: &[u8], output: &'a mut [u8]) -> &'a str {
+  const BASE64URL_TABLE: &[u8; 64] =
+    b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789-_";
+
+  assert!(data.len().div_ceil(3) * 4 <= output.len());
+
+  let mut i = 0;
+  for chunk in data.chunks(3) {
+    let b0 = chunk[0] as usize;
+    let b1 = chunk.get(1).copied().unwrap_or(0) as usize;
+    let b2 = chunk.get(2).copied().unwrap_or(0) as usize;
+    let n = (b0 << 16) | (b1 << 8) | b2;
+    let chars = chunk.len() + 1;
+    for j in 0..chars {
+      output[i + j] = BASE64URL_TABLE[(n >> (18 - j * 6)) & 0x3f];
+    }
+    i += chars;
+  }
+
+  // # Safety
+  //
+  // base64url is always ascii
+  unsafe { std::str::from_utf8_unchecked(&output[..i]) }
+}
+
+const BASE36_TABLE: &[u8] = b"0123456789abcdefghijklmnopqrstuvwxyz";
+const BASE62_TABLE: &[u8] = b"0123456789ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz";
+
+/// Encode `data` as a big-endian number in the radix of `table`.
+///
+/// The output has a fixed width for a given input length, and digits are emitted from the least
+/// significant one, so truncating the digest with `[contenthash:N]` keeps uniformly distributed digits.
+fn base_n<'a>(data: &[u8], table: &[u8], output: &'a mut [u8]) -> &'a str {
+  // 256 bits of input at most
+  let mut num = [0u8; 32];
+  assert!(data.len() <= num.len());
+  let num = &mut num[..data.len()];
+  num.copy_from_slice(data);
+
+  let radix = table.len() as u32;
+  let width = (data.len() as f64 * 8.0 / (radix as f64).log2()).ceil() as usize;
+  assert!(width <= output.len());
+
+  for digit in output.iter_mut().take(width) {
+    let mut rem = 0u32;
+    for byte in num.iter_mut() {
+      let acc = (rem << 8) | *byte as u32;
+      *byte = (acc / radix) as u8;
+      rem = acc % radix;
+    }
+    *digit = table[rem as usize];
+  }
+
+  // # Safety
+  //
+  // all tables are ascii
+  unsafe { std::str::from_utf8_unchecked(&output[..width]) }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  fn encode(data: &[u8], digest: HashDigest) -> String {
+    RspackHashDigest::new(data, &digest).encoded().to_string()
+  }
+
+  #[test]
+  fn base64url_digest() {
+    assert_eq!(encode(b"", HashDigest::Base64Url), "");
+    assert_eq!(encode(b"f", HashDigest::Base64Url), "Zg");
+    assert_eq!(encode(b"fo", HashDigest::Base64Url), "Zm8");
+    assert_eq!(encode(b"foo", HashDigest::Base64Url), "Zm9v");
+    assert_eq!(encode(&[0xfb, 0xff], HashDigest::Base64Url), "-_8");
+  }
+
+  #[test]
+  fn base_n_digest() {
+    assert_eq!(encode(&[0, 0], HashDigest::Base36), "0000");
+    // 0x00ff = 255 = 7 * 36 + 3
+    assert_eq!(encode(&[0, 0xff], HashDigest::Base36), "3700");
+    // 0x00ff = 255 = 4 * 62 + 7
+    assert_eq!(encode(&[0, 0xff], HashDigest::Base62), "740");
+    assert_eq!(encode(&[0xff; 32], HashDigest::Base36).len(), 50);
+    assert_eq!(encode(&[0xff; 32], HashDigest::Base62).len(), 43);
+  }
+
+  #[test]
+  fn digest_with_new_hash_functions() {
+    for (function, digest, len) in [
+      (HashFunction::Blake3, HashDigest::Hex, 64),
+      (HashFunction::Blake3, HashDigest::Base64Url, 43),
+      (HashFunction::Xxhash128, HashDigest::Hex, 32),
+      (HashFunction::Xxhash128, HashDigest::Base62, 22),
+    ] {
+      let mut hasher = RspackHash::new(&function);
+      hasher.write(b"rspack");
+      let digest = hasher.digest(&digest);
+      assert_eq!(digest.encoded().len(), len);
+      assert_eq!(digest.rendered(8).len(), 8);
+    }
+  }
+
+  #[test]
+  fn unknown_options_are_errors() {
+    assert!(HashFunction::try_from("md5").is_err());
+    assert!(HashDigest::try_from("base64").is_err());
+  }
+}
@@ -0,0 +1,172 @@
+use std::hash::Hasher;
+
+use rspack_core::{
+  ChunkGraph, Compilation, CompilationModuleIds, LibIdentOptions, ModuleIdsArtifact, Plugin,
+  incremental::IncrementalPasses,
+};
+use rspack_error::{Diagnostic, Error, Result};
+use rspack_hash::{HashDigest, HashFunction, RspackHash};
+use rspack_hook::{plugin, plugin_hook};
+
+use crate::id_helpers::{
+  compare_modules_by_pre_order_index_or_identifier, get_full_module_name,
+  get_used_module_ids_and_modules_with_artifact,
+};
+
+/// The digest of the module ids, `base64` is supported in addition to [`HashDigest`] for
+/// compatibility with webpack's `HashedModuleIdsPlugin`.
+#[derive(Debug, Clone, Copy)]
+pub enum HashedModuleIdsDigest {
+  /// Padded standard base64, the default of webpack.
+  Base64,
+  Digest(HashDigest),
+}
+
+impl TryFrom<&str> for HashedModuleIdsDigest {
+  type Error = Error;
+
+  fn try_from(value: &str) -> Result<Self, Self::Error> {
+    match value {
+      "base64" => Ok(Self::Base64),
+      _ => HashDigest::try_from(value).map(Self::Digest),
+    }
+  }
+}
+
+impl HashedModuleIdsDigest {
+  fn digest(&self, hasher: RspackHash) -> String {
+    match self {
+      Self::Base64 => {
+        let encoded = hasher.digest(&HashDigest::Base64Url);
+        let mut encoded = encoded
+          .encoded()
+          .chars()
+          .map(|c| match c {
+            '-' => '+',
+            '_' => '/',
+            c => c,
+          })
+          .collect::<String>();
+        while !encoded.len().is_multiple_of(4) {
+          encoded.push('=');
+        }
+        encoded
+      }
+      Self::Digest(digest) => hasher.digest(digest).encoded().to_string(),
+    }
+  }
+}
+
+#[derive(Debug)]
+pub struct HashedModuleIdsPluginOptions {
+  /// The context to make the identifiers of modules relative to, defaults to the compiler context.
+  pub context: Option<String>,
+  pub hash_function: HashFunction,
+  pub hash_digest: HashedModuleIdsDigest,
+  /// The length of the ids, which is extended when the prefix of the digest conflicts.
+  pub hash_digest_length: usize,
+}
+
+impl Default for HashedModuleIdsPluginOptions {
+  fn default() -> Self {
+    Self {
+      context: None,
+      hash_function: HashFunction::MD4,
+      hash_digest: HashedModuleIdsDigest::Base64,
+      hash_digest_length: 4,
+    }
+  }
+}
+
+/// Assigns module ids from the hash of the relative `lib_ident` of modules, the same as
+/// webpack's `HashedModuleIdsPlugin`.
+#[plugin]
+#[derive(Debug)]
+pub struct HashedModuleIdsPlugin {
+  options: HashedModuleIdsPluginOptions,
+}
+
+impl HashedModuleIdsPlugin {
+  pub fn new(options: HashedModuleIdsPluginOptions) -> Self {
+    Self::new_inner(options)
+  }
+}
+
+#[plugin_hook(CompilationModuleIds for HashedModuleIdsPlugin)]
+async fn module_ids(
+  &self,
+  compilation: &Compilation,
+  module_ids: &mut ModuleIdsArtifact,
+  diagnostics: &mut Vec<Diagnostic>,
+) -> Result<()> {
+  if let Some(diagnostic) = compilation.incremental.disable_passes(
+    IncrementalPasses::MODULE_IDS,
+    "HashedModuleIdsPlugin",
+    "it requires calculating the id of all the modules, which is a global effect",
+  ) {
+    if let Some(diagnostic) = diagnostic {
+      diagnostics.push(diagnostic);
+    }
+    module_ids.clear();
+  }
+
+  let (mut used_ids, modules) =
+    get_used_module_ids_and_modules_with_artifact(compilation, module_ids, None);
+
+  let context = self
+    .options
+    .context
+    .as_deref()
+    .unwrap_or(compilation.options.context.as_str());
+  let module_graph = compilation.get_module_graph();
+  let mut modules = modules
+    .into_iter()
+    .filter_map(|i| module_graph.module_by_identifier(&i))
+    .collect::<Vec<_>>();
+  modules.sort_unstable_by(|a, b| {
+    compare_modules_by_pre_order_index_or_identifier(module_graph, &a.identifier(), &b.identifier())
+  });
+
+  for module in modules {
+    let ident = module.lib_ident(LibIdentOptions { context }).map_or_else(
+      || get_full_module_name(module, context),
+      |ident| ident.into_owned(),
+    );
+    let mut hasher = RspackHash::new(&self.options.hash_function);
+    hasher.write(ident.as_bytes());
+    let hash_id = self.options.hash_digest.digest(hasher);
+
+    let mut len = self.options.hash_digest_length.min(hash_id.len());
+    while len < hash_id.len() && used_ids.contains(&hash_id[..len]) {
+      len += 1;
+    }
+    let module_id = hash_id[..len].to_string();
+    ChunkGraph::set_module_id(module_ids, module.identifier(), module_id.clone().into());
+    used_ids.insert(module_id);
+  }
+  Ok(())
+}
+
+impl Plugin for HashedModuleIdsPlugin {
+  fn apply(&self, ctx: &mut rspack_core::ApplyContext<'_>) -> Result<()> {
+    ctx.compilation_hooks.module_ids.tap(module_ids::new(self));
+    Ok(())
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn base64_digest() {
+    let mut hasher = RspackHash::new(&HashFunction::SHA256);
+    hasher.write(b"./src/b.js");
+    assert_eq!(
+      HashedModuleIdsDigest::Base64.digest(hasher),
+      "YbgfIcT2sNftvN0gd8jOQylD4EIfGU/RavuiBcgp+/A="
+    );
+    assert!(HashedModuleIdsDigest::try_from("base64").is_ok());
+    assert!(HashedModuleIdsDigest::try_from("latin1").is_err());
+  }
+}
@@ -11,5 +11,9 @@ mod natural_module_ids_plugin;
 pub use natural_module_ids_plugin::NaturalModuleIdsPlugin;
 mod natural_chunk_ids_plugin;
 pub use natural_chunk_ids_plugin::NaturalChunkIdsPlugin;
+mod hashed_module_ids_plugin;
+pub use hashed_module_ids_plugin::*;
 mod occurrence_chunk_ids_plugin;
 pub use occurrence_chunk_ids_plugin::*;
+mod record_ids_plugin;
+pub use record_ids_plugin::*;
@@ -0,0 +1,317 @@
+use std::{
+  collections::{BTreeMap, BTreeSet},
+  sync::Mutex,
+};
+
+use rspack_collections::DatabaseItem;
+use rspack_core::{
+  ChunkByUkey, ChunkGraph, ChunkNamedIdArtifact, Compilation, CompilationChunkIds,
+  CompilationModuleIds, CompilationParams, CompilerAfterEmit, CompilerThisCompilation,
+  ModuleIdsArtifact, Plugin, RecordsArtifact, incremental::IncrementalPasses,
+};
+use rspack_error::{Diagnostic, Result, error};
+use rspack_hook::{plugin, plugin_hook};
+use rspack_paths::Utf8PathBuf;
+use rustc_hash::FxHashSet;
+use serde::{Deserialize, Serialize};
+
+use crate::id_helpers::{
+  get_full_chunk_name, get_full_module_name, get_used_chunk_ids,
+  get_used_module_ids_and_modules_with_artifact,
+};
+
+/// Max number of missing modules listed in the warning.
+const MAX_LISTED_MISSING_MODULES: usize = 10;
+
+/// The keys of the ids in the records.
+const MODULES_KEY: &str = "modules";
+const CHUNKS_KEY: &str = "chunks";
+
+/// The ids of modules recorded by their relative identifiers
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ModuleRecords {
+  #[serde(default)]
+  pub by_identifier: BTreeMap<String, String>,
+}
+
+/// The ids of chunks recorded by their names, and by the relative identifiers of their modules
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ChunkRecords {
+  #[serde(default)]
+  pub by_name: BTreeMap<String, String>,
+  #[serde(default)]
+  pub by_modules: BTreeMap<String, String>,
+}
+
+#[derive(Debug, Default)]
+pub struct RecordIdsPluginOptions {
+  /// The records file to read the previous ids from.
+  pub input_path: Option<Utf8PathBuf>,
+  /// The records file to write the ids to after emit.
+  pub output_path: Option<Utf8PathBuf>,
+}
+
+/// Keeps the module and chunk ids assigned in previous builds
+///
+/// The recorded ids are assigned before the other id plugins, so they only assign ids to
+/// the new modules and chunks. The plugin also reads and writes the records file shared by
+/// the other plugins through [`RecordsArtifact`]. The records of the last build are kept in
+/// memory for rebuilds, and the input file is only read once.
+#[plugin]
+#[derive(Debug)]
+pub struct RecordIdsPlugin {
+  options: RecordIdsPluginOptions,
+  records: Mutex<Option<RecordsArtifact>>,
+}
+
+impl RecordIdsPlugin {
+  pub fn new(options: RecordIdsPluginOptions) -> Self {
+    Self::new_inner(options, Default::default())
+  }
+
+  async fn load_records(&self, compilation: &Compilation) -> Result<RecordsArtifact> {
+    if let Some(records) = self.records.lock().expect("should get lock").as_ref() {
+      return Ok(records.clone());
+    }
+
+    let records = match &self.options.input_path {
+      Some(input_path) => match compilation.input_filesystem.read(input_path).await {
+        Ok(content) => serde_json::from_slice::<RecordsArtifact>(&content)
+          .map_err(|e| error!("Failed to parse records file {input_path}: {e}"))?,
+        // there are no records before the first build
+        Err(rspack_fs::Error::Io(e)) if e.kind() == std::io::ErrorKind::NotFound => {
+          RecordsArtifact::default()
+        }
+        Err(e) => return Err(error!("Failed to read records file {input_path}: {e}")),
+      },
+      None => RecordsArtifact::default(),
+    };
+    *self.records.lock().expect("should get lock") = Some(records.clone());
+    Ok(records)
+  }
+}
+
+#[plugin_hook(CompilerThisCompilation for RecordIdsPlugin)]
+async fn this_compilation(
+  &self,
+  compilation: &mut Compilation,
+  _params: &mut CompilationParams,
+) -> Result<()> {
+  // the records are read before the compilation, so they can be used in any of its hooks
+  compilation.records_artifact = self.load_records(compilation).await?;
+  Ok(())
+}
+
+#[plugin_hook(CompilationModuleIds for RecordIdsPlugin, stage = -100)]
+async fn module_ids(
+  &self,
+  compilation: &Compilation,
+  module_ids: &mut ModuleIdsArtifact,
+  diagnostics: &mut Vec<Diagnostic>,
+) -> Result<()> {
+  // the recorded ids should be assigned before the incremental id plugins clear the ids,
+  // so the ids are cleared here instead
+  if let Some(diagnostic) = compilation.incremental.disable_passes(
+    IncrementalPasses::MODULE_IDS,
+    "RecordIdsPlugin (recordsPath)",
+    "it requires reviving the id of all the modules, which is a global effect",
+  ) {
+    if let Some(diagnostic) = diagnostic {
+      diagnostics.push(diagnostic);
+    }
+    module_ids.clear();
+  }
+
+  let Some(records) = compilation
+    .records_artifact
+    .get::<ModuleRecords>(MODULES_KEY)?
+  else {
+    return Ok(());
+  };
+  if records.by_identifier.is_empty() {
+    return Ok(());
+  }
+
+  let context = compilation.options.context.as_ref();
+  let module_graph = compilation.get_module_graph();
+  let (mut used_ids, modules) =
+    get_used_module_ids_and_modules_with_artifact(compilation, module_ids, None);
+  for module in modules
+    .iter()
+    .filter_map(|identifier| module_graph.module_by_identifier(identifier))
+  {
+    let name = get_full_module_name(module, context);
+    if let Some(id) = records.by_identifier.get(&name)
+      && used_ids.insert(id.clone())
+    {
+      ChunkGraph::set_module_id(module_ids, module.identifier(), id.clone().into());
+    }
+  }
+
+  let existing_names = module_graph
+    .modules()
+    .map(|(_, module)| module)
+    .filter(|module| module.need_id())
+    .map(|module| get_full_module_name(module, context))
+    .collect::<FxHashSet<_>>();
+  let missing = records
+    .by_identifier
+    .keys()
+    .filter(|name| !existing_names.contains(*name))
+    .collect::<Vec<_>>();
+  if !missing.is_empty() {
+    let mut message = format!(
+      "Records reference {} modules which no longer exist, their ids are not kept:",
+      missing.len()
+    );
+    for name in missing.iter().take(MAX_LISTED_MISSING_MODULES) {
+      message.push_str(&format!("\n  {name}"));
+    }
+    if missing.len() > MAX_LISTED_MISSING_MODULES {
+      message.push_str(&format!(
+        "\n  ...and {} more",
+        missing.len() - MAX_LISTED_MISSING_MODULES
+      ));
+    }
+    diagnostics.push(Diagnostic::warn("Records Warning".to_string(), message));
+  }
+  Ok(())
+}
+
+#[plugin_hook(CompilationChunkIds for RecordIdsPlugin, stage = -100)]
+async fn chunk_ids(
+  &self,
+  compilation: &Compilation,
+  chunk_by_ukey: &mut ChunkByUkey,
+  _named_chunk_ids_artifact: &mut ChunkNamedIdArtifact,
+  _diagnostics: &mut Vec<Diagnostic>,
+) -> Result<()> {
+  let Some(records) = compilation
+    .records_artifact
+    .get::<ChunkRecords>(CHUNKS_KEY)?
+  else {
+    return Ok(());
+  };
+  if records.by_name.is_empty() && records.by_modules.is_empty() {
+    return Ok(());
+  }
+
+  let context = compilation.options.context.as_str();
+  let mut used_ids = get_used_chunk_ids(chunk_by_ukey);
+  let mut revived = vec![];
+  for chunk in chunk_by_ukey.values().filter(|chunk| chunk.id().is_none()) {
+    let id = chunk
+      .name()
+      .and_then(|name| records.by_name.get(name))
+      .or_else(|| {
+        let name = get_full_chunk_name(
+          chunk,
+          &compilation.build_chunk_graph_artifact.chunk_graph,
+          compilation.get_module_graph(),
+          &compilation.module_graph_cache_artifact,
+          context,
+          &compilation.exports_info_artifact,
+        );
+        records.by_modules.get(&name)
+      });
+    if let Some(id) = id
+      && used_ids.insert(id.clone())
+    {
+      revived.push((chunk.ukey(), id.clone()));
+    }
+  }
+
+  for (chunk_ukey, id) in revived {
+    chunk_by_ukey.expect_get_mut(&chunk_ukey).set_id(id);
+  }
+  Ok(())
+}
+
+#[plugin_hook(CompilerAfterEmit for RecordIdsPlugin)]
+async fn after_emit(&self, compilation: &mut Compilation) -> Result<()> {
+  let context = compilation.options.context.as_str();
+  let module_graph = compilation.get_module_graph();
+
+  let mut module_records = ModuleRecords::default();
+  for (_, module) in module_graph.modules() {
+    if let Some(id) =
+      ChunkGraph::get_module_id(&compilation.module_ids_artifact, module.identifier())
+    {
+      module_records
+        .by_identifier
+        .insert(get_full_module_name(module, context), id.to_string());
+    }
+  }
+
+  let mut chunk_records = ChunkRecords::default();
+  let mut duplicated_keys = BTreeSet::default();
+  for chunk in compilation
+    .build_chunk_graph_artifact
+    .chunk_by_ukey
+    .values()
+  {
+    let Some(id) = chunk.id() else {
+      continue;
+    };
+    if let Some(name) = chunk.name() {
+      chunk_records
+        .by_name
+        .insert(name.to_string(), id.to_string());
+    }
+    let key = get_full_chunk_name(
+      chunk,
+      &compilation.build_chunk_graph_artifact.chunk_graph,
+      module_graph,
+      &compilation.module_graph_cache_artifact,
+      context,
+      &compilation.exports_info_artifact,
+    );
+    if chunk_records
+      .by_modules
+      .insert(key.clone(), id.to_string())
+      .is_some()
+    {
+      duplicated_keys.insert(key);
+    }
+  }
+  // chunks with the same modules can not be distinguished
+  for key in duplicated_keys {
+    chunk_records.by_modules.remove(&key);
+  }
+
+  // the records of the other plugins are kept
+  let records = &mut compilation.records_artifact;
+  records.set(MODULES_KEY, &module_records)?;
+  records.set(CHUNKS_KEY, &chunk_records)?;
+  let records = records.clone();
+
+  if let Some(output_path) = &self.options.output_path {
+    let content = serde_json::to_string_pretty(&records)
+      .map_err(|e| error!("Failed to serialize records: {e}"))?;
+    if let Some(dir) = output_path.parent() {
+      compilation.output_filesystem.create_dir_all(dir).await?;
+    }
+    compilation
+      .output_filesystem
+      .write(output_path, format!("{content}\n").as_bytes())
+      .await?;
+  }
+
+  *self.records.lock().expect("should get lock") = Some(records);
+  Ok(())
+}
+
+impl Plugin for RecordIdsPlugin {
+  fn apply(&self, ctx: &mut rspack_core::ApplyContext<'_>) -> Result<()> {
+    ctx
+      .compiler_hooks
+      .this_compilation
+      .tap(this_compilation::new(self));
+    ctx.compilation_hooks.module_ids.tap(module_ids::new(self));
+    ctx.compilation_hooks.chunk_ids.tap(chunk_ids::new(self));
+    ctx.compiler_hooks.after_emit.tap(after_emit::new(self));
+    Ok(())
+  }
+}
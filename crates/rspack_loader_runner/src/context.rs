@@ -7,7 +7,7 @@ use rspack_sources::SourceMap;
 use rustc_hash::FxHashSet as HashSet;
 
 use crate::{
-  AdditionalData, Content, LoaderItem, LoaderRunnerPlugin, ParseMeta, ResourceData,
+  AdditionalData, Content, LoaderItem, LoaderProfile, LoaderRunnerPlugin, ParseMeta, ResourceData,
   loader::LoaderItemList,
 };
 
@@ -59,6 +59,8 @@ pub struct LoaderContext<Context: Send> {
   pub loader_items: Vec<LoaderItem<Context>>,
   #[debug(skip)]
   pub plugin: Option<Arc<dyn LoaderRunnerPlugin<Context = Context>>>,
+  /// Time spent in each loader, only collected when profiling is enabled.
+  pub(crate) profile: Option<Vec<LoaderProfile>>,
 }
 
 impl<Context: Send> LoaderContext<Context> {
@@ -1,5 +1,11 @@
-use std::{fmt::Debug, path::PathBuf, sync::Arc};
+use std::{
+  fmt::Debug,
+  path::PathBuf,
+  sync::Arc,
+  time::{Duration, Instant},
+};
 
+use rspack_collections::Identifier;
 use rspack_error::{Diagnostic, Error, Result, error};
 use rspack_fs::ReadableFileSystem;
 use rspack_paths::Utf8PathBuf;
@@ -25,6 +31,31 @@ impl<Context: Send> LoaderContext<Context> {
     }
     Ok(false)
   }
+
+  fn profile_start(&self) -> Option<(i32, Instant)> {
+    self
+      .profile
+      .is_some()
+      .then(|| (self.loader_index, Instant::now()))
+  }
+
+  fn profile_end(&mut self, start: Option<(i32, Instant)>, pitch: bool) {
+    let Some((loader_index, start)) = start else {
+      return;
+    };
+    let Some(profile) = self
+      .profile
+      .as_mut()
+      .and_then(|profile| profile.get_mut(usize::try_from(loader_index).ok()?))
+    else {
+      return;
+    };
+    if pitch {
+      profile.pitch += start.elapsed();
+    } else {
+      profile.normal += start.elapsed();
+    }
+  }
 }
 
 #[tracing::instrument("LoaderRunner:process_resource",
@@ -66,6 +97,7 @@ fn create_loader_context<Context: Send>(
   resource_data: Arc<ResourceData>,
   plugin: Option<Arc<dyn LoaderRunnerPlugin<Context = Context>>>,
   context: Context,
+  profile: bool,
 ) -> LoaderContext<Context> {
   let mut file_dependencies: HashSet<PathBuf> = Default::default();
   if let Some(resource_path) = resource_data.path()
@@ -73,6 +105,16 @@ fn create_loader_context<Context: Send>(
   {
     file_dependencies.insert(resource_path.to_owned().into_std_path_buf());
   }
+  let profile = profile.then(|| {
+    loader_items
+      .iter()
+      .map(|item| LoaderProfile {
+        loader: item.request(),
+        pitch: Duration::ZERO,
+        normal: Duration::ZERO,
+      })
+      .collect()
+  });
 
   LoaderContext {
     hot: false,
@@ -92,9 +134,14 @@ fn create_loader_context<Context: Send>(
     plugin,
     resource_data,
     diagnostics: vec![],
+    profile,
   }
 }
 
+/// Run the loaders on the resource.
+///
+/// When `profile` is set, the time spent in the pitch and normal phases of each loader
+/// is collected into [LoaderResult::profile].
 #[tracing::instrument("LoaderRunner:run_loaders", skip_all, level = "trace")]
 pub async fn run_loaders<Context: Send>(
   loaders: Vec<Arc<dyn Loader<Context>>>,
@@ -102,12 +149,13 @@ pub async fn run_loaders<Context: Send>(
   plugin: Option<Arc<dyn LoaderRunnerPlugin<Context = Context>>>,
   context: Context,
   fs: Arc<dyn ReadableFileSystem>,
+  profile: bool,
 ) -> (LoaderResult<Context>, Option<Error>) {
   let loaders = loaders
     .into_iter()
     .map(|i| i.into())
     .collect::<Vec<LoaderItem<Context>>>();
-  let mut cx = create_loader_context(loaders, resource_data, plugin, context);
+  let mut cx = create_loader_context(loaders, resource_data, plugin, context, profile);
   let result = run_loaders_impl(&mut cx, fs).await;
   (LoaderResult::new(cx), result.err())
 }
@@ -132,7 +180,10 @@ async fn run_loaders_impl<Context: Send>(
           continue;
         }
         let span = info_span!("run_loader:pitch:yield_to_js", resource);
-        if cx.start_yielding().instrument(span).await? {
+        let start = cx.profile_start();
+        let yielded = cx.start_yielding().instrument(span).await?;
+        cx.profile_end(start, true);
+        if yielded {
           if cx.content.is_some() {
             cx.state.transition(State::Normal);
             cx.loader_index -= 1;
@@ -148,7 +199,9 @@ async fn run_loaders_impl<Context: Send>(
         cx.current_loader().set_pitch_executed();
         let loader = cx.current_loader().loader().clone();
         let span = info_span!("run_loader:pitch", resource);
+        let start = cx.profile_start();
         loader.pitch(cx).instrument(span).await?;
+        cx.profile_end(start, true);
         if cx.content.is_some() {
           cx.state.transition(State::Normal);
           cx.loader_index -= 1;
@@ -171,7 +224,10 @@ async fn run_loaders_impl<Context: Send>(
           continue;
         }
         let span = info_span!("run_loader:yield_to_js", resource);
-        if cx.start_yielding().instrument(span).await? {
+        let start = cx.profile_start();
+        let yielded = cx.start_yielding().instrument(span).await?;
+        cx.profile_end(start, false);
+        if yielded {
           continue;
         }
 
@@ -184,7 +240,9 @@ async fn run_loaders_impl<Context: Send>(
         let loader = cx.current_loader().loader().clone();
 
         let span = info_span!("run_loader:normal", resource);
+        let start = cx.profile_start();
         loader.run(cx).instrument(span).await?;
+        cx.profile_end(start, false);
         if !cx.current_loader().finish_called() {
           // If nothing is returned from this loader,
           // we set everything to [None] and move to the next loader.
@@ -210,6 +268,17 @@ async fn run_loaders_impl<Context: Send>(
   Ok(())
 }
 
+/// Time spent in the pitch and normal phases of a loader.
+///
+/// Loaders running on the JavaScript side are executed in a single yield,
+/// so their time is attributed to the loader the yield started from.
+#[derive(Debug, Clone)]
+pub struct LoaderProfile {
+  pub loader: Identifier,
+  pub pitch: Duration,
+  pub normal: Duration,
+}
+
 #[derive(Debug)]
 pub struct LoaderResult<Context> {
   pub context: Context,
@@ -224,6 +293,8 @@ pub struct LoaderResult<Context> {
   pub additional_data: Option<AdditionalData>,
   pub parse_meta: ParseMeta,
   pub current_loader: Option<Utf8PathBuf>,
+  /// Only available when profiling is enabled in [run_loaders].
+  pub profile: Option<Vec<LoaderProfile>>,
 }
 
 impl<Context: Send> LoaderResult<Context> {
@@ -250,6 +321,7 @@ impl<Context: Send> LoaderResult<Context> {
         })
         .flatten()
         .map(|loader| loader.path().to_path_buf()),
+      profile: loader_context.profile,
     }
   }
 }
@@ -442,7 +514,8 @@ mod test {
         rs.clone(),
         Some(Arc::new(TestContentPlugin)),
         (),
-        Arc::new(NativeFileSystem::new(false))
+        Arc::new(NativeFileSystem::new(false)),
+        false,
       )
       .await
       .1
@@ -462,7 +535,8 @@ mod test {
         rs.clone(),
         Some(Arc::new(TestContentPlugin)),
         (),
-        Arc::new(NativeFileSystem::new(false))
+        Arc::new(NativeFileSystem::new(false)),
+        false,
       )
       .await
       .1
@@ -536,6 +610,7 @@ mod test {
         Some(Arc::new(TestContentPlugin)),
         (),
         Arc::new(NativeFileSystem::new(false)),
+        false,
       )
       .await
       .1
@@ -592,11 +667,80 @@ mod test {
         rs,
         Some(Arc::new(TestContentPlugin)),
         (),
-        Arc::new(NativeFileSystem::new(false))
+        Arc::new(NativeFileSystem::new(false)),
+        false,
       )
       .await
       .1
       .is_some()
     );
   }
+
+  #[tokio::test]
+  async fn should_collect_loader_profile() {
+    #[cacheable]
+    struct Pitching;
+
+    #[cacheable_dyn]
+    #[async_trait::async_trait]
+    impl Loader<()> for Pitching {
+      fn identifier(&self) -> Identifier {
+        "/rspack/pitching-loader".into()
+      }
+
+      async fn pitch(&self, _loader_context: &mut LoaderContext<()>) -> Result<()> {
+        Ok(())
+      }
+    }
+
+    #[cacheable]
+    struct Normal;
+
+    #[cacheable_dyn]
+    #[async_trait::async_trait]
+    impl Loader<()> for Normal {
+      fn identifier(&self) -> Identifier {
+        "/rspack/normal-loader".into()
+      }
+
+      async fn run(&self, loader_context: &mut LoaderContext<()>) -> Result<()> {
+        loader_context.finish_with((String::new(), None, None));
+        Ok(())
+      }
+    }
+
+    let rs = Arc::new(ResourceData::new_with_resource(
+      "/rspack/main.js".to_owned(),
+    ));
+
+    let (result, err) = run_loaders(
+      vec![Arc::new(Pitching) as Arc<dyn Loader>, Arc::new(Normal)],
+      rs.clone(),
+      Some(Arc::new(TestContentPlugin)),
+      (),
+      Arc::new(NativeFileSystem::new(false)),
+      true,
+    )
+    .await;
+    assert!(err.is_none());
+    let profile = result.profile.expect("should have profile");
+    assert_eq!(
+      profile
+        .iter()
+        .map(|profile| profile.loader.as_str())
+        .collect::<Vec<_>>(),
+      ["/rspack/pitching-loader", "/rspack/normal-loader"]
+    );
+
+    let (result, _) = run_loaders(
+      vec![Arc::new(Normal) as Arc<dyn Loader>],
+      rs,
+      Some(Arc::new(TestContentPlugin)),
+      (),
+      Arc::new(NativeFileSystem::new(false)),
+      false,
+    )
+    .await;
+    assert!(result.profile.is_none());
+  }
 }
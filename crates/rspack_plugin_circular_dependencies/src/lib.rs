@@ -1,15 +1,20 @@
+use std::collections::{BTreeSet, VecDeque};
+
 use cow_utils::CowUtils;
 use derive_more::Debug;
 use futures::future::BoxFuture;
 use itertools::Itertools;
 use rspack_collections::{Identifier, IdentifierMap};
 use rspack_core::{
-  Compilation, CompilationOptimizeModules, DependencyType, ModuleIdentifier, Plugin,
+  Compilation, CompilationOptimizeModules, CompilationProcessAssets, DependencyType,
+  ModuleIdentifier, Plugin,
 };
-use rspack_error::{Diagnostic, Result};
+use rspack_error::{Diagnostic, Result, error};
 use rspack_hook::{plugin, plugin_hook};
+use rspack_paths::Utf8PathBuf;
 use rspack_regex::RspackRegex;
 use rustc_hash::FxHashSet as HashSet;
+use serde::{Deserialize, Serialize};
 
 struct CycleDetector<'a> {
   module_map: &'a IdentifierMap<GraphModule>,
@@ -28,21 +33,27 @@ impl<'a> CycleDetector<'a> {
   }
 
   /// Returns all dependency cycles contained in the dependency graph starting at
-  /// `initial_module_id`.
+  /// `initial_module_id`, stopping once `limit` cycles are found. The flag is
+  /// true if there are more cycles than `limit`.
   fn find_cycles_from(
     &mut self,
     initial_module_id: ModuleIdentifier,
-  ) -> Vec<Vec<ModuleIdentifier>> {
+    limit: usize,
+  ) -> (Vec<Vec<ModuleIdentifier>>, bool) {
     let mut cycles = vec![];
+    // search for one more cycle to know whether the limit cut off any
     self.recurse_dependencies(
       initial_module_id,
       &mut HashSet::default(),
       &mut vec![initial_module_id],
       &mut cycles,
+      limit.saturating_add(1),
     );
+    let is_truncated = cycles.len() > limit;
+    cycles.truncate(limit);
     // sort to keep output stable
     cycles.sort();
-    cycles
+    (cycles, is_truncated)
   }
 
   fn recurse_dependencies(
@@ -51,9 +62,14 @@ impl<'a> CycleDetector<'a> {
     seen_relations: &mut HashSet<(ModuleIdentifier, ModuleIdentifier)>,
     current_path: &mut Vec<ModuleIdentifier>,
     found_cycles: &mut Vec<Vec<ModuleIdentifier>>,
+    limit: usize,
   ) {
     current_path.push(current_module_id);
     for target_id in self.get_module(&current_module_id).dependencies.keys() {
+      if found_cycles.len() >= limit {
+        break;
+      }
+
       // If the current path already contains the dependent module, then it
       // creates a cycle and doesn't need to be traversed further. Otherwise,
       // recurse through that dependency to keep searching.
@@ -75,12 +91,154 @@ impl<'a> CycleDetector<'a> {
         continue;
       }
       seen_relations.insert((current_module_id, *target_id));
-      self.recurse_dependencies(*target_id, seen_relations, current_path, found_cycles);
+      self.recurse_dependencies(
+        *target_id,
+        seen_relations,
+        current_path,
+        found_cycles,
+        limit,
+      );
     }
     current_path.pop();
   }
 }
 
+/// Finds the strongly connected components of the dependency graph with
+/// Tarjan's algorithm. The state is kept across calls, so every module is
+/// visited once and every component is returned from the first call reaching
+/// it.
+struct ComponentDetector<'a, F> {
+  module_map: &'a IdentifierMap<GraphModule>,
+  /// Returns true if the connection `(from, to)` should not count for creating
+  /// cycles.
+  is_connection_ignored: F,
+  indices: IdentifierMap<usize>,
+  low_links: IdentifierMap<usize>,
+  stack: Vec<ModuleIdentifier>,
+  on_stack: HashSet<ModuleIdentifier>,
+}
+
+impl<'a, F> ComponentDetector<'a, F>
+where
+  F: Fn(&ModuleIdentifier, &ModuleIdentifier) -> bool,
+{
+  fn new(module_map: &'a IdentifierMap<GraphModule>, is_connection_ignored: F) -> Self {
+    Self {
+      module_map,
+      is_connection_ignored,
+      indices: IdentifierMap::default(),
+      low_links: IdentifierMap::default(),
+      stack: vec![],
+      on_stack: HashSet::default(),
+    }
+  }
+
+  fn successors(&self, module_id: &ModuleIdentifier) -> Vec<ModuleIdentifier> {
+    let mut successors = self.module_map[module_id]
+      .dependencies
+      .keys()
+      .filter(|target_id| !(self.is_connection_ignored)(module_id, target_id))
+      .copied()
+      .collect::<Vec<_>>();
+    // sort to keep output stable
+    successors.sort();
+    successors
+  }
+
+  fn open(&mut self, module_id: ModuleIdentifier) -> Vec<ModuleIdentifier> {
+    let index = self.indices.len();
+    self.indices.insert(module_id, index);
+    self.low_links.insert(module_id, index);
+    self.stack.push(module_id);
+    self.on_stack.insert(module_id);
+    self.successors(&module_id)
+  }
+
+  /// Returns the components containing more than one module which are reachable
+  /// from `initial_module_id` and not returned before, each starting with its
+  /// smallest module.
+  fn find_components_from(
+    &mut self,
+    initial_module_id: ModuleIdentifier,
+  ) -> Vec<Vec<ModuleIdentifier>> {
+    let mut components = vec![];
+    if self.indices.contains_key(&initial_module_id) {
+      return components;
+    }
+
+    // The traversal is iterative, as the recursion may overflow the stack on
+    // deep dependency graphs.
+    let successors = self.open(initial_module_id);
+    let mut call_stack = vec![(initial_module_id, successors, 0)];
+    while let Some((module_id, successors, position)) = call_stack.last_mut() {
+      let module_id = *module_id;
+      if let Some(&target_id) = successors.get(*position) {
+        *position += 1;
+        if !self.indices.contains_key(&target_id) {
+          let successors = self.open(target_id);
+          call_stack.push((target_id, successors, 0));
+        } else if self.on_stack.contains(&target_id) {
+          let low_link = self.low_links[&module_id].min(self.indices[&target_id]);
+          self.low_links.insert(module_id, low_link);
+        }
+        continue;
+      }
+
+      call_stack.pop();
+      let low_link = self.low_links[&module_id];
+      if let Some((parent_id, ..)) = call_stack.last() {
+        let parent_low_link = self.low_links[parent_id].min(low_link);
+        self.low_links.insert(*parent_id, parent_low_link);
+      }
+      if low_link != self.indices[&module_id] {
+        continue;
+      }
+
+      let mut component = vec![];
+      while let Some(member_id) = self.stack.pop() {
+        self.on_stack.remove(&member_id);
+        component.push(member_id);
+        if member_id == module_id {
+          break;
+        }
+      }
+      if component.len() > 1 {
+        component.sort();
+        components.push(component);
+      }
+    }
+    components.sort();
+    components
+  }
+
+  /// Returns a shortest cycle through the first module of `component`.
+  fn shortest_cycle(&self, component: &[ModuleIdentifier]) -> Vec<ModuleIdentifier> {
+    let start_id = component[0];
+    let members = component.iter().collect::<HashSet<_>>();
+    let mut parents: IdentifierMap<ModuleIdentifier> = IdentifierMap::default();
+    let mut queue = VecDeque::from([start_id]);
+    while let Some(module_id) = queue.pop_front() {
+      for target_id in self.successors(&module_id) {
+        if target_id == start_id {
+          let mut cycle = vec![start_id, module_id];
+          let mut current_id = module_id;
+          while let Some(parent_id) = parents.get(&current_id) {
+            cycle.push(*parent_id);
+            current_id = *parent_id;
+          }
+          cycle.reverse();
+          return cycle;
+        }
+        if members.contains(&target_id) && !parents.contains_key(&target_id) {
+          parents.insert(target_id, module_id);
+          queue.push_back(target_id);
+        }
+      }
+    }
+    unreachable!("Strongly connected component should contain a cycle through every module")
+  }
+}
+
 /// Single Dependency representing all types of connections to a target module.
 #[derive(Debug)]
 struct AggregatedDependency {
@@ -115,6 +273,17 @@ impl AggregatedDependency {
       )
     })
   }
+
+  /// Returns true if any type of this dependency is a static ESM import, which
+  /// evaluates the target module when this module is initialized.
+  fn is_evaluated_on_init(&self) -> bool {
+    self.types.iter().any(|ty| {
+      matches!(
+        ty,
+        DependencyType::EsmImport | DependencyType::EsmExportImport
+      )
+    })
+  }
 }
 
 #[derive(Debug)]
@@ -143,7 +312,7 @@ impl GraphModule {
   }
 }
 
-fn build_module_map(compilation: &Compilation) -> IdentifierMap<GraphModule> {
+fn build_module_map(compilation: &Compilation, init_only: bool) -> IdentifierMap<GraphModule> {
   let module_graph = compilation.get_module_graph();
 
   let mut module_map: IdentifierMap<GraphModule> = IdentifierMap::default();
@@ -170,6 +339,11 @@ fn build_module_map(compilation: &Compilation) -> IdentifierMap<GraphModule> {
       graph_module.add_dependency(dependent_module.identifier(), *dependency.dependency_type());
     }
 
+    if init_only {
+      graph_module
+        .dependencies
+        .retain(|_, dependency| dependency.is_evaluated_on_init());
+    }
     module_map.insert(id, graph_module);
   }
   module_map
@@ -205,10 +379,66 @@ impl CircularDependencyIgnoredConnection {
   }
 }
 
+/// The content of the baseline file, the known cycles normalized by the
+/// relative paths of their modules.
+#[derive(Debug, Default, Serialize, Deserialize)]
+struct CircularDependencyBaseline {
+  cycles: BTreeSet<Vec<String>>,
+}
+
+#[derive(Debug, Default)]
+struct BaselineState {
+  /// The cycles in the baseline file, or `None` if the baseline is created or
+  /// updated in this compilation, so every cycle is accepted.
+  known: Option<CircularDependencyBaseline>,
+  found: CircularDependencyBaseline,
+}
+
+/// Rotates the closed `cycle` to start at its smallest module, so the same
+/// cycle found from different modules is the same entry in the baseline.
+fn normalize_cycle(cycle: &[String]) -> Vec<String> {
+  let Some((_, modules)) = cycle.split_last() else {
+    return vec![];
+  };
+  let start = modules
+    .iter()
+    .position_min()
+    .expect("cycle should not be empty");
+  let mut normalized = modules[start..].to_vec();
+  normalized.extend_from_slice(&modules[..start]);
+  normalized.push(modules[start].clone());
+  normalized
+}
+
 pub type CycleHandlerFn =
   Box<dyn Fn(String, Vec<String>) -> BoxFuture<'static, Result<()>> + Sync + Send>;
 pub type CompilationHookFn = Box<dyn Fn() -> BoxFuture<'static, Result<()>> + Sync + Send>;
 
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum CircularDependencyReportMode {
+  /// Report every cycle reachable from the entry modules.
+  #[default]
+  Cycles,
+  /// Report every strongly connected component once, with its size and a
+  /// shortest cycle through it. This is much faster on large tangled graphs
+  /// where the number of cycles explodes.
+  Components,
+}
+
+impl TryFrom<&str> for CircularDependencyReportMode {
+  type Error = rspack_error::Error;
+
+  fn try_from(value: &str) -> Result<Self> {
+    match value {
+      "cycles" => Ok(Self::Cycles),
+      "components" => Ok(Self::Components),
+      _ => Err(error!(
+        "Unknown circular dependency report mode \"{value}\", expected \"cycles\" or \"components\""
+      )),
+    }
+  }
+}
+
 #[derive(Debug)]
 pub struct CircularDependencyRspackPluginOptions {
   /// When `true`, the plugin will emit Error diagnostics rather than the
@@ -221,6 +451,20 @@ pub struct CircularDependencyRspackPluginOptions {
   /// Connections are represented as `[from, to]`, where each entry must be an
   /// exact match for the module path.
   pub ignored_connections: Option<Vec<CircularDependencyIgnoredConnection>>,
+  pub mode: CircularDependencyReportMode,
+  /// When `true`, only static ESM imports, which are evaluated when the module
+  /// is initialized, count for creating cycles.
+  pub init_only: bool,
+  /// The maximum number of cycles to find, including the ignored ones. The
+  /// detection stops once it is reached.
+  pub max_cycles: Option<usize>,
+  /// The file of known cycles. Cycles in it are not reported, and any other
+  /// detected cycle is reported as an error. The file is created with the
+  /// detected cycles if it does not exist.
+  pub baseline_path: Option<Utf8PathBuf>,
+  /// When `true`, the baseline file is rewritten with the detected cycles
+  /// instead of being checked.
+  pub update_baseline: bool,
   /// Handler function called for every detected cycle. Providing this handler
   /// overrides the default behavior of adding diagnostics to the compilation.
   #[debug(skip)]
@@ -260,36 +504,41 @@ impl CircularDependencyRspackPlugin {
     }
   }
 
-  fn is_cycle_ignored(
+  fn is_dependency_ignored(
     &self,
     module_map: &IdentifierMap<GraphModule>,
-    cycle: &[ModuleIdentifier],
+    module_id: &ModuleIdentifier,
+    target_id: &ModuleIdentifier,
     compilation: &Compilation,
   ) -> bool {
-    for window in cycle.windows(2) {
-      let [module_id, target_id] = [&window[0], &window[1]];
-      // If any dependency in the cycle is purely asynchronous, then it does not count as a runtime
-      // circular dependency, since execution order will be guaranteed.
-      if module_map[module_id].dependencies[target_id].is_asynchronous_only() {
-        return true;
-      }
+    // If any dependency in the cycle is purely asynchronous, then it does not count as a runtime
+    // circular dependency, since execution order will be guaranteed.
+    if module_map[module_id].dependencies[target_id].is_asynchronous_only() {
+      return true;
+    }
 
-      let Some(module) = compilation
-        .module_by_identifier(module_id)
-        .and_then(|m| m.as_normal_module())
-      else {
-        continue;
-      };
+    let Some(module) = compilation
+      .module_by_identifier(module_id)
+      .and_then(|m| m.as_normal_module())
+    else {
+      return false;
+    };
 
-      // Not all cycles are errors, so filter out any cycles containing
-      // explicitly-ignored modules.
-      if self.is_ignored_module(module.resource_resolved_data().resource())
-        || self.is_ignored_connection(module_id, target_id)
-      {
-        return true;
-      }
-    }
-    false
+    // Not all cycles are errors, so filter out any cycles containing
+    // explicitly-ignored modules.
+    self.is_ignored_module(module.resource_resolved_data().resource())
+      || self.is_ignored_connection(module_id, target_id)
+  }
+
+  fn is_cycle_ignored(
+    &self,
+    module_map: &IdentifierMap<GraphModule>,
+    cycle: &[ModuleIdentifier],
+    compilation: &Compilation,
+  ) -> bool {
+    cycle
+      .windows(2)
+      .any(|window| self.is_dependency_ignored(module_map, &window[0], &window[1], compilation))
   }
 
   async fn handle_cycle_ignored(
@@ -308,19 +557,11 @@ impl CircularDependencyRspackPlugin {
     &self,
     entrypoint: String,
     cycle: Vec<ModuleIdentifier>,
+    component_size: Option<usize>,
+    baseline: Option<&mut BaselineState>,
     compilation: &Compilation,
     diagnostics: &mut Vec<Diagnostic>,
   ) -> Result<()> {
-    if let Some(callback) = &self.options.on_detected {
-      return callback(entrypoint, cycle.iter().map(ToString::to_string).collect()).await;
-    }
-
-    let diagnostic_factory = if self.options.fail_on_error {
-      Diagnostic::error
-    } else {
-      Diagnostic::warn
-    };
-
     let cwd = std::env::current_dir()
       .expect("cwd should be available")
       .to_string_lossy()
@@ -344,15 +585,121 @@ impl CircularDependencyRspackPlugin {
       })
       .collect();
 
-    diagnostics.push(diagnostic_factory(
-      "Circular Dependency".to_string(),
-      format!(
+    // With a baseline, only the new cycles are reported, and they are errors.
+    let mut fail_on_error = self.options.fail_on_error;
+    if let Some(baseline) = baseline {
+      let normalized = normalize_cycle(&cycle_without_root);
+      let is_known = baseline
+        .known
+        .as_ref()
+        .is_none_or(|known| known.cycles.contains(&normalized));
+      baseline.found.cycles.insert(normalized);
+      if is_known {
+        return Ok(());
+      }
+      fail_on_error = true;
+    }
+
+    if let Some(callback) = &self.options.on_detected {
+      return callback(entrypoint, cycle.iter().map(ToString::to_string).collect()).await;
+    }
+
+    let diagnostic_factory = if fail_on_error {
+      Diagnostic::error
+    } else {
+      Diagnostic::warn
+    };
+
+    let message = match component_size {
+      Some(size) => format!(
+        "Circular dependency detected in {size} strongly connected modules, shortest cycle:\n {}",
+        cycle_without_root.iter().join(" -> ")
+      ),
+      None => format!(
         "Circular dependency detected:\n {}",
         cycle_without_root.iter().join(" -> ")
       ),
+    };
+    diagnostics.push(diagnostic_factory(
+      "Circular Dependency".to_string(),
+      message,
     ));
     Ok(())
   }
+
+  async fn load_baseline(&self, compilation: &Compilation) -> Result<Option<BaselineState>> {
+    let Some(baseline_path) = &self.options.baseline_path else {
+      return Ok(None);
+    };
+    if self.options.update_baseline {
+      return Ok(Some(BaselineState::default()));
+    }
+    let known = match compilation.input_filesystem.read(baseline_path).await {
+      Ok(content) => Some(
+        serde_json::from_slice::<CircularDependencyBaseline>(&content).map_err(|e| {
+          error!("Failed to parse circular dependency baseline {baseline_path}: {e}")
+        })?,
+      ),
+      // the baseline is created on the first build
+      Err(rspack_fs::Error::Io(e)) if e.kind() == std::io::ErrorKind::NotFound => None,
+      Err(e) => {
+        return Err(error!(
+          "Failed to read circular dependency baseline {baseline_path}: {e}"
+        ));
+      }
+    };
+    Ok(Some(BaselineState {
+      known,
+      found: Default::default(),
+    }))
+  }
+
+  async fn finish_baseline(
+    &self,
+    baseline: BaselineState,
+    is_complete: bool,
+    compilation: &Compilation,
+    diagnostics: &mut Vec<Diagnostic>,
+  ) -> Result<()> {
+    let Some(baseline_path) = &self.options.baseline_path else {
+      return Ok(());
+    };
+    let Some(known) = baseline.known else {
+      let content = serde_json::to_string_pretty(&baseline.found)
+        .map_err(|e| error!("Failed to serialize circular dependency baseline: {e}"))?;
+      if let Some(dir) = baseline_path.parent() {
+        compilation.output_filesystem.create_dir_all(dir).await?;
+      }
+      compilation
+        .output_filesystem
+        .write(baseline_path, format!("{content}\n").as_bytes())
+        .await?;
+      return Ok(());
+    };
+
+    // The cycles which are not found may still exist when the detection stopped early.
+    if !is_complete {
+      return Ok(());
+    }
+    let disappeared = known
+      .cycles
+      .difference(&baseline.found.cycles)
+      .collect::<Vec<_>>();
+    if !disappeared.is_empty() {
+      diagnostics.push(Diagnostic::warn(
+        "Circular Dependency".to_string(),
+        format!(
+          "{} circular dependencies in the baseline are no longer detected, they can be removed from it:\n{}",
+          disappeared.len(),
+          disappeared
+            .iter()
+            .map(|cycle| format!(" {}", cycle.join(" -> ")))
+            .join("\n")
+        ),
+      ));
+    }
+    Ok(())
+  }
 }
 
 #[plugin_hook(CompilationOptimizeModules for CircularDependencyRspackPlugin)]
@@ -365,9 +712,14 @@ async fn optimize_modules(
     on_start().await?;
   };
 
-  let module_map = build_module_map(compilation);
+  let mut baseline = self.load_baseline(compilation).await?;
+  let module_map = build_module_map(compilation, self.options.init_only);
+  let max_cycles = self.options.max_cycles.unwrap_or(usize::MAX);
+  let mut remaining_cycles = max_cycles;
+  let mut stopped_early = false;
   let mut detector = CycleDetector::new(&module_map);
-  for (entrypoint_name, chunk_group_key) in
+  let mut component_detector = ComponentDetector::new(&module_map, |_, _| false);
+  'entrypoints: for (entrypoint_name, chunk_group_key) in
     compilation.build_chunk_graph_artifact.entrypoints.clone()
   {
     let chunk_group = compilation
@@ -389,26 +741,122 @@ async fn optimize_modules(
         continue;
       };
 
-      for cycle in detector.find_cycles_from(module_id) {
-        if self.is_cycle_ignored(&module_map, &cycle, compilation) {
-          self
-            .handle_cycle_ignored(entrypoint_name.clone(), cycle, diagnostics)
-            .await?
-        } else {
-          self
-            .handle_cycle_detected(entrypoint_name.clone(), cycle, compilation, diagnostics)
-            .await?
+      match self.options.mode {
+        CircularDependencyReportMode::Cycles => {
+          let (cycles, is_truncated) = detector.find_cycles_from(module_id, remaining_cycles);
+          for cycle in cycles {
+            remaining_cycles -= 1;
+            if self.is_cycle_ignored(&module_map, &cycle, compilation) {
+              self
+                .handle_cycle_ignored(entrypoint_name.clone(), cycle, diagnostics)
+                .await?
+            } else {
+              self
+                .handle_cycle_detected(
+                  entrypoint_name.clone(),
+                  cycle,
+                  None,
+                  baseline.as_mut(),
+                  compilation,
+                  diagnostics,
+                )
+                .await?
+            }
+          }
+          if is_truncated {
+            stopped_early = true;
+            break 'entrypoints;
+          }
+        }
+        CircularDependencyReportMode::Components => {
+          for component in component_detector.find_components_from(module_id) {
+            // The ignored connections are excluded inside of the component, so
+            // it is split into the components which are detected.
+            let members = component.iter().collect::<HashSet<_>>();
+            let mut detected_detector =
+              ComponentDetector::new(&module_map, |module_id, target_id| {
+                !members.contains(target_id)
+                  || self.is_dependency_ignored(&module_map, module_id, target_id, compilation)
+              });
+            let mut detected_components = vec![];
+            for member_id in &component {
+              detected_components.extend(detected_detector.find_components_from(*member_id));
+            }
+            detected_components.sort();
+
+            // Every cycle of the component goes through an ignored connection.
+            if detected_components.is_empty() {
+              if remaining_cycles == 0 {
+                stopped_early = true;
+                break 'entrypoints;
+              }
+              remaining_cycles -= 1;
+              let cycle = component_detector.shortest_cycle(&component);
+              self
+                .handle_cycle_ignored(entrypoint_name.clone(), cycle, diagnostics)
+                .await?;
+              continue;
+            }
+
+            for detected_component in detected_components {
+              if remaining_cycles == 0 {
+                stopped_early = true;
+                break 'entrypoints;
+              }
+              remaining_cycles -= 1;
+              let cycle = detected_detector.shortest_cycle(&detected_component);
+              self
+                .handle_cycle_detected(
+                  entrypoint_name.clone(),
+                  cycle,
+                  Some(detected_component.len()),
+                  baseline.as_mut(),
+                  compilation,
+                  diagnostics,
+                )
+                .await?
+            }
+          }
         }
       }
     }
   }
 
+  if let Some(baseline) = baseline {
+    self
+      .finish_baseline(baseline, !stopped_early, compilation, diagnostics)
+      .await?;
+  }
+
+  if stopped_early {
+    diagnostics.push(Diagnostic::warn(
+      "Circular Dependency".to_string(),
+      format!(
+        "Circular dependency detection stopped early, as the maximum of {max_cycles} cycles was reached"
+      ),
+    ));
+  }
+
   if let Some(on_end) = &self.options.on_end {
     on_end().await?;
   }
   Ok(None)
 }
 
+#[plugin_hook(CompilationProcessAssets for CircularDependencyRspackPlugin)]
+async fn process_assets(&self, compilation: &mut Compilation) -> Result<()> {
+  // Rebuild when the baseline is edited. It is not watched when it is rewritten
+  // by the compilation itself.
+  if let Some(baseline_path) = &self.options.baseline_path
+    && !self.options.update_baseline
+  {
+    compilation
+      .file_dependencies
+      .insert(baseline_path.as_std_path().to_path_buf().into());
+  }
+  Ok(())
+}
+
 // implement apply method for the plugin
 impl Plugin for CircularDependencyRspackPlugin {
   fn apply(&self, ctx: &mut rspack_core::ApplyContext<'_>) -> Result<()> {
@@ -416,6 +864,10 @@ impl Plugin for CircularDependencyRspackPlugin {
       .compilation_hooks
       .optimize_modules
       .tap(optimize_modules::new(self));
+    ctx
+      .compilation_hooks
+      .process_assets
+      .tap(process_assets::new(self));
     Ok(())
   }
 }
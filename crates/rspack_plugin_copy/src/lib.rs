@@ -21,6 +21,7 @@ use rspack_error::{Diagnostic, Error, Result};
 use rspack_hash::{HashDigest, HashFunction, HashSalt, RspackHash, RspackHashDigest};
 use rspack_hook::{plugin, plugin_hook};
 use rspack_paths::{AssertUtf8, Utf8Path, Utf8PathBuf};
+use rustc_hash::{FxHashMap as HashMap, FxHashSet as HashSet};
 use sugar_path::SugarPath;
 
 #[derive(Debug)]
@@ -99,6 +100,9 @@ pub struct CopyPattern {
   pub copy_permissions: Option<bool>,
   #[debug(skip)]
   pub transform_fn: Option<TransformerFn>,
+  /// Whether to reuse the results of the unchanged files on rebuild, defaults to `true` without
+  /// `transform_fn`. The results of `transform_fn` are only reused on opt-in, as it may depend
+  /// on other inputs than the file content.
   pub cache: Option<bool>,
 }
 
@@ -121,10 +125,23 @@ pub struct RunPatternResult {
   pub pattern_index: usize,
 }
 
+/// The result of reading and transforming a source file in a previous compilation
+#[derive(Debug, Clone)]
+struct CopyCacheEntry {
+  mtime_ms: u64,
+  size: u64,
+  content_hash: RspackHashDigest,
+  source: BoxSource,
+}
+
+/// Keyed by the pattern index and the absolute path of the source file
+type CopyCache = Mutex<HashMap<(usize, Utf8PathBuf), CopyCacheEntry>>;
+
 #[plugin]
 #[derive(Debug)]
 pub struct CopyRspackPlugin {
   pub patterns: Vec<CopyPattern>,
+  cache: CopyCache,
 }
 
 static TEMPLATE_RE: LazyLock<Regex> =
@@ -132,7 +149,7 @@ static TEMPLATE_RE: LazyLock<Regex> =
 
 impl CopyRspackPlugin {
   pub fn new(patterns: Vec<CopyPattern>) -> Self {
-    Self::new_inner(patterns)
+    Self::new_inner(patterns, Default::default())
   }
 
   fn get_content_hash(
@@ -158,6 +175,7 @@ impl CopyRspackPlugin {
     compilation: &Compilation,
     logger: &CompilationLogger,
     pattern_index: usize,
+    cache: &CopyCache,
   ) -> Result<Option<RunPatternResult>> {
     // Exclude directories
     if entry.is_dir() {
@@ -264,43 +282,103 @@ impl CopyRspackPlugin {
       );
     }
 
-    // TODO cache
+    let cache_key = (pattern_index, absolute_filename.clone());
+    let metadata = if pattern.cache.unwrap_or(pattern.transform_fn.is_none()) {
+      compilation
+        .input_filesystem
+        .metadata(&absolute_filename)
+        .await
+        .ok()
+    } else {
+      None
+    };
+
+    // Unchanged mtime and size, reuse the previous result without reading the file
+    let cached = metadata.as_ref().and_then(|metadata| {
+      let cache = cache.lock().expect("failed to obtain lock of `cache`");
+      let entry = cache.get(&cache_key)?;
+      (entry.mtime_ms == metadata.mtime_ms && entry.size == metadata.size)
+        .then(|| entry.source.clone())
+    });
+
+    let source = if let Some(source) = cached {
+      logger.debug(format!("reused cached content for '{absolute_filename}'"));
+      source
+    } else {
+      logger.debug(format!("reading '{absolute_filename}'..."));
 
-    logger.debug(format!("reading '{absolute_filename}'..."));
-    // TODO inputFileSystem
+      let data = compilation.input_filesystem.read(&absolute_filename).await;
 
-    let data = compilation.input_filesystem.read(&absolute_filename).await;
+      let source_vec = match data {
+        Ok(data) => {
+          logger.debug(format!("read '{absolute_filename}'..."));
 
-    let source_vec = match data {
-      Ok(data) => {
-        logger.debug(format!("read '{absolute_filename}'..."));
+          data
+        }
+        Err(e) => {
+          let e: Error = e.into();
+          diagnostics
+            .lock()
+            .expect("failed to obtain lock of `diagnostics`")
+            .push(e.into());
+          return Ok(None);
+        }
+      };
 
-        data
+      let mut source = RawBufferSource::from(source_vec.clone()).boxed();
+      let content_hash = metadata.as_ref().map(|_| {
+        Self::get_content_hash(
+          &source,
+          &compilation.options.output.hash_function,
+          &compilation.options.output.hash_digest,
+          &compilation.options.output.hash_salt,
+        )
+      });
+
+      // Only the mtime changed, the transformed content is still valid
+      let cached = content_hash.as_ref().and_then(|content_hash| {
+        let cache = cache.lock().expect("failed to obtain lock of `cache`");
+        let entry = cache.get(&cache_key)?;
+        (entry.content_hash == *content_hash).then(|| entry.source.clone())
+      });
+
+      let mut cacheable = true;
+      if let Some(cached) = cached {
+        logger.debug(format!(
+          "reused cached content for '{absolute_filename}' with the same content hash"
+        ));
+        source = cached;
+      } else if let Some(transformer) = &pattern.transform_fn {
+        logger.debug(format!("transforming content for '{absolute_filename}'..."));
+        cacheable = handle_transform(
+          transformer,
+          source_vec,
+          absolute_filename.clone(),
+          &mut source,
+          diagnostics,
+        )
+        .await
       }
-      Err(e) => {
-        let e: Error = e.into();
-        diagnostics
+
+      if let (Some(metadata), Some(content_hash)) = (metadata, content_hash)
+        && cacheable
+      {
+        cache
           .lock()
-          .expect("failed to obtain lock of `diagnostics`")
-          .push(e.into());
-        return Ok(None);
+          .expect("failed to obtain lock of `cache`")
+          .insert(
+            cache_key,
+            CopyCacheEntry {
+              mtime_ms: metadata.mtime_ms,
+              size: metadata.size,
+              content_hash,
+              source: source.clone(),
+            },
+          );
       }
-    };
 
-    let mut source = RawBufferSource::from(source_vec.clone()).boxed();
-
-    if let Some(transformer) = &pattern.transform_fn {
-      logger.debug(format!("transforming content for '{absolute_filename}'..."));
-      // TODO: support cache in the future.
-      handle_transform(
-        transformer,
-        source_vec,
-        absolute_filename.clone(),
-        &mut source,
-        diagnostics,
-      )
-      .await
-    }
+      source
+    };
 
     let filename = if matches!(&to_type, ToType::Template) {
       logger.log(format!(
@@ -345,6 +423,7 @@ impl CopyRspackPlugin {
     }))
   }
 
+  #[allow(clippy::too_many_arguments)]
   async fn run_patter(
     compilation: &Compilation,
     pattern: &CopyPattern,
@@ -353,6 +432,7 @@ impl CopyRspackPlugin {
     context_dependencies: &DashSet<PathBuf>,
     diagnostics: Arc<Mutex<Vec<Diagnostic>>>,
     logger: &CompilationLogger,
+    cache: &CopyCache,
   ) -> Result<Option<Vec<Option<RunPatternResult>>>> {
     let orig_from = &pattern.from;
     let normalized_orig_from = Utf8PathBuf::from(orig_from);
@@ -483,12 +563,21 @@ impl CopyRspackPlugin {
           })
           .collect();
 
-        if need_add_context_to_dependency
-          && let Some(common_dir) = get_closest_common_parent_dir(
-            &entries.iter().map(|it| it.as_path()).collect::<Vec<_>>(),
-          )
-        {
-          context_dependencies.insert(common_dir.into_std_path_buf());
+        if need_add_context_to_dependency {
+          // Watch the static part of the glob when nothing matches yet, so that newly added files are copied
+          let context_dependency = if entries.is_empty() {
+            Some(get_glob_base_dir(&glob_query))
+          } else {
+            get_closest_common_parent_dir(
+              &entries.iter().map(|it| it.as_path()).collect::<Vec<_>>(),
+            )
+          };
+          if let Some(context_dependency) = context_dependency {
+            logger.debug(format!(
+              "added '{context_dependency}' as a context dependency"
+            ));
+            context_dependencies.insert(context_dependency.into_std_path_buf());
+          }
         }
 
         if entries.is_empty() {
@@ -522,6 +611,7 @@ impl CopyRspackPlugin {
             compilation,
             logger,
             index,
+            cache,
           )
           .await
         }))
@@ -597,6 +687,7 @@ async fn process_assets(&self, compilation: &mut Compilation) -> Result<()> {
         &context_dependencies,
         diagnostics.clone(),
         &logger,
+        &self.cache,
       )
     }))
     .await
@@ -628,6 +719,17 @@ async fn process_assets(&self, compilation: &mut Compilation) -> Result<()> {
       .deref_mut(),
   ));
 
+  // Drop cached results of files that are no longer copied
+  let copied_files = copied_result
+    .iter()
+    .map(|(_, result)| (result.pattern_index, result.absolute_filename.clone()))
+    .collect::<HashSet<_>>();
+  self
+    .cache
+    .lock()
+    .expect("failed to obtain lock of `cache`")
+    .retain(|key, _| copied_files.contains(key));
+
   copied_result.sort_unstable_by(|a, b| a.0.cmp(&b.0));
 
   // Keep track of source to destination file mappings for permission copying
@@ -743,6 +845,13 @@ fn get_closest_common_parent_dir(paths: &[&Utf8Path]) -> Option<Utf8PathBuf> {
   Some(parent_dir)
 }
 
+fn get_glob_base_dir(glob_query: &str) -> Utf8PathBuf {
+  Utf8Path::new(glob_query)
+    .components()
+    .take_while(|component| !component.as_str().contains(['*', '?', '[', '{']))
+    .collect()
+}
+
 fn set_info(target: &mut AssetInfo, info: Info) {
   if let Some(minimized) = info.minimized {
     target.minimized.replace(minimized);
@@ -787,10 +896,11 @@ async fn handle_transform(
   absolute_filename: Utf8PathBuf,
   source: &mut BoxSource,
   diagnostics: Arc<Mutex<Vec<Diagnostic>>>,
-) {
+) -> bool {
   match transformer(source_vec, absolute_filename.as_str()).await {
     Ok(code) => {
       *source = code;
+      true
     }
     Err(e) => {
       diagnostics
@@ -800,6 +910,7 @@ async fn handle_transform(
           "Run copy transform fn error".into(),
           e.to_string(),
         ));
+      false
     }
   }
 }
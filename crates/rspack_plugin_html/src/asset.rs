@@ -1,6 +1,6 @@
 use std::{
   borrow::Cow,
-  collections::HashMap,
+  collections::{BTreeSet, HashMap, HashSet, VecDeque},
   env,
   hash::Hasher,
   path::{Path, PathBuf},
@@ -11,17 +11,24 @@ use cow_utils::CowUtils;
 use itertools::Itertools;
 use rayon::prelude::*;
 use rspack_core::{
-  AssetInfo, Compilation, CompilationAsset, Filename, PathData,
+  AssetInfo, ChunkGroup, ChunkGroupOrderKey, ChunkUkey, Compilation, CompilationAsset,
+  CrossOriginLoading, Filename, PathData,
   rspack_sources::{RawBufferSource, RawStringSource, SourceExt},
 };
 use rspack_error::{AnyhowResultToRspackResultExt, Result};
 use rspack_hash::RspackHash;
 use rspack_paths::Utf8PathBuf;
+use rspack_util::{
+  asset_condition::{AssetConditionsObject, match_object},
+  fx_hash::FxHashSet,
+};
 use serde::{Deserialize, Serialize};
 use sugar_path::SugarPath;
 
 use crate::{
-  config::{HtmlChunkSortMode, HtmlInject, HtmlRspackPluginOptions, HtmlScriptLoading},
+  config::{
+    HtmlChunkSortFn, HtmlChunkSortMode, HtmlInject, HtmlRspackPluginOptions, HtmlScriptLoading,
+  },
   tag::HtmlPluginTag,
 };
 
@@ -44,46 +51,37 @@ impl HtmlPluginAssets {
     public_path: &str,
     output_path: &Utf8PathBuf,
     html_file_name: &Filename,
-  ) -> Result<(HtmlPluginAssets, HashMap<String, &'a CompilationAsset>)> {
+  ) -> Result<(
+    HtmlPluginAssets,
+    HashMap<String, (String, &'a CompilationAsset)>,
+  )> {
     let mut assets: HtmlPluginAssets = HtmlPluginAssets::default();
     let mut asset_map = HashMap::new();
     assets.public_path = public_path.to_string();
 
-    let sorted_entry_names: Vec<&String> =
-      if matches!(config.chunks_sort_mode, HtmlChunkSortMode::Manual)
-        && let Some(chunks) = &config.chunks
-      {
-        chunks
-          .iter()
-          .filter(|&name| {
-            compilation
-              .build_chunk_graph_artifact
-              .entrypoints
-              .contains_key(name)
-          })
-          .collect()
-      } else {
-        compilation
-          .build_chunk_graph_artifact
-          .entrypoints
-          .keys()
-          .filter(|&entry_name| {
-            let mut included = true;
-            if let Some(included_chunks) = &config.chunks {
-              included = included_chunks.iter().any(|c| c.eq(entry_name));
-            }
-            if let Some(exclude_chunks) = &config.exclude_chunks {
-              included = included && !exclude_chunks.iter().any(|c| c.eq(entry_name));
-            }
-            included
-          })
-          .collect()
-      };
-
-    let included_assets = sorted_entry_names
+    let mut sorted_entry_names = get_sorted_entry_names(config, compilation);
+    if let HtmlChunkSortMode::Function(sort_fn) = &config.chunks_sort_mode {
+      sorted_entry_names = sort_entry_names_by_fn(sort_fn, sorted_entry_names).await?;
+    }
+    let entrypoints = sorted_entry_names
       .iter()
       .map(|entry_name| compilation.entrypoint_by_name(entry_name))
-      .flat_map(|entry| entry.get_files(&compilation.build_chunk_graph_artifact.chunk_by_ukey))
+      .collect::<Vec<_>>();
+    let chunk_by_ukey = &compilation.build_chunk_graph_artifact.chunk_by_ukey;
+    let entry_files = if matches!(config.chunks_sort_mode, HtmlChunkSortMode::Dependency) {
+      sort_chunks_by_dependency(&entrypoints)
+        .into_iter()
+        .flat_map(|chunk| chunk_by_ukey.expect_get(&chunk).files().iter().cloned())
+        .collect::<Vec<_>>()
+    } else {
+      entrypoints
+        .iter()
+        .flat_map(|entry| entry.get_files(chunk_by_ukey))
+        .collect::<Vec<_>>()
+    };
+
+    let included_assets = entry_files
+      .into_iter()
       .filter_map(|asset_name| {
         let asset = compilation.assets().get(&asset_name).expect("TODO:");
         if asset.info.hot_module_replacement.unwrap_or(false)
@@ -100,22 +98,22 @@ impl HtmlPluginAssets {
       if let Some(extension) =
         Path::new(asset_name.split("?").next().unwrap_or_default()).extension()
       {
-        let mut asset_uri = format!("{}{}", assets.public_path, url_encode_path(&asset_name));
-        if config.hash.unwrap_or_default()
-          && let Some(hash) = compilation.get_hash()
-        {
-          asset_uri = append_hash(&asset_uri, hash);
-        }
-        let final_path = generate_posix_path(&asset_uri);
+        let final_path = create_asset_uri(config, compilation, &assets.public_path, &asset_name);
         if extension.eq_ignore_ascii_case("css") {
-          if asset_map.insert(final_path.to_string(), asset).is_none() {
-            assets.css.push(final_path.to_string());
+          if asset_map
+            .insert(final_path.clone(), (asset_name.clone(), asset))
+            .is_none()
+          {
+            assets.css.push(final_path.clone());
           }
         } else if extension.eq_ignore_ascii_case("js") || extension.eq_ignore_ascii_case("mjs") {
           // keep the `if` to make the code more readable
           #[allow(clippy::collapsible_if)]
-          if asset_map.insert(final_path.to_string(), asset).is_none() {
-            assets.js.push(final_path.to_string());
+          if asset_map
+            .insert(final_path.clone(), (asset_name.clone(), asset))
+            .is_none()
+          {
+            assets.js.push(final_path.clone());
           }
         }
       }
@@ -253,6 +251,136 @@ impl HtmlPluginAssetTags {
   }
 }
 
+fn get_sorted_entry_names<'a>(
+  config: &'a HtmlRspackPluginOptions,
+  compilation: &'a Compilation,
+) -> Vec<&'a String> {
+  if matches!(config.chunks_sort_mode, HtmlChunkSortMode::Manual)
+    && let Some(chunks) = &config.chunks
+  {
+    chunks
+      .iter()
+      .filter(|&name| {
+        compilation
+          .build_chunk_graph_artifact
+          .entrypoints
+          .contains_key(name)
+      })
+      .collect()
+  } else {
+    compilation
+      .build_chunk_graph_artifact
+      .entrypoints
+      .keys()
+      .filter(|&entry_name| {
+        let mut included = true;
+        if let Some(included_chunks) = &config.chunks {
+          included = included_chunks.iter().any(|c| c.eq(entry_name));
+        }
+        if let Some(exclude_chunks) = &config.exclude_chunks {
+          included = included && !exclude_chunks.iter().any(|c| c.eq(entry_name));
+        }
+        included
+      })
+      .collect()
+  }
+}
+
+/// Let the user callback reorder the included entry names, unknown names returned by the
+/// callback are ignored.
+async fn sort_entry_names_by_fn<'a>(
+  sort_fn: &HtmlChunkSortFn,
+  entry_names: Vec<&'a String>,
+) -> Result<Vec<&'a String>> {
+  let sorted = (sort_fn.inner)(entry_names.iter().map(|name| (*name).clone()).collect()).await?;
+  Ok(
+    sorted
+      .iter()
+      .filter_map(|name| entry_names.iter().find(|entry_name| **entry_name == name))
+      .copied()
+      .unique()
+      .collect(),
+  )
+}
+
+/// Order the chunks of the entrypoints topologically, so that the chunks of a chunk group are
+/// placed after its own preceding chunks and after all chunks of its parent chunk groups
+/// (e.g. `dependOn`). Ties and cycles fall back to the order of first appearance.
+fn sort_chunks_by_dependency(entrypoints: &[&ChunkGroup]) -> Vec<ChunkUkey> {
+  let chunks = entrypoints
+    .iter()
+    .flat_map(|entrypoint| entrypoint.chunks.iter().copied())
+    .unique()
+    .collect::<Vec<_>>();
+  let index_by_chunk = chunks
+    .iter()
+    .enumerate()
+    .map(|(index, chunk)| (*chunk, index))
+    .collect::<HashMap<_, _>>();
+
+  let mut edges = HashSet::new();
+  for entrypoint in entrypoints {
+    for (from, to) in entrypoint.chunks.iter().tuple_windows() {
+      edges.insert((index_by_chunk[from], index_by_chunk[to]));
+    }
+    for parent in entrypoints
+      .iter()
+      .filter(|parent| entrypoint.parents.contains(&parent.ukey))
+    {
+      for from in &parent.chunks {
+        for to in &entrypoint.chunks {
+          edges.insert((index_by_chunk[from], index_by_chunk[to]));
+        }
+      }
+    }
+  }
+
+  let mut in_degrees = vec![0usize; chunks.len()];
+  let mut successors = vec![vec![]; chunks.len()];
+  for (from, to) in edges {
+    if from != to {
+      in_degrees[to] += 1;
+      successors[from].push(to);
+    }
+  }
+
+  let mut ready = (0..chunks.len())
+    .filter(|index| in_degrees[*index] == 0)
+    .collect::<BTreeSet<_>>();
+  let mut pending = (0..chunks.len()).collect::<BTreeSet<_>>();
+  let mut sorted = Vec::with_capacity(chunks.len());
+  while let Some(&first) = pending.first() {
+    // a cycle is broken at the chunk that appears first
+    let index = ready.pop_first().unwrap_or(first);
+    if !pending.remove(&index) {
+      continue;
+    }
+    sorted.push(chunks[index]);
+    for successor in &successors[index] {
+      in_degrees[*successor] = in_degrees[*successor].saturating_sub(1);
+      if in_degrees[*successor] == 0 && pending.contains(successor) {
+        ready.insert(*successor);
+      }
+    }
+  }
+  sorted
+}
+
+fn create_asset_uri(
+  config: &HtmlRspackPluginOptions,
+  compilation: &Compilation,
+  public_path: &str,
+  asset_name: &str,
+) -> String {
+  let mut asset_uri = format!("{}{}", public_path, url_encode_path(asset_name));
+  if config.hash.unwrap_or_default()
+    && let Some(hash) = compilation.get_hash()
+  {
+    asset_uri = append_hash(&asset_uri, hash);
+  }
+  generate_posix_path(&asset_uri).into_owned()
+}
+
 pub fn append_hash(url: &str, hash: &str) -> String {
   format!(
     "{}{}{}",
@@ -298,6 +426,242 @@ fn url_encode_path(file_path: &str) -> String {
   )
 }
 
+/// Replace the script and style tags of the assets matched by `inline` with inline tags,
+/// returns the names of the inlined assets.
+pub fn inline_asset_tags(
+  config: &HtmlRspackPluginOptions,
+  compilation: &Compilation,
+  asset_map: &HashMap<String, (String, &CompilationAsset)>,
+  asset_tags: &mut HtmlPluginAssetTags,
+) -> Vec<String> {
+  let Some(inline) = &config.inline else {
+    return vec![];
+  };
+  let chunk_by_ukey = &compilation.build_chunk_graph_artifact.chunk_by_ukey;
+  let chunk_group_by_ukey = &compilation.build_chunk_graph_artifact.chunk_group_by_ukey;
+
+  let runtime_files = if inline.runtime_chunk {
+    compilation
+      .build_chunk_graph_artifact
+      .entrypoints
+      .values()
+      .map(|entrypoint| {
+        chunk_group_by_ukey
+          .expect_get(entrypoint)
+          .get_runtime_chunk(chunk_group_by_ukey)
+      })
+      .flat_map(|chunk| chunk_by_ukey.expect_get(&chunk).files())
+      .collect::<HashSet<_>>()
+  } else {
+    HashSet::new()
+  };
+
+  let mut inlined_assets = vec![];
+  let mut inline_tag = |tag: &mut HtmlPluginTag, is_style: bool| {
+    let Some((asset_name, asset)) = tag.asset.as_ref().and_then(|uri| asset_map.get(uri)) else {
+      return;
+    };
+    let Some(source) = asset.get_source() else {
+      return;
+    };
+    let should_inline = runtime_files.contains(asset_name)
+      || (is_style
+        && inline
+          .css_size_limit
+          .is_some_and(|limit| source.size() as f64 <= limit))
+      || inline
+        .test
+        .as_ref()
+        .is_some_and(|test| test.try_match(asset_name));
+    if !should_inline {
+      return;
+    }
+
+    let content = source.source().into_string_lossy();
+    // the source map is removed together with the asset
+    let content = strip_source_mapping_url(&content);
+    *tag = if is_style {
+      HtmlPluginTag::create_inline_style(asset_name, content)
+    } else {
+      HtmlPluginTag::create_inline_script(asset_name, content, &config.script_loading)
+    };
+    inlined_assets.push(asset_name.clone());
+  };
+
+  for tag in &mut asset_tags.scripts {
+    inline_tag(tag, false);
+  }
+  for tag in &mut asset_tags.styles {
+    inline_tag(tag, true);
+  }
+  inlined_assets
+}
+
+fn strip_source_mapping_url(content: &str) -> &str {
+  let trimmed = content.trim_end();
+  let line_start = trimmed.rfind('\n').map_or(0, |index| index + 1);
+  let last_line = &trimmed[line_start..];
+  if last_line.starts_with("//# sourceMappingURL=")
+    || last_line.starts_with("/*# sourceMappingURL=")
+  {
+    &trimmed[..line_start]
+  } else {
+    content
+  }
+}
+
+/// The assets still injected by url in the script and style tags, which are kept even if they are
+/// inlined by other html files.
+pub fn get_tag_assets(
+  asset_map: &HashMap<String, (String, &CompilationAsset)>,
+  asset_tags: &HtmlPluginAssetTags,
+) -> Vec<String> {
+  asset_tags
+    .scripts
+    .iter()
+    .chain(asset_tags.styles.iter())
+    .filter(|tag| tag.inner_html.is_none())
+    .filter_map(|tag| tag.asset.as_ref().and_then(|uri| asset_map.get(uri)))
+    .map(|(asset_name, _)| asset_name.clone())
+    .collect()
+}
+
+/// Whether the inlined asset is still loaded by url, either injected by the tags of the html
+/// files, loaded on demand as a file of an async chunk group, or emitted as an auxiliary file.
+pub fn is_inlined_asset_referenced(
+  compilation: &Compilation,
+  asset_name: &str,
+  tag_assets: &FxHashSet<String>,
+) -> bool {
+  if tag_assets.contains(asset_name) {
+    return true;
+  }
+  let chunk_group_by_ukey = &compilation.build_chunk_graph_artifact.chunk_group_by_ukey;
+  compilation
+    .build_chunk_graph_artifact
+    .chunk_by_ukey
+    .values()
+    .any(|chunk| {
+      chunk.auxiliary_files().contains(asset_name)
+        || (chunk.files().contains(asset_name)
+          && chunk
+            .groups()
+            .iter()
+            .any(|group| !chunk_group_by_ukey.expect_get(group).is_initial()))
+    })
+}
+
+/// Create `preload`, `modulepreload` and `prefetch` links for the async chunk groups of the
+/// included entries, files already injected by the entries are skipped.
+pub fn create_resource_hint_tags(
+  config: &HtmlRspackPluginOptions,
+  compilation: &Compilation,
+  public_path: &str,
+) -> Vec<HtmlPluginTag> {
+  let Some(resource_hints) = &config.resource_hints else {
+    return vec![];
+  };
+  let chunk_by_ukey = &compilation.build_chunk_graph_artifact.chunk_by_ukey;
+  let chunk_group_by_ukey = &compilation.build_chunk_graph_artifact.chunk_group_by_ukey;
+
+  let entrypoints = get_sorted_entry_names(config, compilation)
+    .into_iter()
+    .map(|entry_name| compilation.entrypoint_by_name(entry_name))
+    .collect::<Vec<_>>();
+  let mut visited_files = entrypoints
+    .iter()
+    .flat_map(|entrypoint| entrypoint.get_files(chunk_by_ukey))
+    .collect::<HashSet<_>>();
+  // (file, is_preload)
+  let mut hint_files = vec![];
+
+  if resource_hints.preload {
+    for entrypoint in &entrypoints {
+      let preload_groups = entrypoint
+        .get_children_by_orders(compilation)
+        .remove(&ChunkGroupOrderKey::Preload)
+        .unwrap_or_default();
+      for chunk_group in preload_groups {
+        for file in chunk_group_by_ukey
+          .expect_get(&chunk_group)
+          .get_files(chunk_by_ukey)
+        {
+          if visited_files.insert(file.clone()) {
+            hint_files.push((file, true));
+          }
+        }
+      }
+    }
+  }
+
+  if resource_hints.prefetch {
+    let mut visited_groups = HashSet::new();
+    let mut queue = entrypoints
+      .iter()
+      .flat_map(|entrypoint| entrypoint.children_iterable().copied())
+      .collect::<VecDeque<_>>();
+    while let Some(chunk_group) = queue.pop_front() {
+      if !visited_groups.insert(chunk_group) {
+        continue;
+      }
+      let chunk_group = chunk_group_by_ukey.expect_get(&chunk_group);
+      for file in chunk_group.get_files(chunk_by_ukey) {
+        if visited_files.insert(file.clone()) {
+          hint_files.push((file, false));
+        }
+      }
+      queue.extend(chunk_group.children_iterable().copied());
+    }
+  }
+
+  let conditions = AssetConditionsObject {
+    test: None,
+    include: resource_hints.include.as_ref(),
+    exclude: resource_hints.exclude.as_ref(),
+  };
+
+  hint_files
+    .into_iter()
+    .filter(|(file, _)| {
+      compilation.assets().get(file).is_some_and(|asset| {
+        !asset.info.hot_module_replacement.unwrap_or(false)
+          && !asset.info.development.unwrap_or(false)
+      }) && match_object(&conditions, file)
+    })
+    .map(|(file, is_preload)| {
+      let href = create_asset_uri(config, compilation, public_path, &file);
+      let as_type = get_resource_hint_as_type(&file);
+      let crossorigin = match &compilation.options.output.cross_origin_loading {
+        CrossOriginLoading::Enable(value) => Some(value.as_str()),
+        // fonts are always fetched in CORS mode
+        CrossOriginLoading::Disable => (as_type == "font").then_some("anonymous"),
+      };
+      if !is_preload {
+        HtmlPluginTag::create_resource_hint(&href, "prefetch", Some(as_type), crossorigin)
+      } else if as_type == "script" && matches!(config.script_loading, HtmlScriptLoading::Module) {
+        HtmlPluginTag::create_resource_hint(&href, "modulepreload", None, crossorigin)
+      } else {
+        HtmlPluginTag::create_resource_hint(&href, "preload", Some(as_type), crossorigin)
+      }
+    })
+    .collect()
+}
+
+fn get_resource_hint_as_type(file: &str) -> &'static str {
+  let extension = Path::new(file.split('?').next().unwrap_or_default())
+    .extension()
+    .and_then(|extension| extension.to_str())
+    .unwrap_or_default()
+    .cow_to_ascii_lowercase();
+  match extension.as_ref() {
+    "js" | "mjs" | "cjs" => "script",
+    "css" => "style",
+    "woff" | "woff2" | "ttf" | "otf" | "eot" => "font",
+    "png" | "jpg" | "jpeg" | "gif" | "svg" | "webp" | "avif" | "ico" => "image",
+    _ => "fetch",
+  }
+}
+
 pub async fn create_favicon_asset(
   favicon: &str,
   config: &HtmlRspackPluginOptions,
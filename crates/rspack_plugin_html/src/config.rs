@@ -3,7 +3,7 @@ use std::{fmt, path::PathBuf, str::FromStr};
 use futures::future::BoxFuture;
 use rspack_core::{Compilation, PublicPath};
 use rspack_error::Result;
-use rspack_util::fx_hash::FxHashMap;
+use rspack_util::{asset_condition::AssetConditions, fx_hash::FxHashMap};
 use serde::Serialize;
 use sugar_path::SugarPath;
 
@@ -113,13 +113,42 @@ impl std::fmt::Debug for TemplateRenderFn {
   }
 }
 
-#[derive(Serialize, Debug, Clone, Copy, Default)]
-#[serde(rename_all = "snake_case")]
+type HtmlChunkSortTsfn =
+  Box<dyn Fn(Vec<String>) -> BoxFuture<'static, Result<Vec<String>>> + Sync + Send>;
+
+pub struct HtmlChunkSortFn {
+  pub inner: HtmlChunkSortTsfn,
+}
+
+impl std::fmt::Debug for HtmlChunkSortFn {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    f.debug_struct("HtmlChunkSortFn").finish()
+  }
+}
+
+#[derive(Debug, Default)]
 pub enum HtmlChunkSortMode {
   #[default]
   Auto,
   Manual,
-  // TODO: support function
+  /// chunks of a chunk group are placed after the chunks of its parent chunk groups
+  Dependency,
+  /// receives the included entry names and returns them in injection order
+  Function(HtmlChunkSortFn),
+}
+
+impl Serialize for HtmlChunkSortMode {
+  fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+  where
+    S: serde::Serializer,
+  {
+    serializer.serialize_str(match self {
+      HtmlChunkSortMode::Auto => "auto",
+      HtmlChunkSortMode::Manual => "manual",
+      HtmlChunkSortMode::Dependency => "dependency",
+      HtmlChunkSortMode::Function(_) => "function",
+    })
+  }
 }
 
 impl FromStr for HtmlChunkSortMode {
@@ -130,14 +159,55 @@ impl FromStr for HtmlChunkSortMode {
       Ok(HtmlChunkSortMode::Auto)
     } else if s.eq("manual") {
       Ok(HtmlChunkSortMode::Manual)
+    } else if s.eq("dependency") {
+      Ok(HtmlChunkSortMode::Dependency)
     } else {
       Err(anyhow::Error::msg(
-        "chunksSortMode in html config only support 'auto' or 'manual'",
+        "chunksSortMode in html config only support 'auto', 'manual', 'dependency' or a function",
       ))
     }
   }
 }
 
+#[derive(Serialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct HtmlResourceHintsOptions {
+  /// `<link rel="preload">` for the async chunks of the entries marked with `webpackPreload`,
+  /// scripts use `<link rel="modulepreload">` when `script_loading` is `module`
+  pub preload: bool,
+  /// `<link rel="prefetch">` for all async chunk groups reachable from the entries
+  pub prefetch: bool,
+  /// only generate hints for assets matching these conditions
+  #[serde(skip)]
+  pub include: Option<AssetConditions>,
+  /// skip hints for assets matching these conditions
+  #[serde(skip)]
+  pub exclude: Option<AssetConditions>,
+}
+
+impl Default for HtmlResourceHintsOptions {
+  fn default() -> Self {
+    Self {
+      preload: true,
+      prefetch: false,
+      include: None,
+      exclude: None,
+    }
+  }
+}
+
+#[derive(Serialize, Debug, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct HtmlInlineOptions {
+  /// inline the runtime chunks of the included entries
+  pub runtime_chunk: bool,
+  /// inline css files not larger than this size in bytes
+  pub css_size_limit: Option<f64>,
+  /// inline the js and css files matching these conditions
+  #[serde(skip)]
+  pub test: Option<AssetConditions>,
+}
+
 #[derive(Serialize, Debug)]
 #[serde(rename_all = "camelCase", deny_unknown_fields)]
 pub struct HtmlRspackPluginOptions {
@@ -164,6 +234,11 @@ pub struct HtmlRspackPluginOptions {
   pub chunks: Option<Vec<String>>,
   pub exclude_chunks: Option<Vec<String>>,
   pub chunks_sort_mode: HtmlChunkSortMode,
+  /// `preload`, `modulepreload` and `prefetch` links generated from the chunk graph
+  pub resource_hints: Option<HtmlResourceHintsOptions>,
+  /// inline the matched assets into the html, they are removed from the output
+  /// when no other asset references them
+  pub inline: Option<HtmlInlineOptions>,
 
   #[serde(default)]
   pub minify: Option<bool>,
@@ -206,6 +281,8 @@ impl Default for HtmlRspackPluginOptions {
       chunks: None,
       exclude_chunks: None,
       chunks_sort_mode: default_chunks_sort_mode(),
+      resource_hints: None,
+      inline: None,
       minify: None,
       title: None,
       favicon: None,
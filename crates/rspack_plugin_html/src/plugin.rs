@@ -1,7 +1,7 @@
 use std::{
   borrow::Cow,
   path::{Path, PathBuf},
-  sync::{Arc, LazyLock},
+  sync::{Arc, LazyLock, Mutex},
 };
 
 use atomic_refcell::AtomicRefCell;
@@ -11,13 +11,16 @@ use rspack_error::{Diagnostic, Result};
 use rspack_hook::{plugin, plugin_hook};
 #[cfg(allocative)]
 use rspack_util::allocative;
-use rspack_util::fx_hash::FxDashMap;
+use rspack_util::fx_hash::{FxDashMap, FxHashSet};
 use sugar_path::SugarPath;
 
 use crate::{
   AfterEmitData, AfterTemplateExecutionData, AlterAssetTagGroupsData, AlterAssetTagsData,
   BeforeAssetTagGenerationData, BeforeEmitData, HtmlPluginHooks,
-  asset::{HtmlPluginAssetTags, HtmlPluginAssets, create_favicon_asset, create_html_asset},
+  asset::{
+    HtmlPluginAssetTags, HtmlPluginAssets, create_favicon_asset, create_html_asset,
+    create_resource_hint_tags, get_tag_assets, inline_asset_tags, is_inlined_asset_referenced,
+  },
   config::{HtmlInject, HtmlRspackPluginOptions},
   injector::AssetInjector,
   parser::HtmlCompiler,
@@ -33,15 +36,21 @@ type ArcHtmlPluginHooks = Arc<AtomicRefCell<HtmlPluginHooks>>;
 static COMPILATION_HOOKS_MAP: LazyLock<FxDashMap<CompilationId, ArcHtmlPluginHooks>> =
   LazyLock::new(Default::default);
 
+/// The assets injected by url into the html files of all the plugin instances of a compilation.
+#[cfg_attr(allocative, allocative::root)]
+static COMPILATION_TAG_ASSETS_MAP: LazyLock<FxDashMap<CompilationId, FxHashSet<String>>> =
+  LazyLock::new(Default::default);
+
 #[plugin]
 #[derive(Debug)]
 pub struct HtmlRspackPlugin {
   config: HtmlRspackPluginOptions,
+  inlined_assets: Mutex<FxHashSet<String>>,
 }
 
 impl HtmlRspackPlugin {
   pub fn new(config: HtmlRspackPluginOptions) -> Self {
-    Self::new_inner(config)
+    Self::new_inner(config, Default::default())
   }
 
   pub fn get_compilation_hooks(id: CompilationId) -> Arc<AtomicRefCell<HtmlPluginHooks>> {
@@ -65,7 +74,7 @@ async fn generate_html(
   config: &HtmlRspackPluginOptions,
   compilation: &mut Compilation,
   hooks: ArcHtmlPluginHooks,
-) -> Result<(String, String, Vec<PathBuf>)> {
+) -> Result<(String, String, Vec<PathBuf>, Vec<String>, Vec<String>)> {
   let public_path = config.get_public_path(compilation, filename).await;
 
   let mut template = HtmlTemplate::new(config, compilation).await?;
@@ -96,8 +105,16 @@ async fn generate_html(
     })
     .await?;
 
-  let asset_tags: HtmlPluginAssetTags =
+  let mut asset_tags: HtmlPluginAssetTags =
     HtmlPluginAssetTags::from_assets(config, &before_generation_data.assets);
+  // inline before the tag hooks, so `SubresourceIntegrityPlugin` never adds integrity to the
+  // inlined tags, the sources are read after its `processAssets` filled in the integrity of the
+  // async chunks
+  let inlined_assets = inline_asset_tags(config, compilation, &assets_info.1, &mut asset_tags);
+  let tag_assets = get_tag_assets(&assets_info.1, &asset_tags);
+  asset_tags
+    .meta
+    .extend(create_resource_hint_tags(config, compilation, &public_path));
 
   let alter_asset_tags_data = hooks
     .borrow()
@@ -178,6 +195,8 @@ async fn generate_html(
     template_file_name.to_string(),
     html.into_owned(),
     template.file_dependencies,
+    inlined_assets,
+    tag_assets,
   ))
 }
 
@@ -222,6 +241,15 @@ async fn process_assets(&self, compilation: &mut Compilation) -> Result<()> {
         compilation
           .file_dependencies
           .extend(content.2.into_iter().map(Into::into));
+        self
+          .inlined_assets
+          .lock()
+          .expect("should lock inlined assets")
+          .extend(content.3);
+        COMPILATION_TAG_ASSETS_MAP
+          .entry(compilation.id())
+          .or_default()
+          .extend(content.4);
         (content.0, content.1)
       }
       Err(err) => {
@@ -277,6 +305,33 @@ async fn process_assets(&self, compilation: &mut Compilation) -> Result<()> {
   Ok(())
 }
 
+// runs after the html files of all the plugin instances are generated, so that the assets still
+// injected by url into other html files are kept, and before `RealContentHashPlugin` hashes them
+#[plugin_hook(CompilationProcessAssets for HtmlRspackPlugin, stage = Compilation::PROCESS_ASSETS_STAGE_OPTIMIZE_INLINE + 1)]
+async fn remove_inlined_assets(&self, compilation: &mut Compilation) -> Result<()> {
+  let inlined_assets = std::mem::take(
+    &mut *self
+      .inlined_assets
+      .lock()
+      .expect("should lock inlined assets"),
+  );
+
+  let removed_assets = {
+    let tag_assets = COMPILATION_TAG_ASSETS_MAP
+      .entry(compilation.id())
+      .or_default();
+    inlined_assets
+      .into_iter()
+      .filter(|asset_name| !is_inlined_asset_referenced(compilation, asset_name, &tag_assets))
+      .collect::<Vec<_>>()
+  };
+  for asset_name in removed_assets {
+    compilation.delete_asset(&asset_name);
+  }
+
+  Ok(())
+}
+
 impl Plugin for HtmlRspackPlugin {
   fn name(&self) -> &'static str {
     "rspack.HtmlRspackPlugin"
@@ -287,11 +342,16 @@ impl Plugin for HtmlRspackPlugin {
       .compilation_hooks
       .process_assets
       .tap(process_assets::new(self));
+    ctx
+      .compilation_hooks
+      .process_assets
+      .tap(remove_inlined_assets::new(self));
     Ok(())
   }
 
   fn clear_cache(&self, id: CompilationId) {
     COMPILATION_HOOKS_MAP.remove(&id);
+    COMPILATION_TAG_ASSETS_MAP.remove(&id);
   }
 }
 
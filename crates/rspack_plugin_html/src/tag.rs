@@ -1,5 +1,6 @@
 use core::fmt;
 
+use cow_utils::CowUtils;
 use itertools::Itertools;
 use rspack_util::fx_hash::FxHashMap;
 use serde::{
@@ -153,6 +154,82 @@ impl HtmlPluginTag {
     }
   }
 
+  pub fn create_inline_script(
+    asset: &str,
+    content: &str,
+    script_loading: &HtmlScriptLoading,
+  ) -> HtmlPluginTag {
+    // inline scripts can't be deferred, only the script type is kept
+    let script_type = match script_loading {
+      HtmlScriptLoading::Module => Some("module"),
+      HtmlScriptLoading::SystemjsModule => Some("systemjs-module"),
+      _ => None,
+    };
+
+    HtmlPluginTag {
+      tag_name: "script".to_string(),
+      attributes: script_type
+        .map(|script_type| HtmlPluginAttribute {
+          attr_name: "type".to_string(),
+          attr_value: Some(script_type.to_string()),
+        })
+        .into_iter()
+        .collect(),
+      inner_html: Some(content.cow_replace("</script", "<\\/script").into_owned()),
+      asset: Some(asset.to_string()),
+      ..Default::default()
+    }
+  }
+
+  pub fn create_inline_style(asset: &str, content: &str) -> HtmlPluginTag {
+    HtmlPluginTag {
+      tag_name: "style".to_string(),
+      inner_html: Some(content.cow_replace("</style", "<\\/style").into_owned()),
+      asset: Some(asset.to_string()),
+      ..Default::default()
+    }
+  }
+
+  pub fn create_resource_hint(
+    href: &str,
+    rel: &str,
+    as_type: Option<&str>,
+    crossorigin: Option<&str>,
+  ) -> HtmlPluginTag {
+    let mut attributes = vec![
+      HtmlPluginAttribute {
+        attr_name: "rel".to_string(),
+        attr_value: Some(rel.to_string()),
+      },
+      HtmlPluginAttribute {
+        attr_name: "href".to_string(),
+        attr_value: Some(href.to_string()),
+      },
+    ];
+
+    if let Some(as_type) = as_type {
+      attributes.push(HtmlPluginAttribute {
+        attr_name: "as".to_string(),
+        attr_value: Some(as_type.to_string()),
+      });
+    }
+
+    if let Some(crossorigin) = crossorigin {
+      attributes.push(HtmlPluginAttribute {
+        attr_name: "crossorigin".to_string(),
+        attr_value: Some(crossorigin.to_string()),
+      });
+    }
+
+    HtmlPluginTag {
+      tag_name: "link".to_string(),
+      attributes,
+      void_tag: true,
+      asset: Some(href.to_string()),
+      ..Default::default()
+    }
+  }
+
   pub fn create_base(base: &HtmlRspackPluginBaseOptions) -> Option<HtmlPluginTag> {
     let mut attributes = vec![];
 
@@ -82,6 +82,7 @@ async fn optimize_chunks(&self, compilation: &mut Compilation) -> Result<Option<
   let mut new_chunk_group_by_ukey =
     std::mem::take(&mut compilation.build_chunk_graph_artifact.chunk_group_by_ukey);
   let mut new_chunk_graph = std::mem::take(&mut compilation.build_chunk_graph_artifact.chunk_graph);
+  let mut split_chunks_artifact = std::mem::take(&mut compilation.split_chunks_artifact);
 
   //    let chunk_graph = &compilation.build_chunk_graph_artifact.chunk_graph.clone();
   let module_graph = compilation.get_module_graph();
@@ -212,6 +213,7 @@ async fn optimize_chunks(&self, compilation: &mut Compilation) -> Result<Option<
         &mut new_chunk_group_by_ukey,
         module_graph,
       );
+      split_chunks_artifact.integrate_chunks(&a, &b);
       integrated_chunks.insert(a);
       new_chunk_by_ukey.remove(&b);
       removed_chunks.insert(b);
@@ -303,6 +305,7 @@ async fn optimize_chunks(&self, compilation: &mut Compilation) -> Result<Option<
   compilation.build_chunk_graph_artifact.chunk_by_ukey = new_chunk_by_ukey;
   compilation.build_chunk_graph_artifact.chunk_group_by_ukey = new_chunk_group_by_ukey;
   compilation.build_chunk_graph_artifact.chunk_graph = new_chunk_graph;
+  compilation.split_chunks_artifact = split_chunks_artifact;
 
   if let Some(mut mutations) = compilation.incremental.mutations_write() {
     // ChunkRemove mutations must be added last because a chunk can be removed after another chunk
@@ -157,6 +157,9 @@ async fn optimize_chunks(&self, compilation: &mut Compilation) -> Result<Option<
             &mut chunk_group_by_ukey,
             compilation.get_module_graph(),
           );
+          compilation
+            .split_chunks_artifact
+            .integrate_chunks(&chunk_ukey, &other_chunk_ukey);
           if chunk_by_ukey.remove(&other_chunk_ukey).is_some()
             && let Some(mut mutations) = compilation.incremental.mutations_write()
           {
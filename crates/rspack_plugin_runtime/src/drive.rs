@@ -29,6 +29,14 @@ pub struct LinkPrefetchData {
   pub chunk: RuntimeModuleChunkWrapper,
 }
 
+/// `code` is the options object passed to `fetch` when loading async WebAssembly modules,
+/// empty means no options
+#[derive(Debug, Clone)]
+pub struct WasmFetchOptionsData {
+  pub code: String,
+  pub chunk: RuntimeModuleChunkWrapper,
+}
+
 #[derive(Debug, Clone)]
 pub struct RuntimeModuleChunkWrapper {
   pub chunk_ukey: ChunkUkey,
@@ -42,6 +50,7 @@ define_hook!(RuntimePluginCreateScript: SeriesWaterfall(data: CreateScriptData)
 define_hook!(RuntimePluginCreateLink: SeriesWaterfall(data: CreateLinkData) -> CreateLinkData);
 define_hook!(RuntimePluginLinkPreload: SeriesWaterfall(data: LinkPreloadData) -> LinkPreloadData);
 define_hook!(RuntimePluginLinkPrefetch: SeriesWaterfall(data: LinkPrefetchData) -> LinkPrefetchData);
+define_hook!(RuntimePluginWasmFetchOptions: SeriesWaterfall(data: WasmFetchOptionsData) -> WasmFetchOptionsData);
 
 #[derive(Debug, Default)]
 #[cfg_attr(allocative, derive(allocative::Allocative))]
@@ -54,4 +63,6 @@ pub struct RuntimePluginHooks {
   pub link_preload: RuntimePluginLinkPreloadHook,
   #[cfg_attr(allocative, allocative(skip))]
   pub link_prefetch: RuntimePluginLinkPrefetchHook,
+  #[cfg_attr(allocative, allocative(skip))]
+  pub wasm_fetch_options: RuntimePluginWasmFetchOptionsHook,
 }
@@ -1,6 +1,7 @@
-use std::collections::HashMap;
+use std::{collections::HashMap, io::Write};
 
 use derive_more::Debug;
+use fast_glob::glob_match;
 use futures::future::BoxFuture;
 use rspack_core::{
   ChunkGroup, ChunkGroupUkey, Compilation, CompilationAsset, CompilerAfterEmit, Plugin,
@@ -11,6 +12,72 @@ use rspack_util::size::format_size;
 
 pub type AssetFilterFn = Box<dyn for<'a> Fn(&'a str) -> BoxFuture<'a, Result<bool>> + Sync + Send>;
 
+const BROTLI_QUALITY: u32 = 5;
+const BROTLI_LG_WINDOW_SIZE: u32 = 22;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SizeLimitsCompression {
+  Gzip,
+  Brotli,
+  Zstd,
+}
+
+impl TryFrom<String> for SizeLimitsCompression {
+  type Error = rspack_error::Error;
+
+  fn try_from(value: String) -> Result<Self, rspack_error::Error> {
+    match value.as_str() {
+      "gzip" => Ok(Self::Gzip),
+      "brotli" => Ok(Self::Brotli),
+      "zstd" => Ok(Self::Zstd),
+      _ => Err(rspack_error::Error::error(format!(
+        "Expect size limits compression to be 'gzip', 'brotli' or 'zstd', but got '{value}'."
+      ))),
+    }
+  }
+}
+
+impl SizeLimitsCompression {
+  pub fn as_str(&self) -> &'static str {
+    match self {
+      Self::Gzip => "gzip",
+      Self::Brotli => "brotli",
+      Self::Zstd => "zstd",
+    }
+  }
+
+  /// Compress `content` and return the compressed size in bytes.
+  ///
+  /// Gzip and zstd use their default levels. Brotli uses quality 5 instead of its default
+  /// (maximum) quality 11, which is too slow to run on every emitted asset.
+  pub fn compressed_size(&self, content: &[u8]) -> std::io::Result<usize> {
+    match self {
+      Self::Gzip => {
+        let mut encoder = flate2::write::GzEncoder::new(Vec::new(), flate2::Compression::default());
+        encoder.write_all(content)?;
+        Ok(encoder.finish()?.len())
+      }
+      Self::Brotli => {
+        let mut output = Vec::new();
+        {
+          let mut encoder =
+            brotli::CompressorWriter::new(&mut output, 4096, BROTLI_QUALITY, BROTLI_LG_WINDOW_SIZE);
+          encoder.write_all(content)?;
+        }
+        Ok(output.len())
+      }
+      Self::Zstd => Ok(zstd::bulk::compress(content, zstd::DEFAULT_COMPRESSION_LEVEL)?.len()),
+    }
+  }
+}
+
+/// A size limit for the assets whose filename matches the glob `test`.
+#[derive(Debug)]
+pub struct AssetSizeLimit {
+  pub test: String,
+  pub max_size: f64,
+}
+
 #[derive(Debug)]
 pub struct SizeLimitsPluginOptions {
   #[debug(skip)]
@@ -18,6 +85,35 @@ pub struct SizeLimitsPluginOptions {
   pub hints: Option<String>,
   pub max_asset_size: Option<f64>,
   pub max_entrypoint_size: Option<f64>,
+  /// Limit the size of each async chunk group, i.e. the chunks loaded by one `import()`.
+  pub max_async_chunk_group_size: Option<f64>,
+  /// Limits for specific assets, the first matching one takes precedence over `max_asset_size`.
+  pub asset_size_limits: Vec<AssetSizeLimit>,
+  /// Compare the compressed size instead of the raw size against the limits.
+  pub compression: Option<SizeLimitsCompression>,
+}
+
+#[derive(Debug, Clone, Copy)]
+struct AssetSize {
+  size: f64,
+  compressed_size: Option<f64>,
+}
+
+impl AssetSize {
+  /// The size that is compared against the limits.
+  fn limited_size(&self) -> f64 {
+    self.compressed_size.unwrap_or(self.size)
+  }
+}
+
+impl std::ops::AddAssign for AssetSize {
+  fn add_assign(&mut self, rhs: Self) {
+    self.size += rhs.size;
+    self.compressed_size = match (self.compressed_size, rhs.compressed_size) {
+      (Some(a), Some(b)) => Some(a + b),
+      (a, b) => a.or(b),
+    };
+  }
 }
 
 #[plugin]
@@ -43,26 +139,55 @@ impl SizeLimitsPlugin {
     }
   }
 
-  async fn get_entrypoint_size(&self, entrypoint: &ChunkGroup, compilation: &Compilation) -> f64 {
-    let mut size = 0.0;
+  fn get_max_asset_size(&self, name: &str, default: f64) -> f64 {
+    self
+      .options
+      .asset_size_limits
+      .iter()
+      .find(|limit| glob_match(&limit.test, name))
+      .map_or(default, |limit| limit.max_size)
+  }
 
-    for filename in entrypoint.get_files(&compilation.build_chunk_graph_artifact.chunk_by_ukey) {
-      let asset = compilation.assets().get(&filename);
+  fn get_chunk_group_size(
+    &self,
+    chunk_group: &ChunkGroup,
+    compilation: &Compilation,
+    asset_sizes: &HashMap<String, AssetSize>,
+  ) -> (AssetSize, Vec<String>) {
+    let mut size = AssetSize {
+      size: 0.0,
+      compressed_size: self.options.compression.map(|_| 0.0),
+    };
+    let mut files = vec![];
 
-      if let Some(asset) = asset {
-        if !self.asset_filter(&filename, asset).await {
-          continue;
-        }
+    // only assets that pass the asset filter have a size
+    for filename in chunk_group.get_files(&compilation.build_chunk_graph_artifact.chunk_by_ukey) {
+      if let Some(asset_size) = asset_sizes.get(&filename) {
+        size += *asset_size;
+        files.push(filename);
+      }
+    }
 
-        let source = asset.get_source();
+    (size, files)
+  }
 
-        if let Some(source) = source {
-          size += source.size() as f64;
-        }
-      }
+  fn format_size(&self, size: &AssetSize) -> String {
+    match (self.options.compression, size.compressed_size) {
+      (Some(compression), Some(compressed_size)) => format!(
+        "{}, {}: {}",
+        format_size(size.size),
+        compression.as_str(),
+        format_size(compressed_size)
+      ),
+      _ => format_size(size.size),
     }
+  }
 
-    size
+  fn format_limit(&self, limit: f64) -> String {
+    match self.options.compression {
+      Some(compression) => format!("{} {}", format_size(limit), compression.as_str()),
+      None => format_size(limit),
+    }
   }
 
   fn add_diagnostic(
@@ -80,38 +205,45 @@ impl SizeLimitsPlugin {
   }
 
   fn add_assets_over_size_limit_warning(
-    detail: &[(String, f64)],
+    &self,
+    detail: &[(String, AssetSize, f64)],
     limit: f64,
     hints: &str,
     diagnostics: &mut Vec<Diagnostic>,
   ) {
     let asset_list: String = detail
       .iter()
-      .map(|(name, size)| format!("\n  {} ({})", name, format_size(*size)))
+      .map(|(name, size, asset_limit)| {
+        if *asset_limit == limit {
+          format!("\n  {} ({})", name, self.format_size(size))
+        } else {
+          format!(
+            "\n  {} ({}) exceeds its limit ({})",
+            name,
+            self.format_size(size),
+            self.format_limit(*asset_limit)
+          )
+        }
+      })
       .collect::<String>();
     let title = String::from("assets over size limit warning");
     let message = format!(
       "asset size limit: The following asset(s) exceed the recommended size limit ({}). This can impact web performance.\nAssets:{}",
-      format_size(limit),
+      self.format_limit(limit),
       asset_list
     );
 
     Self::add_diagnostic(hints, title, message, diagnostics);
   }
 
-  fn add_entrypoints_over_size_limit_warning(
-    detail: &[(&String, f64, Vec<String>)],
-    limit: f64,
-    hints: &str,
-    diagnostics: &mut Vec<Diagnostic>,
-  ) {
-    let entrypoint_list: String = detail
+  fn format_chunk_group_list(&self, detail: &[(String, AssetSize, Vec<String>)]) -> String {
+    detail
       .iter()
       .map(|(name, size, files)| {
         format!(
           "\n  {} ({})\n{}",
           name,
-          format_size(*size),
+          self.format_size(size),
           files
             .iter()
             .map(|file| format!("      {file}"))
@@ -119,12 +251,38 @@ impl SizeLimitsPlugin {
             .join("\n")
         )
       })
-      .collect::<String>();
+      .collect::<String>()
+  }
+
+  fn add_entrypoints_over_size_limit_warning(
+    &self,
+    detail: &[(String, AssetSize, Vec<String>)],
+    limit: f64,
+    hints: &str,
+    diagnostics: &mut Vec<Diagnostic>,
+  ) {
     let title = String::from("entrypoints over size limit warning");
     let message = format!(
       "entrypoint size limit: The following entrypoint(s) combined asset size exceeds the recommended limit ({}). This can impact web performance.\nEntrypoints:{}",
-      format_size(limit),
-      entrypoint_list
+      self.format_limit(limit),
+      self.format_chunk_group_list(detail)
+    );
+
+    Self::add_diagnostic(hints, title, message, diagnostics);
+  }
+
+  fn add_async_chunk_groups_over_size_limit_warning(
+    &self,
+    detail: &[(String, AssetSize, Vec<String>)],
+    limit: f64,
+    hints: &str,
+    diagnostics: &mut Vec<Diagnostic>,
+  ) {
+    let title = String::from("async chunk groups over size limit warning");
+    let message = format!(
+      "async chunk group size limit: The following async chunk group(s) combined asset size exceeds the recommended limit ({}). This can impact web performance.\nChunk groups:{}",
+      self.format_limit(limit),
+      self.format_chunk_group_list(detail)
     );
 
     Self::add_diagnostic(hints, title, message, diagnostics);
@@ -140,34 +298,58 @@ async fn after_emit(&self, compilation: &mut Compilation) -> Result<()> {
   let mut checked_chunk_groups: HashMap<ChunkGroupUkey, bool> = HashMap::default();
 
   let mut assets_over_size_limit = vec![];
+  let compression = self.options.compression;
 
   let asset_sizes = rspack_futures::scope::<_, _>(|token| {
     compilation.assets().iter().for_each(|(name, asset)| {
       // SAFETY: await immediately and trust caller to poll future entirely
-      let s = unsafe { token.used((&self, asset, name, max_asset_size)) };
-
-      s.spawn(|(plugin, asset, name, max_asset_size)| async move {
-        if !plugin.asset_filter(name, asset).await {
-          return None;
-        }
-
-        let source = asset.get_source()?;
-
-        let size = source.size() as f64;
-        let is_over_size_limit = size > max_asset_size;
-        Some((name.clone(), size, is_over_size_limit))
-      })
+      let s = unsafe { token.used((&self, asset, name, max_asset_size, compression)) };
+
+      s.spawn(
+        |(plugin, asset, name, max_asset_size, compression)| async move {
+          if !plugin.asset_filter(name, asset).await {
+            return Ok(None);
+          }
+
+          let Some(source) = asset.get_source() else {
+            return Ok(None);
+          };
+
+          let size = AssetSize {
+            size: source.size() as f64,
+            compressed_size: compression
+              .map(|compression| compression.compressed_size(&source.buffer()))
+              .transpose()
+              .to_rspack_result_with_message(|e| {
+                format!("Failed to compress asset '{name}' for size limits: {e}")
+              })?
+              .map(|size| size as f64),
+          };
+          let max_asset_size = plugin.get_max_asset_size(name, max_asset_size);
+          let is_over_size_limit = size.limited_size() > max_asset_size;
+          Ok(Some((
+            name.clone(),
+            size,
+            max_asset_size,
+            is_over_size_limit,
+          )))
+        },
+      )
     })
   })
   .await
   .into_iter()
   .map(|res| res.to_rspack_result())
+  .collect::<Result<Vec<Result<_>>>>()?
+  .into_iter()
   .collect::<Result<Vec<_>>>()?;
 
-  for (name, size, is_over_size_limit) in asset_sizes.into_iter().flatten() {
+  let mut sizes: HashMap<String, AssetSize> = HashMap::default();
+  for (name, size, asset_limit, is_over_size_limit) in asset_sizes.into_iter().flatten() {
     checked_assets.insert(name.clone(), is_over_size_limit);
+    sizes.insert(name.clone(), size);
     if is_over_size_limit {
-      assets_over_size_limit.push((name, size));
+      assets_over_size_limit.push((name, size, asset_limit));
     }
   }
 
@@ -178,32 +360,40 @@ async fn after_emit(&self, compilation: &mut Compilation) -> Result<()> {
       .build_chunk_graph_artifact
       .chunk_group_by_ukey
       .expect_get(ukey);
-    let size = self.get_entrypoint_size(entry, compilation).await;
-    let is_over_size_limit = size > max_entrypoint_size;
+    let (size, files) = self.get_chunk_group_size(entry, compilation, &sizes);
+    let is_over_size_limit = size.limited_size() > max_entrypoint_size;
 
     checked_chunk_groups.insert(ukey.to_owned(), is_over_size_limit);
     if is_over_size_limit {
-      let mut files = vec![];
+      entrypoints_over_limit.push((name.clone(), size, files));
+    }
+  }
 
-      for filename in entry.get_files(&compilation.build_chunk_graph_artifact.chunk_by_ukey) {
-        let asset = compilation.assets().get(&filename);
+  let mut async_chunk_groups_over_limit = vec![];
 
-        if let Some(asset) = asset
-          && self.asset_filter(&filename, asset).await
-        {
-          files.push(filename);
-        }
+  if let Some(max_async_chunk_group_size) = self.options.max_async_chunk_group_size {
+    for chunk_group in compilation
+      .build_chunk_graph_artifact
+      .chunk_group_by_ukey
+      .values()
+      .filter(|chunk_group| !chunk_group.is_initial())
+    {
+      let (size, files) = self.get_chunk_group_size(chunk_group, compilation, &sizes);
+      if size.limited_size() > max_async_chunk_group_size {
+        let name = chunk_group
+          .name()
+          .map_or_else(|| chunk_group.id(compilation), ToString::to_string);
+        async_chunk_groups_over_limit.push((name, size, files));
       }
-
-      entrypoints_over_limit.push((name, size, files));
     }
+    async_chunk_groups_over_limit.sort_by(|a, b| a.0.cmp(&b.0));
   }
 
   if let Some(hints) = hints {
     let mut diagnostics = vec![];
 
     if !assets_over_size_limit.is_empty() {
-      Self::add_assets_over_size_limit_warning(
+      self.add_assets_over_size_limit_warning(
         &assets_over_size_limit,
         max_asset_size,
         hints,
@@ -212,7 +402,7 @@ async fn after_emit(&self, compilation: &mut Compilation) -> Result<()> {
     }
 
     if !entrypoints_over_limit.is_empty() {
-      Self::add_entrypoints_over_size_limit_warning(
+      self.add_entrypoints_over_size_limit_warning(
         &entrypoints_over_limit,
         max_entrypoint_size,
         hints,
@@ -220,6 +410,17 @@ async fn after_emit(&self, compilation: &mut Compilation) -> Result<()> {
       );
     }
 
+    if let Some(max_async_chunk_group_size) = self.options.max_async_chunk_group_size
+      && !async_chunk_groups_over_limit.is_empty()
+    {
+      self.add_async_chunk_groups_over_size_limit_warning(
+        &async_chunk_groups_over_limit,
+        max_async_chunk_group_size,
+        hints,
+        &mut diagnostics,
+      );
+    }
+
     if !diagnostics.is_empty() {
       let has_async_chunk = compilation
         .build_chunk_graph_artifact
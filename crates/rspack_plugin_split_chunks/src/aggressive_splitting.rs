@@ -0,0 +1,365 @@
+use std::sync::Mutex;
+
+use rayon::prelude::*;
+use rspack_collections::{IdentifierMap, UkeySet};
+use rspack_core::{
+  ChunkUkey, Compilation, CompilationId, CompilationOptimizeChunks, ModuleIdentifier, Plugin,
+  SourceType, compare_chunks_with_graph, incremental::Mutation,
+};
+use rspack_error::Result;
+use rspack_hook::{plugin, plugin_hook};
+use rspack_util::identifier::make_paths_relative;
+use rustc_hash::FxHashMap;
+use serde::{Deserialize, Serialize};
+
+use crate::{SplitChunkSizes, get_module_sizes};
+
+const CHUNK_REASON: &str = "aggressive splitted";
+
+/// The key of the splits in the records.
+const RECORDS_KEY: &str = "aggressiveSplits";
+
+#[derive(Debug)]
+pub struct AggressiveSplittingPluginOptions {
+  /// The minimal size of a split piece.
+  pub min_size: SplitChunkSizes,
+  /// The chunks bigger than `max_size` are split into pieces.
+  pub max_size: SplitChunkSizes,
+}
+
+impl Default for AggressiveSplittingPluginOptions {
+  fn default() -> Self {
+    let default_size_types = [SourceType::JavaScript, SourceType::Unknown];
+    Self {
+      min_size: SplitChunkSizes::with_initial_value(&default_size_types, 30.0 * 1024.0),
+      max_size: SplitChunkSizes::with_initial_value(&default_size_types, 50.0 * 1024.0),
+    }
+  }
+}
+
+/// A piece split from chunks, identified by the relative identifiers of its modules
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AggressiveSplit {
+  pub modules: Vec<String>,
+  /// The total size of the modules, the split is dropped once the modules change.
+  pub size: f64,
+}
+
+#[derive(Debug, Default)]
+struct AggressiveSplittingState {
+  /// The splits of the last compilation, `None` before the first compilation.
+  splits: Option<Vec<AggressiveSplit>>,
+  /// The compilation already split, as the optimize chunks hooks are called again on changes.
+  compilation_id: Option<CompilationId>,
+}
+
+/// Splits chunks into pieces between `min_size` and `max_size`, the same as webpack's
+/// `AggressiveSplittingPlugin`.
+///
+/// The splits are recorded and applied again in the following builds as long as their modules
+/// are not changed, so an edit only invalidates the piece containing the changed module. They
+/// are persisted in the compilation records under `aggressiveSplits`, see `recordsPath`.
+#[plugin]
+#[derive(Debug)]
+pub struct AggressiveSplittingPlugin {
+  options: AggressiveSplittingPluginOptions,
+  state: Mutex<AggressiveSplittingState>,
+}
+
+impl AggressiveSplittingPlugin {
+  pub fn new(options: AggressiveSplittingPluginOptions) -> Self {
+    Self::new_inner(options, Default::default())
+  }
+
+  fn load_splits(&self, compilation: &Compilation) -> Result<Vec<AggressiveSplit>> {
+    if let Some(splits) = &self.state.lock().expect("should get lock").splits {
+      return Ok(splits.clone());
+    }
+    Ok(
+      compilation
+        .records_artifact
+        .get::<Vec<AggressiveSplit>>(RECORDS_KEY)?
+        .unwrap_or_default(),
+    )
+  }
+}
+
+struct Splitter<'a> {
+  compilation: &'a mut Compilation,
+  name_to_module: FxHashMap<String, ModuleIdentifier>,
+  module_sizes: IdentifierMap<SplitChunkSizes>,
+  split_chunks: UkeySet<ChunkUkey>,
+}
+
+impl Splitter<'_> {
+  fn total_size(&self, modules: &[ModuleIdentifier]) -> f64 {
+    modules
+      .iter()
+      .filter_map(|module| self.module_sizes.get(module))
+      .flat_map(|sizes| sizes.values())
+      .sum()
+  }
+
+  fn chunk_sizes(&self, chunk: &ChunkUkey) -> SplitChunkSizes {
+    let mut sizes = SplitChunkSizes::empty();
+    for module in self
+      .compilation
+      .build_chunk_graph_artifact
+      .chunk_graph
+      .get_chunk_modules_identifier(chunk)
+    {
+      if let Some(module_sizes) = self.module_sizes.get(module) {
+        sizes.add_by(module_sizes);
+      }
+    }
+    sizes
+  }
+
+  /// Returns whether the split is applied, or `None` if the modules of the split are changed.
+  fn apply_split(&mut self, split: &AggressiveSplit) -> Option<bool> {
+    let Some(modules) = split
+      .modules
+      .iter()
+      .map(|name| self.name_to_module.get(name).copied())
+      .collect::<Option<Vec<_>>>()
+    else {
+      return Some(false);
+    };
+    if modules.is_empty() {
+      return Some(false);
+    }
+    if (self.total_size(&modules) - split.size).abs() > f64::EPSILON {
+      return None;
+    }
+
+    // the chunks containing all the modules
+    let chunk_graph = &self.compilation.build_chunk_graph_artifact.chunk_graph;
+    let mut selected_chunks = chunk_graph.get_module_chunks(modules[0]).clone();
+    for module in &modules[1..] {
+      let chunks = chunk_graph.get_module_chunks(*module);
+      selected_chunks.retain(|chunk| chunks.contains(chunk));
+    }
+    if selected_chunks.is_empty() {
+      return Some(false);
+    }
+
+    // the chunk is already the split
+    if selected_chunks.len() == 1 {
+      let chunk = *selected_chunks.iter().next().expect("should have chunk");
+      if chunk_graph.get_number_of_chunk_modules(&chunk) == modules.len() {
+        return Some(self.split_chunks.insert(chunk));
+      }
+    }
+
+    let new_chunk_ukey =
+      Compilation::add_chunk(&mut self.compilation.build_chunk_graph_artifact.chunk_by_ukey);
+    if let Some(mut mutations) = self.compilation.incremental.mutations_write() {
+      mutations.add(Mutation::ChunkAdd {
+        chunk: new_chunk_ukey,
+      });
+    }
+    self
+      .compilation
+      .build_chunk_graph_artifact
+      .chunk_graph
+      .add_chunk(new_chunk_ukey);
+
+    let mut selected_chunks = selected_chunks.into_iter().collect::<Vec<_>>();
+    selected_chunks.sort_unstable();
+    for chunk_ukey in selected_chunks {
+      let chunk_graph = &mut self.compilation.build_chunk_graph_artifact.chunk_graph;
+      for module in &modules {
+        chunk_graph.connect_chunk_and_module(new_chunk_ukey, *module);
+        chunk_graph.disconnect_chunk_and_module(&chunk_ukey, *module);
+      }
+
+      let [new_chunk, chunk] = self
+        .compilation
+        .build_chunk_graph_artifact
+        .chunk_by_ukey
+        .get_many_mut([&new_chunk_ukey, &chunk_ukey]);
+      let new_chunk = new_chunk.expect("should have new chunk");
+      let chunk = chunk.expect("should have chunk to split");
+      chunk.split(
+        new_chunk,
+        &mut self
+          .compilation
+          .build_chunk_graph_artifact
+          .chunk_group_by_ukey,
+      );
+      *new_chunk.chunk_reason_mut() = Some(CHUNK_REASON.to_string());
+      if let Some(mut mutations) = self.compilation.incremental.mutations_write() {
+        mutations.add(Mutation::ChunkSplit {
+          from: chunk_ukey,
+          to: new_chunk_ukey,
+        });
+      }
+    }
+
+    self.split_chunks.insert(new_chunk_ukey);
+    Some(true)
+  }
+
+  /// Select the modules of a new split from the chunk, or `None` if the chunk is small enough.
+  fn create_split(
+    &self,
+    chunk: &ChunkUkey,
+    options: &AggressiveSplittingPluginOptions,
+    module_to_name: &IdentifierMap<String>,
+  ) -> Option<AggressiveSplit> {
+    let chunk_graph = &self.compilation.build_chunk_graph_artifact.chunk_graph;
+    if chunk_graph.get_number_of_chunk_modules(chunk) <= 1
+      || !self.chunk_sizes(chunk).bigger_than(&options.max_size)
+    {
+      return None;
+    }
+
+    let entry_modules = chunk_graph.get_chunk_entry_modules(chunk);
+    let mut selected_modules = vec![];
+    let mut selected_sizes = SplitChunkSizes::empty();
+    for module in chunk_graph
+      .get_ordered_chunk_modules_identifier(chunk)
+      .into_iter()
+      .filter(|module| !entry_modules.contains(module))
+    {
+      let mut new_sizes = selected_sizes.clone();
+      if let Some(module_sizes) = self.module_sizes.get(&module) {
+        new_sizes.add_by(module_sizes);
+      }
+      if new_sizes.bigger_than(&options.max_size) && !selected_sizes.smaller_than(&options.min_size)
+      {
+        break;
+      }
+      selected_sizes = new_sizes;
+      selected_modules.push(module);
+    }
+    if selected_modules.is_empty() {
+      return None;
+    }
+
+    let mut modules = selected_modules
+      .iter()
+      .filter_map(|module| module_to_name.get(module).cloned())
+      .collect::<Vec<_>>();
+    modules.sort_unstable();
+    Some(AggressiveSplit {
+      modules,
+      size: self.total_size(&selected_modules),
+    })
+  }
+
+  /// The chunks which are not split yet, biggest first.
+  fn sorted_chunks(&self) -> Vec<ChunkUkey> {
+    let chunk_graph = &self.compilation.build_chunk_graph_artifact.chunk_graph;
+    let module_graph = self.compilation.get_module_graph();
+    let mut chunks = self
+      .compilation
+      .build_chunk_graph_artifact
+      .chunk_by_ukey
+      .keys()
+      .filter(|chunk| !self.split_chunks.contains(*chunk))
+      .map(|chunk| {
+        let size = self.chunk_sizes(chunk).values().sum::<f64>();
+        (*chunk, size)
+      })
+      .collect::<Vec<_>>();
+    chunks.sort_by(|(a, a_size), (b, b_size)| {
+      b_size
+        .total_cmp(a_size)
+        .then_with(|| {
+          chunk_graph
+            .get_number_of_chunk_modules(a)
+            .cmp(&chunk_graph.get_number_of_chunk_modules(b))
+        })
+        .then_with(|| compare_chunks_with_graph(chunk_graph, module_graph, a, b))
+    });
+    chunks.into_iter().map(|(chunk, _)| chunk).collect()
+  }
+}
+
+#[plugin_hook(CompilationOptimizeChunks for AggressiveSplittingPlugin, stage = Compilation::OPTIMIZE_CHUNKS_STAGE_ADVANCED)]
+async fn optimize_chunks(&self, compilation: &mut Compilation) -> Result<Option<bool>> {
+  if self.state.lock().expect("should get lock").compilation_id == Some(compilation.id()) {
+    return Ok(None);
+  }
+  let recorded_splits = self.load_splits(compilation)?;
+
+  let context = compilation.options.context.as_str();
+  let module_graph = compilation.get_module_graph();
+  let module_to_name = module_graph
+    .modules()
+    .map(|(identifier, _)| (*identifier, make_paths_relative(context, identifier)))
+    .collect::<IdentifierMap<_>>();
+  let name_to_module = module_to_name
+    .iter()
+    .map(|(identifier, name)| (name.clone(), *identifier))
+    .collect::<FxHashMap<_, _>>();
+  let module_sizes = get_module_sizes(module_to_name.keys().copied().par_bridge(), compilation)
+    .into_iter()
+    .map(|(identifier, sizes)| (identifier, SplitChunkSizes(sizes)))
+    .collect::<IdentifierMap<_>>();
+
+  let mut splitter = Splitter {
+    compilation,
+    name_to_module,
+    module_sizes,
+    split_chunks: Default::default(),
+  };
+
+  // restore the recorded splits first
+  let mut changed = false;
+  let mut applied_splits = vec![];
+  let mut unused_splits = vec![];
+  for split in recorded_splits {
+    match splitter.apply_split(&split) {
+      Some(true) => {
+        changed = true;
+        applied_splits.push(split);
+      }
+      Some(false) => unused_splits.push(split),
+      // the modules are changed, so the split is dropped
+      None => {}
+    }
+  }
+
+  // split the remaining big chunks, starting with the biggest one
+  loop {
+    let mut split_any = false;
+    for chunk in splitter.sorted_chunks() {
+      if splitter.split_chunks.contains(&chunk) {
+        continue;
+      }
+      if let Some(split) = splitter.create_split(&chunk, &self.options, &module_to_name)
+        && splitter.apply_split(&split) == Some(true)
+      {
+        split_any = true;
+        applied_splits.push(split);
+      }
+    }
+    if !split_any {
+      break;
+    }
+    changed = true;
+  }
+
+  // the unused splits can still be used in the following builds
+  applied_splits.extend(unused_splits);
+  let compilation = splitter.compilation;
+  compilation
+    .records_artifact
+    .set(RECORDS_KEY, &applied_splits)?;
+  let mut state = self.state.lock().expect("should get lock");
+  state.splits = Some(applied_splits);
+  state.compilation_id = Some(compilation.id());
+  Ok(changed.then_some(true))
+}
+
+impl Plugin for AggressiveSplittingPlugin {
+  fn apply(&self, ctx: &mut rspack_core::ApplyContext<'_>) -> Result<()> {
+    ctx
+      .compilation_hooks
+      .optimize_chunks
+      .tap(optimize_chunks::new(self));
+    Ok(())
+  }
+}
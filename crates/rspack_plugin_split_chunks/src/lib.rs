@@ -1,8 +1,12 @@
+mod aggressive_splitting;
 mod common;
 mod module_group;
 mod options;
 mod plugin;
 
+pub use aggressive_splitting::{
+  AggressiveSplit, AggressiveSplittingPlugin, AggressiveSplittingPluginOptions,
+};
 pub use common::{
   ChunkFilter, FallbackCacheGroup, ModuleLayerFilter, ModuleSizes, ModuleTypeFilter,
   SplitChunkSizes, create_all_chunk_filter, create_async_chunk_filter,
@@ -224,6 +224,13 @@ impl SplitChunksPlugin {
       .build_chunk_graph_artifact
       .chunk_graph
       .connect_chunk_and_modules(new_chunk, &modules);
+
+    let cache_group = item.get_cache_group(&self.cache_groups);
+    for module in modules {
+      compilation
+        .split_chunks_artifact
+        .set_cache_group(new_chunk, module, cache_group.key.clone());
+    }
   }
 
   /// Since the modules are moved into the `new_chunk`, we should
@@ -696,6 +696,19 @@ impl SplitChunksPlugin {
               to: new_chunk_ukey,
             });
           }
+          for module in &group.nodes {
+            if let Some(cache_group) = compilation
+              .split_chunks_artifact
+              .cache_group(&old_chunk, &module.module)
+              .map(ToString::to_string)
+            {
+              compilation.split_chunks_artifact.set_cache_group(
+                new_part_ukey,
+                module.module,
+                cache_group,
+              );
+            }
+          }
 
           group.nodes.iter().for_each(|module| {
             compilation
@@ -1,13 +1,13 @@
-use std::{cmp::Ordering, sync::Arc};
+use std::{cmp::Ordering, collections::BTreeMap, sync::Arc};
 
 use rayon::iter::{IntoParallelIterator, IntoParallelRefIterator, ParallelIterator};
 use rspack_core::{
-  ChunkUkey, Compilation, CompilationAfterProcessAssets, CompilationAssets,
-  CompilationProcessAssets, CrossOriginLoading, ManifestAssetType,
+  AssetInfo, ChunkUkey, Compilation, CompilationAfterProcessAssets, CompilationAsset,
+  CompilationAssets, CompilationProcessAssets, CrossOriginLoading, ManifestAssetType,
   chunk_graph_chunk::ChunkId,
-  rspack_sources::{ReplaceSource, Source},
+  rspack_sources::{RawStringSource, ReplaceSource, Source, SourceExt},
 };
-use rspack_error::{Diagnostic, Result};
+use rspack_error::{Diagnostic, Result, ToStringResultToRspackResultExt};
 use rspack_hook::plugin_hook;
 use rspack_plugin_real_content_hash::RealContentHashPluginUpdateHash;
 use rustc_hash::{FxHashMap as HashMap, FxHashSet as HashSet};
@@ -163,6 +163,17 @@ fn process_chunk_source(
   hash_by_placeholders: &HashMap<String, String>,
   hot_update_global: &str,
 ) -> ProcessChunkResult {
+  // binary assets never contain placeholders and must not be decoded as text
+  if matches!(asset_type, ManifestAssetType::Wasm) {
+    return ProcessChunkResult {
+      file: file.to_string(),
+      source: None,
+      warnings: vec![],
+      placeholder: None,
+      integrity: Some(compute_integrity(hash_funcs, &source.buffer())),
+    };
+  }
+
   // generate new source
   let mut new_source = ReplaceSource::new(source.clone());
 
@@ -183,7 +194,7 @@ fn process_chunk_source(
   }
 
   // compute self integrity and placeholder
-  let integrity = compute_integrity(hash_funcs, new_source.source().into_string_lossy().as_ref());
+  let integrity = compute_integrity(hash_funcs, &new_source.buffer());
   let placeholder = chunk_id.map(|id| make_placeholder(asset_type, hash_funcs, id.as_str()));
 
   ProcessChunkResult {
@@ -257,8 +268,7 @@ async fn add_minssing_integrities(
           return None;
         }
         asset.source.as_ref().map(|s| {
-          let content = s.source().into_string_lossy();
-          let integrity = compute_integrity(hash_func_names, &content);
+          let integrity = compute_integrity(hash_func_names, &s.buffer());
           (src.clone(), integrity)
         })
       })
@@ -301,6 +311,39 @@ pub async fn handle_assets(&self, compilation: &mut Compilation) -> Result<()> {
   Ok(())
 }
 
+#[plugin_hook(CompilationProcessAssets for SubresourceIntegrityPlugin, stage = Compilation::PROCESS_ASSETS_STAGE_AFTER_OPTIMIZE_HASH)]
+pub async fn emit_integrity_manifest(&self, compilation: &mut Compilation) -> Result<()> {
+  let Some(manifest_filename) = &self.options.manifest else {
+    return Ok(());
+  };
+
+  // assets may still be changed by real content hash, so compute from the final sources
+  let integrities = compilation
+    .assets()
+    .par_iter()
+    .filter(|(name, asset)| {
+      *name != manifest_filename && !asset.get_info().hot_module_replacement.unwrap_or(false)
+    })
+    .filter_map(|(name, asset)| {
+      let source = asset.get_source()?;
+      Some((
+        name.clone(),
+        compute_integrity(&self.options.hash_func_names, &source.buffer()),
+      ))
+    })
+    .collect::<BTreeMap<_, _>>();
+
+  let content = serde_json::to_string_pretty(&integrities).to_rspack_result()?;
+  compilation.emit_asset(
+    manifest_filename.clone(),
+    CompilationAsset::new(
+      Some(RawStringSource::from(content).boxed()),
+      AssetInfo::default(),
+    ),
+  );
+  Ok(())
+}
+
 #[plugin_hook(CompilationAfterProcessAssets for SubresourceIntegrityPlugin)]
 pub async fn detect_unresolved_integrity(
   &self,
@@ -349,8 +392,7 @@ pub async fn update_hash(
     .iter()
     .find_map(|(k, v)| if v == old_hash { Some(k.clone()) } else { None });
   if let (Some(key), Some(asset)) = (key, assets.first()) {
-    let content = asset.source().into_string_lossy();
-    let new_integrity = compute_integrity(&self.options.hash_func_names, content.as_ref());
+    let new_integrity = compute_integrity(&self.options.hash_func_names, &asset.buffer());
     compilation_integrities
       .write()
       .await
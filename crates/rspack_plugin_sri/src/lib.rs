@@ -7,7 +7,7 @@ mod util;
 
 use std::sync::{Arc, LazyLock};
 
-use asset::{detect_unresolved_integrity, handle_assets, update_hash};
+use asset::{detect_unresolved_integrity, emit_integrity_manifest, handle_assets, update_hash};
 use config::SRICompilationContext;
 pub use config::{
   IntegrityCallbackData, IntegrityCallbackFn, IntegrityHtmlPlugin,
@@ -27,7 +27,7 @@ use rspack_plugin_runtime::RuntimePlugin;
 #[cfg(allocative)]
 use rspack_util::allocative;
 use rspack_util::fx_hash::FxDashMap;
-use runtime::{create_link, create_script, handle_runtime, link_preload};
+use runtime::{create_link, create_script, handle_runtime, link_preload, wasm_fetch_options};
 use rustc_hash::FxHashMap as HashMap;
 use tokio::sync::RwLock;
 
@@ -156,6 +156,9 @@ async fn handle_compilation(
     runtime_plugin_hooks
       .link_preload
       .tap(link_preload::new(self));
+    runtime_plugin_hooks
+      .wasm_fetch_options
+      .tap(wasm_fetch_options::new(self));
   }
 
   if matches!(self.options.html_plugin, IntegrityHtmlPlugin::NativePlugin) {
@@ -205,6 +208,11 @@ impl Plugin for SubresourceIntegrityPlugin {
       .process_assets
       .tap(handle_assets::new(self));
 
+    ctx
+      .compilation_hooks
+      .process_assets
+      .tap(emit_integrity_manifest::new(self));
+
     ctx
       .compilation_hooks
       .after_process_assets
@@ -1,18 +1,23 @@
+use std::collections::BTreeMap;
+
 use rspack_core::{
   ChunkUkey, Compilation, CompilationAdditionalTreeRuntimeRequirements, CrossOriginLoading,
-  ManifestAssetType, RuntimeGlobals, RuntimeModule, RuntimeModuleExt, RuntimeModuleGenerateContext,
-  RuntimeTemplate, SourceType, chunk_graph_chunk::ChunkId, impl_runtime_module,
+  ManifestAssetType, ModuleType, RuntimeGlobals, RuntimeModule, RuntimeModuleExt,
+  RuntimeModuleGenerateContext, RuntimeTemplate, SourceType, chunk_graph_chunk::ChunkId,
+  impl_runtime_module,
 };
 use rspack_error::{Result, error};
 use rspack_hook::plugin_hook;
 use rspack_plugin_runtime::{
   CreateLinkData, CreateScriptData, LinkPreloadData, RuntimePluginCreateLink,
-  RuntimePluginCreateScript, RuntimePluginLinkPreload,
+  RuntimePluginCreateScript, RuntimePluginLinkPreload, RuntimePluginWasmFetchOptions,
+  WasmFetchOptionsData,
 };
 use rustc_hash::FxHashMap as HashMap;
 
 use crate::{
   SubresourceIntegrityHashFunction, SubresourceIntegrityPlugin, SubresourceIntegrityPluginInner,
+  integrity::compute_integrity,
   util::{find_chunks, get_hash_variable, make_placeholder},
 };
 
@@ -157,6 +162,17 @@ impl RuntimeModule for SRIHashVariableRuntimeModule {
       }
     }
 
+    let wasm_hashes = generate_sri_wasm_hashes(&all_chunks, &self.hash_funcs, compilation);
+    if !wasm_hashes.is_empty() {
+      code.push(format!(
+        r#"
+          {} = {};
+          "#,
+        get_hash_variable(&runtime_template, SourceType::Wasm),
+        serde_json::to_string(&wasm_hashes).map_err(|e| error!(e.to_string()))?,
+      ));
+    }
+
     Ok(code.join("\n"))
   }
 
@@ -186,6 +202,50 @@ fn generate_sri_hash_placeholders(
   )
 }
 
+/// Async WebAssembly modules are fetched by the runtime and keyed by the module hash, and their
+/// binaries are never rewritten afterwards, so the integrity can be computed right away.
+fn generate_sri_wasm_hashes(
+  chunks: &[ChunkUkey],
+  hash_funcs: &Vec<SubresourceIntegrityHashFunction>,
+  compilation: &Compilation,
+) -> BTreeMap<String, String> {
+  let module_graph = compilation.get_module_graph();
+  let mut hashes = BTreeMap::default();
+  for chunk_ukey in chunks {
+    let Some(chunk) = compilation
+      .build_chunk_graph_artifact
+      .chunk_by_ukey
+      .get(chunk_ukey)
+    else {
+      continue;
+    };
+    for module in compilation
+      .build_chunk_graph_artifact
+      .chunk_graph
+      .get_chunk_modules(chunk_ukey, module_graph)
+    {
+      if module.module_type() != &ModuleType::WasmAsync {
+        continue;
+      }
+      let Some(hash) = module.build_info().hash.as_ref() else {
+        continue;
+      };
+      let Some(source) = compilation
+        .code_generation_results
+        .get(&module.identifier(), Some(chunk.runtime()))
+        .get(&SourceType::Wasm)
+      else {
+        continue;
+      };
+      hashes.insert(
+        hash.rendered(16).to_string(),
+        compute_integrity(hash_funcs, &source.buffer()),
+      );
+    }
+  }
+  hashes
+}
+
 #[plugin_hook(RuntimePluginCreateScript for SubresourceIntegrityPlugin)]
 pub async fn create_script(&self, mut data: CreateScriptData) -> Result<CreateScriptData> {
   let ctx = SubresourceIntegrityPlugin::get_compilation_sri_context(data.chunk.compilation_id);
@@ -253,6 +313,28 @@ pub async fn link_preload(&self, mut data: LinkPreloadData) -> Result<LinkPreloa
   Ok(data)
 }
 
+#[plugin_hook(RuntimePluginWasmFetchOptions for SubresourceIntegrityPlugin)]
+pub async fn wasm_fetch_options(
+  &self,
+  mut data: WasmFetchOptionsData,
+) -> Result<WasmFetchOptionsData> {
+  let ctx = SubresourceIntegrityPlugin::get_compilation_sri_context(data.chunk.compilation_id);
+  // `fetch` only sends credentials to the same origin by default
+  let credentials = if matches!(
+    &ctx.cross_origin_loading,
+    CrossOriginLoading::Enable(cross_origin) if cross_origin == "use-credentials"
+  ) {
+    r#", credentials: "include""#
+  } else {
+    ""
+  };
+  data.code = format!(
+    "{{ integrity: {}[wasmModuleHash]{credentials} }}",
+    get_hash_variable(&ctx.runtime_template, SourceType::Wasm),
+  );
+  Ok(data)
+}
+
 #[plugin_hook(CompilationAdditionalTreeRuntimeRequirements for SubresourceIntegrityPlugin)]
 pub async fn handle_runtime(
   &self,
@@ -28,6 +28,7 @@ pub fn get_hash_variable(runtime_template: &ModuleCodeTemplate, source_type: Sou
     SourceType::Custom(t) if t == "css/mini-extract" => {
       format!("{require_name}.sriExtractCssHashes")
     }
+    SourceType::Wasm => format!("{require_name}.sriWasmHashes"),
     _ => unreachable!(),
   }
 }
@@ -92,7 +93,7 @@ pub fn make_placeholder(
   id: &str,
 ) -> String {
   let placeholder_source = format!("{PLACEHOLDER_PREFIX}{asset_type}{id}");
-  let filler = compute_integrity(hash_funcs, &placeholder_source);
+  let filler = compute_integrity(hash_funcs, placeholder_source.as_bytes());
   format!(
     "{}{}",
     PLACEHOLDER_PREFIX,
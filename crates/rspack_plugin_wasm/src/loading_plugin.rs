@@ -1,9 +1,12 @@
+use std::ptr::NonNull;
+
 use rspack_core::{
   BoxPlugin, ChunkUkey, Compilation, CompilationRuntimeRequirementInTree, Plugin, PluginExt,
   RuntimeGlobals, RuntimeModule, RuntimeModuleExt, WasmLoading, WasmLoadingType,
 };
 use rspack_error::Result;
 use rspack_hook::{plugin, plugin_hook};
+use rspack_plugin_runtime::{RuntimeModuleChunkWrapper, RuntimePlugin, WasmFetchOptionsData};
 
 use crate::runtime::AsyncWasmLoadingRuntimeModule;
 
@@ -15,6 +18,31 @@ pub fn enable_wasm_loading_plugin(wasm_loading_type: WasmLoadingType) -> BoxPlug
   }
 }
 
+/// Returns the extra `fetch` argument provided by `RuntimePlugin` hooks, e.g. `, { integrity: ... }`
+async fn get_wasm_fetch_options(
+  compilation: &Compilation,
+  chunk_ukey: &ChunkUkey,
+) -> Result<String> {
+  let hooks = RuntimePlugin::get_compilation_hooks(compilation.id());
+  let data = hooks
+    .borrow()
+    .wasm_fetch_options
+    .call(WasmFetchOptionsData {
+      code: String::new(),
+      chunk: RuntimeModuleChunkWrapper {
+        chunk_ukey: *chunk_ukey,
+        compilation_id: compilation.id(),
+        compilation: NonNull::from(compilation),
+      },
+    })
+    .await?;
+  if data.code.is_empty() {
+    Ok(String::new())
+  } else {
+    Ok(format!(", {}", data.code))
+  }
+}
+
 #[plugin]
 #[derive(Debug, Default)]
 pub struct FetchCompileAsyncWasmPlugin;
@@ -35,12 +63,13 @@ async fn fetch_compile_async_wasm_plugin_runtime_requirements_in_tree(
 
   let runtime_template = compilation.runtime_template.create_runtime_code_template();
   runtime_requirements_mut.insert(RuntimeGlobals::PUBLIC_PATH);
+  let fetch_options = get_wasm_fetch_options(compilation, chunk_ukey).await?;
   runtime_modules_to_add.push((
     *chunk_ukey,
     AsyncWasmLoadingRuntimeModule::new(
       &compilation.runtime_template,
       format!(
-        "fetch({} + $PATH)",
+        "fetch({} + $PATH{fetch_options})",
         runtime_template.render_runtime_globals(&RuntimeGlobals::PUBLIC_PATH)
       ),
       true,
@@ -173,9 +202,10 @@ var wasmUrl = $PATH;"#
       .to_string();
 
   // Generate load binary code: use fetch in browser, fs.readFile in Node.js
+  let fetch_options = get_wasm_fetch_options(compilation, chunk_ukey).await?;
   let generate_load_binary_code = format!(
     r#"(useFetch
-  ? fetch(new URL(wasmUrl, {import_meta_name}.url))
+  ? fetch(new URL(wasmUrl, {import_meta_name}.url){fetch_options})
   : Promise.all([import('fs'), import('url')]).then(([{{ readFile }}, {{ URL }}]) => new Promise((resolve, reject) => {{
       readFile(new URL(wasmUrl, {import_meta_name}.url), (err, buffer) => {{
         if (err) return reject(err);
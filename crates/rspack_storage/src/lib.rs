@@ -1,12 +1,17 @@
 mod error;
 mod fs;
 mod pack;
+mod seed;
 
 use std::sync::Arc;
 
 pub use error::Result;
 pub use fs::{BridgeFileSystem, FSError, FSOperation, FSResult, FileSystem, Reader, Writer};
-pub use pack::{PackStorage, PackStorageOptions};
+pub use pack::{
+  LoadOnlyPackStorageOptions, PackCompression, PackStorage, PackStorageOptions, ScopeStats,
+  StorageStats, remove_expired_versions,
+};
+pub use seed::SeedStorage;
 use tokio::sync::oneshot::Receiver;
 
 type ItemKey = Vec<u8>;
@@ -22,6 +27,10 @@ pub trait Storage: std::fmt::Debug + Sync + Send {
   async fn reset(&self);
   /// Get list of all available scopes in the storage
   async fn scopes(&self) -> Result<Vec<String>>;
+  /// Take the size of the cache evicted to fit the size budget since last call, in bytes
+  fn take_evicted_size(&self) -> u64 {
+    0
+  }
 }
 
 pub type ArcStorage = Arc<dyn Storage>;
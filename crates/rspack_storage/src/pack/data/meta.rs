@@ -2,7 +2,7 @@ use rspack_paths::{Utf8Path, Utf8PathBuf};
 use rspack_util::current_time;
 use rustc_hash::FxHashSet as HashSet;
 
-use super::options::PackOptions;
+use super::options::{PackCompression, PackOptions};
 
 #[derive(Debug, PartialEq, Eq, Hash)]
 pub struct PackFileMeta {
@@ -11,6 +11,8 @@ pub struct PackFileMeta {
   pub size: usize,
   pub wrote: bool,
   pub generation: usize,
+  /// Checksum of the uncompressed pack contents, empty for packs written before it was introduced
+  pub checksum: String,
 }
 
 #[derive(Debug, Default, Clone)]
@@ -23,6 +25,7 @@ pub enum RootMetaFrom {
 #[derive(Debug, Default, Clone)]
 pub struct RootMeta {
   pub expire_time: u64,
+  pub last_used: u64,
   pub scopes: HashSet<String>,
   pub from: RootMetaFrom,
 }
@@ -32,6 +35,7 @@ impl RootMeta {
     Self {
       scopes,
       expire_time: current_time() + expire,
+      last_used: current_time(),
       from: RootMetaFrom::New,
     }
   }
@@ -45,6 +49,7 @@ pub struct ScopeMeta {
   pub path: Utf8PathBuf,
   pub bucket_size: usize,
   pub pack_size: usize,
+  pub compression: PackCompression,
   pub generation: usize,
   pub packs: Vec<Vec<PackFileMeta>>,
 }
@@ -59,6 +64,7 @@ impl ScopeMeta {
       path: Self::get_path(dir),
       bucket_size: options.bucket_size,
       pack_size: options.pack_size,
+      compression: options.compression,
       generation: 0,
       packs,
     }
@@ -4,7 +4,7 @@ mod pack;
 mod scope;
 
 pub use meta::{PackFileMeta, RootMeta, RootMetaFrom, ScopeMeta};
-pub use options::{PackOptions, RootOptions};
+pub use options::{PackCompression, PackOptions, RootOptions};
 pub use pack::{Pack, PackContents, PackGenerations, PackKeys};
 pub use rspack_util::current_time;
 pub use scope::{PackScope, RootMetaState};
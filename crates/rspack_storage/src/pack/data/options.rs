@@ -1,9 +1,56 @@
 use rspack_paths::Utf8PathBuf;
 
+/// Codec used to compress the contents of pack files.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum PackCompression {
+  #[default]
+  None,
+  Zstd,
+  Lz4,
+}
+
+impl PackCompression {
+  /// The id written to the scope meta file.
+  pub fn id(&self) -> usize {
+    match self {
+      Self::None => 0,
+      Self::Zstd => 1,
+      Self::Lz4 => 2,
+    }
+  }
+
+  pub fn from_id(id: usize) -> Option<Self> {
+    match id {
+      0 => Some(Self::None),
+      1 => Some(Self::Zstd),
+      2 => Some(Self::Lz4),
+      _ => None,
+    }
+  }
+
+  pub fn compress(&self, data: &[u8]) -> std::io::Result<Vec<u8>> {
+    match self {
+      Self::None => Ok(data.to_vec()),
+      Self::Zstd => zstd::bulk::compress(data, zstd::DEFAULT_COMPRESSION_LEVEL),
+      Self::Lz4 => Ok(lz4_flex::compress_prepend_size(data)),
+    }
+  }
+
+  pub fn decompress(&self, data: &[u8], capacity: usize) -> std::io::Result<Vec<u8>> {
+    match self {
+      Self::None => Ok(data.to_vec()),
+      Self::Zstd => zstd::bulk::decompress(data, capacity),
+      Self::Lz4 => lz4_flex::decompress_size_prepended(data)
+        .map_err(|e| std::io::Error::new(std::io::ErrorKind::InvalidData, e)),
+    }
+  }
+}
+
 #[derive(Debug)]
 pub struct PackOptions {
   pub bucket_size: usize,
   pub pack_size: usize,
+  pub compression: PackCompression,
 }
 
 #[derive(Debug)]
@@ -11,4 +58,6 @@ pub struct RootOptions {
   pub root: Utf8PathBuf,
   pub expire: u64,
   pub clean: bool,
+  /// Max total size of the versions under the root in bytes, the least recently used versions will be evicted once exceeded
+  pub max_size: Option<u64>,
 }
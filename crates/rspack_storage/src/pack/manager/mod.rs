@@ -1,15 +1,19 @@
 mod queue;
 
-use std::sync::Arc;
+use std::sync::{
+  Arc,
+  atomic::{AtomicU64, Ordering},
+};
 
 use futures::future::join_all;
+use itertools::Itertools;
 use queue::TaskQueue;
 use rustc_hash::{FxHashMap as HashMap, FxHashSet as HashSet};
 use tokio::sync::{Mutex, oneshot, oneshot::Receiver};
 
 use super::{
   ScopeUpdates,
-  data::{PackOptions, PackScope, RootMeta, RootMetaState, RootOptions},
+  data::{PackCompression, PackOptions, PackScope, RootMeta, RootMetaState, RootOptions},
   strategy::{ScopeStrategy, WriteScopeResult},
 };
 use crate::{
@@ -19,6 +23,25 @@ use crate::{
 
 type ScopeMap = HashMap<String, PackScope>;
 
+/// Statistics of the root meta of a storage
+#[derive(Debug, Default)]
+pub struct StorageStats {
+  pub expire_time: u64,
+  pub last_used: u64,
+  pub scopes: Vec<String>,
+}
+
+/// Statistics of a scope, read without the pack contents
+#[derive(Debug, Default)]
+pub struct ScopeStats {
+  pub packs: usize,
+  pub keys: usize,
+  /// Total size of the packs in bytes
+  pub size: usize,
+  /// Compression of the packs recorded in the scope meta
+  pub compression: PackCompression,
+}
+
 #[derive(Debug)]
 pub struct ScopeManager {
   pub root_options: Arc<RootOptions>,
@@ -27,6 +50,8 @@ pub struct ScopeManager {
   pub scopes: Arc<Mutex<ScopeMap>>,
   pub root_meta: Arc<Mutex<RootMetaState>>,
   pub queue: TaskQueue,
+  /// Size of the versions evicted since last taken
+  pub evicted_size: Arc<AtomicU64>,
 }
 
 impl ScopeManager {
@@ -42,6 +67,7 @@ impl ScopeManager {
       scopes: Default::default(),
       queue: TaskQueue::new(),
       root_meta: Default::default(),
+      evicted_size: Default::default(),
     }
   }
 
@@ -51,6 +77,7 @@ impl ScopeManager {
     let root_meta = self.root_meta.clone();
     let pack_options = self.pack_options.clone();
     let root_options = self.root_options.clone();
+    let evicted_size = self.evicted_size.clone();
     let (tx, rx) = oneshot::channel();
     self.queue.add_task(Box::pin(async move {
       let mut scopes_lock = scopes.lock().await;
@@ -87,8 +114,9 @@ impl ScopeManager {
       let old_scopes = std::mem::take(&mut *scopes_lock);
       let res = save_scopes(old_scopes, &root_meta, strategy.as_ref(), &root_options).await;
       let _ = match res {
-        Ok(new_scopes) => {
+        Ok((new_scopes, removed_size)) => {
           let _ = std::mem::replace(&mut *scopes_lock, new_scopes);
+          evicted_size.fetch_add(removed_size, Ordering::Relaxed);
           tx.send(Ok(()))
         }
         Err(e) => tx.send(Err(e)),
@@ -143,7 +171,11 @@ impl ScopeManager {
         ValidateResult::Valid => {
           let mut scopes = self.scopes.lock().await;
           let scope = scopes.get_mut(name).expect("should have scope");
-          self.strategy.ensure_contents(scope).await?;
+          // clear scope if the packs are broken, e.g. truncated or checksum not match
+          if let Err(e) = self.strategy.ensure_contents(scope).await {
+            scope.clear();
+            return Err(e);
+          }
           let res = scope.get_contents();
           self.strategy.release_scope(scope).await?;
           Ok(res)
@@ -234,6 +266,52 @@ impl ScopeManager {
 
     Ok(root_meta.scopes.iter().cloned().collect())
   }
+
+  /// Take the size of the versions evicted by `max_size` since last call
+  pub fn take_evicted_size(&self) -> u64 {
+    self.evicted_size.swap(0, Ordering::Relaxed)
+  }
+
+  /// Read the statistics of the root meta, returns `None` if the storage does not exist
+  pub async fn stats(&self) -> Result<Option<StorageStats>> {
+    let Some(root_meta) = self.strategy.read_root_meta().await? else {
+      return Ok(None);
+    };
+    Ok(Some(StorageStats {
+      expire_time: root_meta.expire_time,
+      last_used: root_meta.last_used,
+      scopes: root_meta
+        .scopes
+        .into_iter()
+        .filter(|name| !name.is_empty())
+        .sorted()
+        .collect(),
+    }))
+  }
+
+  /// Read the statistics of a scope without loading the pack contents
+  pub async fn scope_stats(&self, name: &'static str) -> Result<ScopeStats> {
+    let mut scope = PackScope::new(
+      name,
+      self.strategy.get_path(name),
+      self.pack_options.clone(),
+    );
+    self.strategy.ensure_keys(&mut scope).await?;
+    let pack_metas = scope.meta.expect_value().packs.iter().flatten();
+    Ok(ScopeStats {
+      packs: pack_metas.clone().count(),
+      keys: scope
+        .packs
+        .expect_value()
+        .iter()
+        .flatten()
+        .filter(|pack| pack.keys.loaded())
+        .map(|pack| pack.keys.expect_value().len())
+        .sum(),
+      size: pack_metas.map(|meta| meta.size).sum(),
+      compression: scope.meta.expect_value().compression,
+    })
+  }
 }
 
 #[tracing::instrument("Cache::Storage::update_scopes", skip_all)]
@@ -282,7 +360,7 @@ async fn save_scopes(
   root_meta: &RootMeta,
   strategy: &dyn ScopeStrategy,
   root_options: &RootOptions,
-) -> Result<ScopeMap> {
+) -> Result<(ScopeMap, u64)> {
   scopes.retain(|_, scope| scope.loaded());
 
   strategy.before_all(&mut scopes).await?;
@@ -317,9 +395,9 @@ async fn save_scopes(
   strategy.write_root_meta(root_meta).await?;
   strategy.merge_changed(changed).await?;
   strategy.after_all(&mut scopes).await?;
-  strategy.clean(root_meta, &scopes, root_options).await?;
+  let removed_size = strategy.clean(root_meta, &scopes, root_options).await?;
 
-  Ok(scopes.into_iter().collect())
+  Ok((scopes.into_iter().collect(), removed_size))
 }
 
 #[cfg(test)]
@@ -371,10 +449,12 @@ mod tests {
       expire: 60000,
       root: root.parent().expect("should get parent").to_path_buf(),
       clean: true,
+      max_size: None,
     });
     let pack_options = Arc::new(PackOptions {
       bucket_size: 10,
       pack_size: 500,
+      compression: Default::default(),
     });
 
     let strategy = Arc::new(SplitPackStrategy::new(
@@ -425,10 +505,12 @@ mod tests {
       expire: 60000,
       root: root.parent().expect("should get parent").to_path_buf(),
       clean: true,
+      max_size: None,
     });
     let pack_options = Arc::new(PackOptions {
       bucket_size: 10,
       pack_size: 500,
+      compression: Default::default(),
     });
 
     let strategy = Arc::new(SplitPackStrategy::new(
@@ -498,11 +580,13 @@ mod tests {
       expire: 60000,
       root: root.parent().expect("should get parent").to_path_buf(),
       clean: true,
+      max_size: None,
     });
     let pack_options = Arc::new(PackOptions {
       // different bucket size
       bucket_size: 100,
       pack_size: 500,
+      compression: Default::default(),
     });
 
     let strategy = Arc::new(SplitPackStrategy::new(
@@ -545,10 +629,12 @@ mod tests {
       expire: 60000,
       root: root.parent().expect("should get parent").to_path_buf(),
       clean: true,
+      max_size: None,
     });
     let pack_options = Arc::new(PackOptions {
       bucket_size: 100,
       pack_size: 500,
+      compression: Default::default(),
     });
 
     let strategy = Arc::new(SplitPackStrategy::new(
@@ -7,20 +7,27 @@ use std::{
   sync::{Arc, Mutex},
 };
 
+pub use data::PackCompression;
 use data::{PackOptions, RootOptions};
 use manager::ScopeManager;
+pub use manager::{ScopeStats, StorageStats};
 use rspack_paths::AssertUtf8;
 use rustc_hash::FxHashMap as HashMap;
 use strategy::{ScopeUpdate, SplitPackStrategy};
 use tokio::sync::oneshot::Receiver;
 
-use crate::{FileSystem, ItemKey, ItemPairs, ItemValue, Storage, error::Result};
+use crate::{
+  FileSystem, ItemKey, ItemPairs, ItemValue, Storage,
+  error::{Error, ErrorType, Result},
+};
 
 pub type ScopeUpdates = HashMap<&'static str, ScopeUpdate>;
 #[derive(Debug)]
 pub struct PackStorage {
   pub manager: ScopeManager,
   pub updates: Mutex<ScopeUpdates>,
+  /// Whether the storage is created by `new_load_only`
+  load_only: bool,
 }
 
 pub struct PackStorageOptions {
@@ -29,13 +36,25 @@ pub struct PackStorageOptions {
   pub fs: Arc<dyn FileSystem>,
   pub bucket_size: usize,
   pub pack_size: usize,
+  pub compression: PackCompression,
   pub expire: u64,
   pub version: String,
   pub clean: bool,
+  pub max_size: Option<u64>,
   pub fresh_generation: Option<usize>,
   pub release_generation: Option<usize>,
 }
 
+/// Options of a pack storage which only loads the version under root
+pub struct LoadOnlyPackStorageOptions {
+  pub root: PathBuf,
+  pub fs: Arc<dyn FileSystem>,
+  pub bucket_size: usize,
+  pub pack_size: usize,
+  pub compression: PackCompression,
+  pub version: String,
+}
+
 impl PackStorage {
   pub fn new(options: PackStorageOptions) -> Self {
     Self {
@@ -44,10 +63,12 @@ impl PackStorage {
           root: options.root.clone().assert_utf8(),
           expire: options.expire,
           clean: options.clean,
+          max_size: options.max_size,
         }),
         Arc::new(PackOptions {
           bucket_size: options.bucket_size,
           pack_size: options.pack_size,
+          compression: options.compression,
         }),
         Arc::new(SplitPackStrategy::new(
           options.root.join(&options.version).assert_utf8(),
@@ -58,8 +79,63 @@ impl PackStorage {
         )),
       ),
       updates: Default::default(),
+      load_only: false,
     }
   }
+
+  /// Create a storage which never writes to root, e.g. a read-only directory
+  ///
+  /// It does not recover the lock files left by an unfinished save, loading a locked
+  /// version fails instead. Saving is not supported and `reset` does nothing.
+  pub fn new_load_only(options: LoadOnlyPackStorageOptions) -> Self {
+    Self {
+      manager: ScopeManager::new(
+        Arc::new(RootOptions {
+          root: options.root.clone().assert_utf8(),
+          expire: 0,
+          clean: false,
+          max_size: None,
+        }),
+        Arc::new(PackOptions {
+          bucket_size: options.bucket_size,
+          pack_size: options.pack_size,
+          compression: options.compression,
+        }),
+        Arc::new(SplitPackStrategy::new_load_only(
+          options.root.join(&options.version).assert_utf8(),
+          options.fs,
+        )),
+      ),
+      updates: Default::default(),
+      load_only: true,
+    }
+  }
+
+  /// Read the statistics of the root meta, returns `None` if the storage does not exist
+  pub async fn stats(&self) -> Result<Option<StorageStats>> {
+    self.manager.stats().await
+  }
+
+  /// Read the statistics of a scope without loading the pack contents
+  pub async fn scope_stats(&self, name: &'static str) -> Result<ScopeStats> {
+    self.manager.scope_stats(name).await
+  }
+}
+
+/// Remove the unknown and expired versions under the root directory of the storages,
+/// following the same rules as the cleaning after saving, returns the removed versions.
+pub async fn remove_expired_versions(
+  root: PathBuf,
+  fs: Arc<dyn FileSystem>,
+) -> Result<Vec<String>> {
+  let root = root.assert_utf8();
+  let root_options = RootOptions {
+    root: root.clone(),
+    expire: 0,
+    clean: true,
+    max_size: None,
+  };
+  Ok(strategy::remove_expired_versions(&root, &root_options, fs).await?)
 }
 
 #[async_trait::async_trait]
@@ -78,6 +154,13 @@ impl Storage for PackStorage {
     scope_update.insert(key.to_vec(), None);
   }
   fn trigger_save(&self) -> Result<Receiver<Result<()>>> {
+    if self.load_only {
+      return Err(Error::from_reason(
+        Some(ErrorType::Save),
+        None,
+        "can not save a load-only storage".to_string(),
+      ));
+    }
     self.manager.save(std::mem::take(
       &mut *self.updates.lock().expect("should get lock"),
     ))
@@ -88,4 +171,7 @@ impl Storage for PackStorage {
   async fn scopes(&self) -> Result<Vec<String>> {
     self.manager.scopes().await
   }
+  fn take_evicted_size(&self) -> u64 {
+    self.manager.take_evicted_size()
+  }
 }
@@ -3,7 +3,7 @@ mod split;
 use async_trait::async_trait;
 use rspack_paths::{Utf8Path, Utf8PathBuf};
 use rustc_hash::{FxHashMap as HashMap, FxHashSet as HashSet};
-pub use split::SplitPackStrategy;
+pub use split::{SplitPackStrategy, remove_expired_versions};
 
 use super::data::{
   Pack, PackContents, PackFileMeta, PackGenerations, PackKeys, PackOptions, PackScope, RootMeta,
@@ -38,12 +38,13 @@ pub trait RootStrategy {
   async fn read_root_meta(&self) -> Result<Option<RootMeta>>;
   async fn write_root_meta(&self, root_meta: &RootMeta) -> Result<()>;
   async fn validate_root(&self, root_meta: &RootMeta) -> Result<ValidateResult>;
+  /// Remove the unused files under the root, returns the size of the evicted versions in bytes
   async fn clean(
     &self,
     root_meta: &RootMeta,
     scopes: &HashMap<String, PackScope>,
     root_options: &RootOptions,
-  ) -> Result<()>;
+  ) -> Result<u64>;
   async fn reset(&self);
 }
 
@@ -56,7 +57,11 @@ pub struct PackMainContents {
 #[async_trait]
 pub trait PackReadStrategy {
   async fn read_pack_keys(&self, path: &Utf8Path) -> Result<Option<PackKeys>>;
-  async fn read_pack_contents(&self, path: &Utf8Path) -> Result<Option<PackMainContents>>;
+  async fn read_pack_contents(
+    &self,
+    path: &Utf8Path,
+    options: &PackOptions,
+  ) -> Result<Option<PackMainContents>>;
 }
 
 #[async_trait]
@@ -75,7 +80,7 @@ pub trait PackWriteStrategy {
     options: &PackOptions,
     packs: Vec<(PackFileMeta, Pack)>,
   ) -> Result<UpdatePacksResult>;
-  async fn write_pack(&self, pack: &Pack) -> Result<()>;
+  async fn write_pack(&self, pack: &Pack, options: &PackOptions) -> Result<()>;
 }
 
 #[async_trait]
@@ -1,6 +1,7 @@
 use std::sync::Arc;
 
 use futures::future::join_all;
+use itertools::Itertools;
 use rspack_paths::{Utf8Path, Utf8PathBuf};
 use rustc_hash::{FxHashMap as HashMap, FxHashSet as HashSet};
 use tokio::task::JoinError;
@@ -331,22 +332,22 @@ async fn try_remove_version(
   version: &str,
   dir: &Utf8Path,
   fs: Arc<dyn FileSystem>,
-) -> BatchFSResult<()> {
+) -> BatchFSResult<bool> {
   // do not remove hidden dirs and lock files
   if version.starts_with(".") || version.contains(".lock") {
-    return Ok(());
+    return Ok(false);
   }
 
   // do not remove files
   if !(fs.metadata(dir).await?.is_directory) {
-    return Ok(());
+    return Ok(false);
   }
 
   // remove unknown directories
   let meta = RootMeta::get_path(dir);
   if !fs.exists(&meta).await? {
     fs.remove_dir(dir).await?;
-    return Ok(());
+    return Ok(true);
   }
 
   // remove direcotires of expired versions
@@ -362,17 +363,18 @@ async fn try_remove_version(
 
   if current > expire_time {
     fs.remove_dir(dir).await?;
-    Ok(())
+    Ok(true)
   } else {
-    Ok(())
+    Ok(false)
   }
 }
 
+/// Remove the unknown and expired versions except the current one, returns the removed versions.
 pub async fn remove_expired_versions(
   root: &Utf8Path,
   root_options: &RootOptions,
   fs: Arc<dyn FileSystem>,
-) -> BatchFSResult<()> {
+) -> BatchFSResult<Vec<String>> {
   let dirs = fs.read_dir(&root_options.root).await?;
   let tasks = dirs.into_iter().filter_map(|version| {
     let version_dir = root_options.root.join(&version);
@@ -381,7 +383,9 @@ pub async fn remove_expired_versions(
     } else {
       let fs = fs.clone();
       Some(tokio::spawn(async move {
-        try_remove_version(&version, &version_dir, fs).await
+        try_remove_version(&version, &version_dir, fs)
+          .await
+          .map(|removed| removed.then_some(version))
       }))
     }
   });
@@ -393,5 +397,122 @@ pub async fn remove_expired_versions(
       .into_iter()
       .collect::<Result<Vec<_>, JoinError>>(),
   )
-  .map(|_| ())
+  .map(|removed| removed.into_iter().flatten().sorted().collect())
+}
+
+async fn get_dir_size(dir: &Utf8Path, fs: Arc<dyn FileSystem>) -> BatchFSResult<u64> {
+  let mut size = 0;
+  for file in walk_dir(dir, fs.clone()).await? {
+    size += fs.metadata(&file).await?.size;
+  }
+  Ok(size)
+}
+
+/// The size record is written with a fixed width so that it can count its own length.
+const SIZE_RECORD_WIDTH: usize = 20;
+
+fn get_size_path(dir: &Utf8Path) -> Utf8PathBuf {
+  dir.join("storage_size")
+}
+
+/// Read the size recorded by the last save of the version, the directory will be walked
+/// if the version was saved without a size budget.
+async fn read_version_size(dir: &Utf8Path, fs: Arc<dyn FileSystem>) -> BatchFSResult<u64> {
+  let size_path = get_size_path(dir);
+  if fs.exists(&size_path).await? {
+    let mut reader = fs.read_file(&size_path).await?;
+    if let Ok(size) = reader.read_line().await?.parse::<u64>() {
+      return Ok(size);
+    }
+  }
+  get_dir_size(dir, fs).await
+}
+
+/// Record the size of the version including the record itself, returns the recorded size.
+async fn record_version_size(dir: &Utf8Path, fs: Arc<dyn FileSystem>) -> BatchFSResult<u64> {
+  let size_path = get_size_path(dir);
+  fs.remove_file(&size_path).await?;
+  // the record is written as a line
+  let size = get_dir_size(dir, fs.clone()).await? + SIZE_RECORD_WIDTH as u64 + 1;
+  let mut writer = fs.write_file(&size_path).await?;
+  writer
+    .write_line(format!("{size:0SIZE_RECORD_WIDTH$}").as_str())
+    .await?;
+  writer.flush().await?;
+  Ok(size)
+}
+
+/// A version is being written by another process while it holds a lock file.
+pub async fn is_version_locked(dir: &Utf8Path, fs: Arc<dyn FileSystem>) -> FSResult<bool> {
+  for lock in ["move.lock", "remove.lock"] {
+    if fs.exists(&dir.join(lock)).await? {
+      return Ok(true);
+    }
+  }
+  Ok(false)
+}
+
+async fn read_last_used(dir: &Utf8Path, fs: Arc<dyn FileSystem>) -> FSResult<u64> {
+  let meta = RootMeta::get_path(dir);
+  if !fs.exists(&meta).await? {
+    return Ok(0);
+  }
+  let mut reader = fs.read_file(&meta).await?;
+  // skip expire time and scopes
+  reader.read_line().await?;
+  reader.read_line().await?;
+  Ok(reader.read_line().await?.parse::<u64>().unwrap_or_default())
+}
+
+/// Remove the least recently used versions until the total size of the versions fits
+/// `max_size` of root options, the current version and the versions locked by other
+/// processes will never be removed.
+///
+/// The size of the current version is recorded so that later saves of other versions
+/// do not need to walk its directory again.
+///
+/// Returns the size of the removed files in bytes.
+pub async fn remove_exceeded_versions(
+  root: &Utf8Path,
+  root_options: &RootOptions,
+  fs: Arc<dyn FileSystem>,
+) -> BatchFSResult<u64> {
+  let Some(max_size) = root_options.max_size else {
+    // the recorded size will be outdated after this save
+    fs.remove_file(&get_size_path(root)).await?;
+    return Ok(0);
+  };
+
+  let mut total_size = record_version_size(root, fs.clone()).await?;
+  let mut candidates = vec![];
+  for version in fs.read_dir(&root_options.root).await? {
+    // skip hidden dirs and lock files
+    if version.starts_with(".") || version.contains(".lock") {
+      continue;
+    }
+    let version_dir = root_options.root.join(&version);
+    if version_dir == root || !(fs.metadata(&version_dir).await?.is_directory) {
+      continue;
+    }
+    let size = read_version_size(&version_dir, fs.clone()).await?;
+    total_size += size;
+    if !is_version_locked(&version_dir, fs.clone()).await? {
+      let last_used = read_last_used(&version_dir, fs.clone()).await?;
+      candidates.push((last_used, version_dir, size));
+    }
+  }
+
+  candidates.sort_unstable_by(|a, b| a.0.cmp(&b.0).then_with(|| a.1.cmp(&b.1)));
+
+  let mut removed_size = 0;
+  for (_, version_dir, size) in candidates {
+    if total_size <= max_size {
+      break;
+    }
+    fs.remove_dir(&version_dir).await?;
+    total_size -= size;
+    removed_size += size;
+  }
+
+  Ok(removed_size)
 }
@@ -11,9 +11,10 @@ use std::{
   sync::Arc,
 };
 
+pub use handle_file::remove_expired_versions;
 use handle_file::{
-  recovery_move_lock, recovery_remove_lock, remove_expired_versions, remove_unused_scope_files,
-  remove_unused_scopes,
+  is_version_locked, recovery_move_lock, recovery_remove_lock, remove_exceeded_versions,
+  remove_unused_scope_files, remove_unused_scopes,
 };
 use itertools::Itertools;
 use rspack_paths::{Utf8Path, Utf8PathBuf};
@@ -23,7 +24,7 @@ use util::get_name;
 use super::{RootStrategy, ScopeStrategy};
 use crate::{
   FileSystem,
-  error::{Result, ValidateResult},
+  error::{Error, ErrorType, Result, ValidateResult},
   fs::{FSError, FSOperation},
   pack::data::{
     PackContents, PackKeys, PackScope, RootMeta, RootMetaFrom, RootOptions, current_time,
@@ -34,7 +35,8 @@ use crate::{
 pub struct SplitPackStrategy {
   pub fs: Arc<dyn FileSystem>,
   pub root: Arc<Utf8PathBuf>,
-  pub temp_root: Arc<Utf8PathBuf>,
+  /// Directory to write files before moving them to root, `None` for a load-only strategy
+  pub temp_root: Option<Arc<Utf8PathBuf>>,
   pub fresh_generation: Option<usize>,
   pub release_generation: Option<usize>,
 }
@@ -50,12 +52,31 @@ impl SplitPackStrategy {
     Self {
       fs,
       root: Arc::new(root),
-      temp_root: Arc::new(temp_root),
+      temp_root: Some(Arc::new(temp_root)),
       fresh_generation,
       release_generation,
     }
   }
 
+  /// Create a strategy which only loads from root, it never recovers the lock files or
+  /// writes anything to root.
+  pub fn new_load_only(root: Utf8PathBuf, fs: Arc<dyn FileSystem>) -> Self {
+    Self {
+      fs,
+      root: Arc::new(root),
+      temp_root: None,
+      fresh_generation: None,
+      release_generation: None,
+    }
+  }
+
+  pub fn temp_root(&self) -> &Utf8Path {
+    self
+      .temp_root
+      .as_deref()
+      .expect("should not write with a load-only strategy")
+  }
+
   pub async fn get_pack_hash(
     &self,
     path: &Utf8Path,
@@ -78,8 +99,19 @@ impl SplitPackStrategy {
 #[async_trait::async_trait]
 impl RootStrategy for SplitPackStrategy {
   async fn before_load(&self) -> Result<()> {
-    recovery_remove_lock(&self.root, &self.temp_root, self.fs.clone()).await?;
-    recovery_move_lock(&self.root, &self.temp_root, self.fs.clone()).await?;
+    let Some(temp_root) = &self.temp_root else {
+      // the files of a locked root are incomplete, and they can only be recovered by writing
+      if is_version_locked(&self.root, self.fs.clone()).await? {
+        return Err(Error::from_reason(
+          Some(ErrorType::Load),
+          None,
+          format!("storage `{}` is locked by an unfinished save", self.root),
+        ));
+      }
+      return Ok(());
+    };
+    recovery_remove_lock(&self.root, temp_root, self.fs.clone()).await?;
+    recovery_move_lock(&self.root, temp_root, self.fs.clone()).await?;
     Ok(())
   }
   async fn read_root_meta(&self) -> Result<Option<RootMeta>> {
@@ -102,9 +134,12 @@ impl RootStrategy for SplitPackStrategy {
       .split(',')
       .map(|s| s.to_string())
       .collect::<HashSet<_>>();
+    // root meta written by old versions has no last used time
+    let last_used = reader.read_line().await?.parse::<u64>().unwrap_or_default();
 
     Ok(Some(RootMeta {
       expire_time,
+      last_used,
       scopes,
       from: RootMetaFrom::File,
     }))
@@ -121,6 +156,10 @@ impl RootStrategy for SplitPackStrategy {
       .write_line(root_meta.scopes.iter().join(",").as_str())
       .await?;
 
+    writer
+      .write_line(root_meta.last_used.to_string().as_str())
+      .await?;
+
     writer.flush().await?;
 
     Ok(())
@@ -143,9 +182,9 @@ impl RootStrategy for SplitPackStrategy {
     root_meta: &RootMeta,
     scopes: &HashMap<String, PackScope>,
     root_options: &RootOptions,
-  ) -> Result<()> {
+  ) -> Result<u64> {
     if !root_options.clean {
-      return Ok(());
+      return Ok(0);
     }
 
     let _ = tokio::try_join!(
@@ -154,10 +193,18 @@ impl RootStrategy for SplitPackStrategy {
       remove_expired_versions(&self.root, root_options, self.fs.clone())
     );
 
-    Ok(())
+    // evict after the expired versions are removed so that they are not counted
+    let removed_size = remove_exceeded_versions(&self.root, root_options, self.fs.clone())
+      .await
+      .unwrap_or_default();
+
+    Ok(removed_size)
   }
 
   async fn reset(&self) {
+    if self.temp_root.is_none() {
+      return;
+    }
     let _ = self.fs.remove_dir(&self.root).await;
   }
 }
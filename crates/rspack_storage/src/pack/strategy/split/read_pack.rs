@@ -8,7 +8,7 @@ use crate::{
   FSError, FSOperation,
   error::Result,
   pack::{
-    data::PackKeys,
+    data::{PackCompression, PackKeys, PackOptions},
     strategy::{PackMainContents, PackReadStrategy},
   },
 };
@@ -50,7 +50,11 @@ impl PackReadStrategy for SplitPackStrategy {
     Ok(Some(keys))
   }
 
-  async fn read_pack_contents(&self, path: &Utf8Path) -> Result<Option<PackMainContents>> {
+  async fn read_pack_contents(
+    &self,
+    path: &Utf8Path,
+    options: &PackOptions,
+  ) -> Result<Option<PackMainContents>> {
     if !self.fs.exists(path).await? {
       return Ok(None);
     }
@@ -106,8 +110,37 @@ impl PackReadStrategy for SplitPackStrategy {
     reader.skip(total_key_length).await?;
 
     let mut contents = vec![];
-    for len in content_lengths {
-      contents.push(Arc::new(reader.read(len).await?));
+    if matches!(options.compression, PackCompression::None) {
+      for len in content_lengths {
+        contents.push(Arc::new(reader.read(len).await?));
+      }
+    } else {
+      let total_content_length = content_lengths.iter().sum::<usize>();
+      let blob = options
+        .compression
+        .decompress(&reader.read_to_end().await?, total_content_length)
+        .map_err(|e| {
+          FSError::from_message(
+            path,
+            FSOperation::Read,
+            format!("decompress pack contents failed: {e}"),
+          )
+        })?;
+      if blob.len() != total_content_length {
+        return Err(
+          FSError::from_message(
+            path,
+            FSOperation::Read,
+            "pack contents length not match".to_string(),
+          )
+          .into(),
+        );
+      }
+      let mut offset = 0;
+      for len in content_lengths {
+        contents.push(Arc::new(blob[offset..offset + len].to_vec()));
+        offset += len;
+      }
     }
 
     Ok(Some(PackMainContents {
@@ -125,12 +158,21 @@ mod tests {
 
   use crate::{
     error::Result,
-    pack::strategy::{
-      PackReadStrategy, ScopeReadStrategy, SplitPackStrategy,
-      split::util::test_pack_utils::{clean_strategy, create_strategies, mock_pack_file},
+    pack::{
+      data::{PackCompression, PackOptions},
+      strategy::{
+        PackReadStrategy, ScopeReadStrategy, SplitPackStrategy,
+        split::util::test_pack_utils::{clean_strategy, create_strategies, mock_pack_file},
+      },
     },
   };
 
+  const OPTIONS: PackOptions = PackOptions {
+    bucket_size: 1,
+    pack_size: 100,
+    compression: PackCompression::None,
+  };
+
   async fn test_read_keys_non_exists(strategy: &SplitPackStrategy) -> Result<()> {
     let non_exists_keys = strategy
       .read_pack_keys(&strategy.get_path("/non_exists_path"))
@@ -141,7 +183,7 @@ mod tests {
 
   async fn test_read_contents_non_exists(strategy: &SplitPackStrategy) -> Result<()> {
     let non_exists_contents = strategy
-      .read_pack_contents(&strategy.get_path("/non_exists_path"))
+      .read_pack_contents(&strategy.get_path("/non_exists_path"), &OPTIONS)
       .await?;
     assert!(non_exists_contents.is_none());
     Ok(())
@@ -162,7 +204,7 @@ mod tests {
 
   async fn test_read_contents(path: &Utf8PathBuf, strategy: &SplitPackStrategy) -> Result<()> {
     let contents = strategy
-      .read_pack_contents(path)
+      .read_pack_contents(path, &OPTIONS)
       .await?
       .unwrap_or_default()
       .contents
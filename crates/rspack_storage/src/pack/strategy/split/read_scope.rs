@@ -5,12 +5,15 @@ use futures::{TryFutureExt, future::join_all};
 use itertools::Itertools;
 use rspack_paths::{Utf8Path, Utf8PathBuf};
 
-use super::{SplitPackStrategy, util::get_indexed_packs};
+use super::{
+  SplitPackStrategy,
+  util::{get_checksum, get_indexed_packs},
+};
 use crate::{
   FileSystem,
   error::{Error, ErrorType, Result},
   pack::{
-    data::{Pack, PackFileMeta, PackKeys, PackScope, ScopeMeta},
+    data::{Pack, PackCompression, PackFileMeta, PackKeys, PackScope, ScopeMeta},
     strategy::{PackMainContents, PackReadStrategy, ScopeReadStrategy},
   },
 };
@@ -127,6 +130,17 @@ async fn read_scope_meta(
   let bucket_size = option_items[0];
   let pack_size = option_items[1];
   let generation = option_items[2];
+  // scope meta without compression is written by the uncompressed storage
+  let compression = match option_items.get(3) {
+    Some(id) => PackCompression::from_id(*id).ok_or_else(|| {
+      Error::from_reason(
+        Some(ErrorType::Load),
+        Some(scope),
+        format!("unknown compression id `{id}`"),
+      )
+    })?,
+    None => PackCompression::None,
+  };
 
   let mut packs = vec![];
   for _ in 0..bucket_size {
@@ -138,7 +152,7 @@ async fn read_scope_meta(
         .filter(|i| !i.is_empty())
         .map(|i| i.split(",").collect::<Vec<_>>())
         .map(|i| {
-          if i.len() < 3 {
+          if i.len() < 4 {
             Err(Error::from_reason(
               Some(ErrorType::Load),
               Some(scope),
@@ -163,6 +177,7 @@ async fn read_scope_meta(
                 )
               })?,
               wrote: true,
+              checksum: i.get(4).map(|c| c.to_string()).unwrap_or_default(),
             })
           }
         })
@@ -182,6 +197,7 @@ async fn read_scope_meta(
     path: path.to_path_buf(),
     bucket_size,
     pack_size,
+    compression,
     generation,
     packs,
   }))
@@ -248,11 +264,28 @@ async fn read_contents(
   let (pack_indexes, packs) = get_indexed_packs(scope, Some(&read_contents_filter));
   let tasks = packs
     .into_iter()
-    .map(|i| {
+    .map(|(pack_meta, pack)| {
       let strategy = strategy.to_owned();
-      let path = i.1.path.clone();
-      tokio::spawn(async move { strategy.read_pack_contents(&path).await })
-        .map_err(|e| Error::from_error(Some(ErrorType::Load), Some(scope.name), Box::new(e)))
+      let options = scope.options.clone();
+      let path = pack.path.clone();
+      let checksum = pack_meta.checksum.clone();
+      let scope_name = scope.name;
+      tokio::spawn(async move {
+        let contents = strategy.read_pack_contents(&path, &options).await?;
+        // detect truncated or corrupted pack files before they are used
+        if let Some(contents) = &contents
+          && !checksum.is_empty()
+          && get_checksum(&contents.contents) != checksum
+        {
+          return Err(Error::from_reason(
+            Some(ErrorType::Load),
+            Some(scope_name),
+            format!("checksum of pack `{path}` not match"),
+          ));
+        }
+        Ok(contents)
+      })
+      .map_err(|e| Error::from_error(Some(ErrorType::Load), Some(scope.name), Box::new(e)))
     })
     .collect_vec();
   let pack_contents = join_all(tasks).await.into_iter().process_results(|iter| {
@@ -286,11 +319,12 @@ mod tests {
     FileSystem,
     error::Result,
     pack::{
-      data::{PackOptions, PackScope, ScopeMeta},
+      data::{PackCompression, PackOptions, PackScope, ScopeMeta},
       strategy::{
-        ScopeReadStrategy, SplitPackStrategy,
+        ScopeReadStrategy, ScopeWriteStrategy, SplitPackStrategy,
         split::util::test_pack_utils::{
-          clean_strategy, create_strategies, mock_pack_file, mock_scope_meta_file,
+          UpdateVal, clean_strategy, create_strategies, mock_pack_file, mock_scope_meta_file,
+          mock_updates, save_scope,
         },
       },
     },
@@ -367,6 +401,64 @@ mod tests {
     Ok(())
   }
 
+  async fn test_checksum_not_match(strategy: &SplitPackStrategy) -> Result<()> {
+    let options = Arc::new(PackOptions {
+      bucket_size: 1,
+      pack_size: 2000,
+      compression: PackCompression::Zstd,
+    });
+    let path = strategy.get_path("checksum_scope");
+    let mut scope = PackScope::empty("checksum_scope", path.clone(), options.clone());
+    strategy
+      .update_scope(
+        &mut scope,
+        mock_updates(0, 10, 16, UpdateVal::Value("val".into())),
+      )
+      .await?;
+    save_scope(&mut scope, strategy).await?;
+
+    let mut scope = PackScope::new("checksum_scope", path, options);
+    strategy.ensure_keys(&mut scope).await?;
+    assert_eq!(
+      scope
+        .meta
+        .expect_value()
+        .packs
+        .iter()
+        .flatten()
+        .filter(|meta| meta.checksum.is_empty())
+        .count(),
+      0
+    );
+
+    // flip the last byte of the compressed contents
+    let pack_path = scope.packs.expect_value()[0][0].path.clone();
+    let mut content = strategy
+      .fs
+      .read_file(&pack_path)
+      .await?
+      .read_to_end()
+      .await?;
+    let last = content.last_mut().expect("should have content");
+    *last = !*last;
+    let mut writer = strategy.fs.write_file(&pack_path).await?;
+    writer.write_all(&content).await?;
+    writer.flush().await?;
+
+    assert!(strategy.ensure_contents(&mut scope).await.is_err());
+    Ok(())
+  }
+
+  #[tokio::test]
+  #[cfg_attr(miri, ignore)]
+  async fn should_detect_broken_pack() -> Result<()> {
+    for strategy in create_strategies("read_scope_checksum") {
+      clean_strategy(&strategy).await;
+      test_checksum_not_match(&strategy).await?;
+    }
+    Ok(())
+  }
+
   #[tokio::test]
   #[cfg_attr(miri, ignore)]
   async fn should_read_scope() -> Result<()> {
@@ -375,6 +467,7 @@ mod tests {
       let options = Arc::new(PackOptions {
         bucket_size: 1,
         pack_size: 16,
+        compression: Default::default(),
       });
       let mut scope = PackScope::new(
         "scope_name",
@@ -2,6 +2,7 @@ use std::hash::Hasher;
 
 use itertools::Itertools;
 use rustc_hash::FxHasher;
+use xxhash_rust::xxh64::Xxh64;
 
 use crate::pack::data::{Pack, PackContents, PackFileMeta, PackKeys, PackScope};
 
@@ -64,6 +65,16 @@ pub fn get_name(keys: &PackKeys, _: &PackContents) -> String {
   format!("{:016x}", hasher.finish())
 }
 
+pub fn get_checksum(contents: &PackContents) -> String {
+  let mut hasher = Xxh64::new(0);
+  for content in contents {
+    hasher.update(&content.len().to_le_bytes());
+    hasher.update(content);
+  }
+
+  format!("{:016x}", hasher.digest())
+}
+
 pub fn choose_bucket(key: &[u8], total: &usize) -> usize {
   let num = key.iter().fold(0_usize, |acc, i| acc + *i as usize);
   num % total
@@ -230,7 +241,7 @@ pub mod test_pack_utils {
       .expect("should remove dir");
     strategy
       .fs
-      .remove_dir(&strategy.temp_root)
+      .remove_dir(strategy.temp_root())
       .await
       .expect("should remove dir");
   }
@@ -251,7 +262,7 @@ pub mod test_pack_utils {
     prepare_scope(
       &scope.path,
       &strategy.root,
-      &strategy.temp_root,
+      strategy.temp_root(),
       strategy.fs.clone(),
     )
     .await?;
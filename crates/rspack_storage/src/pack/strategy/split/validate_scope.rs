@@ -21,6 +21,10 @@ impl ScopeValidateStrategy for SplitPackStrategy {
       return Ok(ValidateResult::invalid("`options.packSize` changed"));
     }
 
+    if meta.compression != scope.options.compression {
+      return Ok(ValidateResult::invalid("`options.compression` changed"));
+    }
+
     return Ok(ValidateResult::Valid);
   }
 
@@ -106,6 +110,7 @@ mod tests {
     let same_options = Arc::new(PackOptions {
       bucket_size: 10,
       pack_size: 100,
+      compression: Default::default(),
     });
     let mut scope = PackScope::new("scope_name", scope_path, same_options);
     strategy.ensure_meta(&mut scope).await?;
@@ -122,6 +127,7 @@ mod tests {
     let bucket_changed_options = Arc::new(PackOptions {
       bucket_size: 1,
       pack_size: 100,
+      compression: Default::default(),
     });
     let mut scope = PackScope::new(
       "scope_name",
@@ -142,6 +148,7 @@ mod tests {
     let max_size_changed_options = Arc::new(PackOptions {
       bucket_size: 10,
       pack_size: 99,
+      compression: Default::default(),
     });
     let mut scope = PackScope::new(
       "scope_name",
@@ -212,6 +219,7 @@ mod tests {
       let pack_options = Arc::new(PackOptions {
         bucket_size: 10,
         pack_size: 100,
+        compression: Default::default(),
       });
       mock_scope_meta_file(
         &ScopeMeta::get_path(&scope_path),
@@ -244,6 +252,7 @@ mod tests {
       let pack_options = Arc::new(PackOptions {
         bucket_size: 10,
         pack_size: 100,
+        compression: Default::default(),
       });
       let mut mock_scope = PackScope::empty("scope_name", scope_path.clone(), pack_options.clone());
       let updates = mock_updates(0, 100, 30, UpdateVal::Value("val".to_string()));
@@ -255,7 +264,7 @@ mod tests {
       prepare_scope(
         &mock_scope.path,
         &strategy.root,
-        &strategy.temp_root,
+        strategy.temp_root(),
         strategy.fs.clone(),
       )
       .await
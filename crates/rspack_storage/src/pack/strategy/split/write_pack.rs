@@ -12,8 +12,11 @@ use crate::{
   error::Result,
   pack::{
     ScopeUpdate,
-    data::{Pack, PackFileMeta, PackOptions},
-    strategy::{PackReadStrategy, PackWriteStrategy, UpdatePacksResult, split::util::get_name},
+    data::{Pack, PackCompression, PackFileMeta, PackOptions},
+    strategy::{
+      PackReadStrategy, PackWriteStrategy, UpdatePacksResult,
+      split::util::{get_checksum, get_name},
+    },
   },
 };
 
@@ -136,6 +139,7 @@ impl PackWriteStrategy for SplitPackStrategy {
             .1
         })
         .collect_vec(),
+      options,
       self,
     )
     .await?;
@@ -172,8 +176,8 @@ impl PackWriteStrategy for SplitPackStrategy {
     })
   }
 
-  async fn write_pack(&self, pack: &Pack) -> Result<()> {
-    let path = redirect_to_path(&pack.path, &self.root, &self.temp_root)?;
+  async fn write_pack(&self, pack: &Pack, options: &PackOptions) -> Result<()> {
+    let path = redirect_to_path(&pack.path, &self.root, self.temp_root())?;
     let keys = pack.keys.expect_value();
     let contents = pack.contents.expect_value();
     let generations = &pack.generations;
@@ -216,8 +220,23 @@ impl PackWriteStrategy for SplitPackStrategy {
     }
 
     // contents blob
-    for content in contents {
-      writer.write(content).await?;
+    if matches!(options.compression, PackCompression::None) {
+      for content in contents {
+        writer.write(content).await?;
+      }
+    } else {
+      let blob = contents
+        .iter()
+        .flat_map(|c| c.iter().copied())
+        .collect_vec();
+      let compressed = options.compression.compress(&blob).map_err(|e| {
+        FSError::from_message(
+          &path,
+          FSOperation::Write,
+          format!("compress pack contents failed: {e}"),
+        )
+      })?;
+      writer.write(&compressed).await?;
     }
 
     writer.flush().await?;
@@ -228,6 +247,7 @@ impl PackWriteStrategy for SplitPackStrategy {
 
 async fn reload_released_packs(
   packs: Vec<Pack>,
+  options: &PackOptions,
   strategy: &SplitPackStrategy,
 ) -> Result<Vec<Pack>> {
   let (released_packs, memory_packs): (Vec<_>, Vec<_>) = packs
@@ -237,7 +257,7 @@ async fn reload_released_packs(
   let mut res = join_all(released_packs.into_iter().map(|mut pack| {
     let strategy = strategy.to_owned();
     async move {
-      match strategy.read_pack_contents(&pack.path).await {
+      match strategy.read_pack_contents(&pack.path, options).await {
         Ok(contents) => {
           if let Some(contents) = contents {
             pack.contents.set_value(contents.contents);
@@ -361,6 +381,7 @@ fn create_pack(dir: &Utf8Path, candidates: Vec<PackItemCandidate>) -> (PackFileM
   }
 
   let file_name = get_name(&keys, &contents);
+  let checksum = get_checksum(&contents);
   let mut new_pack = Pack::new(dir.join(&file_name));
   let latest_generation = *generations
     .iter()
@@ -376,6 +397,7 @@ fn create_pack(dir: &Utf8Path, candidates: Vec<PackItemCandidate>) -> (PackFileM
       size: new_pack.size(),
       wrote: false,
       generation: latest_generation,
+      checksum,
     },
     new_pack,
   )
@@ -391,9 +413,9 @@ mod tests {
   use crate::{
     error::Result,
     pack::{
-      data::{Pack, PackFileMeta, PackOptions},
+      data::{Pack, PackCompression, PackFileMeta, PackOptions},
       strategy::{
-        PackWriteStrategy, SplitPackStrategy, UpdatePacksResult,
+        PackReadStrategy, PackWriteStrategy, SplitPackStrategy, UpdatePacksResult,
         split::{
           handle_file::redirect_to_path,
           util::test_pack_utils::{UpdateVal, clean_strategy, create_strategies, mock_updates},
@@ -414,14 +436,23 @@ mod tests {
       Arc::new("val_2".as_bytes().to_vec()),
     ]);
     pack.generations = vec![1_usize, 2_usize];
-    strategy.write_pack(&pack).await?;
+    strategy
+      .write_pack(
+        &pack,
+        &PackOptions {
+          bucket_size: 1,
+          pack_size: 100,
+          compression: PackCompression::None,
+        },
+      )
+      .await?;
 
     let mut reader = strategy
       .fs
       .read_file(&redirect_to_path(
         &pack.path,
         &strategy.root,
-        &strategy.temp_root,
+        strategy.temp_root(),
       )?)
       .await?;
     assert_eq!(reader.read_line().await?, "5 5");
@@ -434,6 +465,43 @@ mod tests {
     Ok(())
   }
 
+  async fn test_write_compressed_pack(
+    strategy: &SplitPackStrategy,
+    compression: PackCompression,
+  ) -> Result<()> {
+    let options = PackOptions {
+      bucket_size: 1,
+      pack_size: 100,
+      compression,
+    };
+    let mut pack = Pack::new(strategy.root.join("write_compressed"));
+    pack.keys.set_value(vec![
+      Arc::new("key_1".as_bytes().to_vec()),
+      Arc::new("key_2".as_bytes().to_vec()),
+    ]);
+    pack.contents.set_value(vec![
+      Arc::new("val_1".repeat(10).as_bytes().to_vec()),
+      Arc::new("val_2".as_bytes().to_vec()),
+    ]);
+    pack.generations = vec![1_usize, 2_usize];
+    strategy.write_pack(&pack, &options).await?;
+
+    let path = redirect_to_path(&pack.path, &strategy.root, strategy.temp_root())?;
+    let mut reader = strategy.fs.read_file(&path).await?;
+    // contents lengths are the uncompressed ones
+    assert_eq!(reader.read_line().await?, "5 5");
+    assert_eq!(reader.read_line().await?, "50 5");
+    assert_eq!(reader.read_line().await?, "1 2");
+
+    let contents = strategy
+      .read_pack_contents(&path, &options)
+      .await?
+      .expect("should have contents");
+    assert_eq!(contents.contents, *pack.contents.expect_value());
+    assert_eq!(contents.generations, vec![1_usize, 2_usize]);
+    Ok(())
+  }
+
   fn update_packs(update_res: UpdatePacksResult) -> HashMap<PackFileMeta, Pack> {
     update_res
       .remain_packs
@@ -458,6 +526,7 @@ mod tests {
     let options = PackOptions {
       bucket_size: 1,
       pack_size: 2100,
+      compression: Default::default(),
     };
 
     // half pack
@@ -581,6 +650,8 @@ mod tests {
       clean_strategy(&strategy).await;
 
       test_write_pack(&strategy).await?;
+      test_write_compressed_pack(&strategy, PackCompression::Zstd).await?;
+      test_write_compressed_pack(&strategy, PackCompression::Lz4).await?;
     }
     Ok(())
   }
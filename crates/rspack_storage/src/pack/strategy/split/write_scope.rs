@@ -1,3 +1,5 @@
+use std::sync::Arc;
+
 use async_trait::async_trait;
 use futures::future::join_all;
 use itertools::Itertools;
@@ -16,7 +18,7 @@ use crate::{
   error::Result,
   fs::BatchFSError,
   pack::{
-    data::{Pack, PackScope},
+    data::{Pack, PackOptions, PackScope},
     strategy::{PackWriteStrategy, ScopeUpdate, ScopeWriteStrategy, WriteScopeResult},
   },
 };
@@ -24,7 +26,7 @@ use crate::{
 #[async_trait]
 impl ScopeWriteStrategy for SplitPackStrategy {
   async fn before_all(&self, scopes: &mut HashMap<String, PackScope>) -> Result<()> {
-    prepare_scope_dirs(scopes, &self.root, &self.temp_root, self.fs.clone()).await?;
+    prepare_scope_dirs(scopes, &self.root, self.temp_root(), self.fs.clone()).await?;
     Ok(())
   }
 
@@ -34,7 +36,7 @@ impl ScopeWriteStrategy for SplitPackStrategy {
       "remove.lock",
       &changed.wrote_files,
       &self.root,
-      &self.temp_root,
+      self.temp_root(),
       self.fs.clone(),
     )
     .await?;
@@ -46,20 +48,20 @@ impl ScopeWriteStrategy for SplitPackStrategy {
       "move.lock",
       &changed.wrote_files,
       &self.root,
-      &self.temp_root,
+      self.temp_root(),
       self.fs.clone(),
     )
     .await?;
     move_files(
       changed.wrote_files,
       &self.root,
-      &self.temp_root,
+      self.temp_root(),
       self.fs.clone(),
     )
     .await?;
     remove_lock("move.lock", &self.root, self.fs.clone()).await?;
 
-    self.fs.remove_dir(&self.temp_root).await?;
+    self.fs.remove_dir(self.temp_root()).await?;
     Ok(())
   }
 
@@ -251,7 +253,7 @@ impl ScopeWriteStrategy for SplitPackStrategy {
       .partition(|x| x.1.wrote);
 
     let (new_packs, new_pack_metas): (Vec<_>, Vec<_>) = new_pack_infos.into_iter().unzip();
-    let write_results = batch_write_packs(new_packs, self).await?;
+    let write_results = batch_write_packs(new_packs, scope.options.clone(), self).await?;
 
     let mut wrote_packs = wrote_pack_infos
       .into_iter()
@@ -288,7 +290,7 @@ impl ScopeWriteStrategy for SplitPackStrategy {
 
   async fn write_meta(&self, scope: &mut PackScope) -> Result<WriteScopeResult> {
     let meta = scope.meta.expect_value();
-    let path = redirect_to_path(&meta.path, &self.root, &self.temp_root)?;
+    let path = redirect_to_path(&meta.path, &self.root, self.temp_root())?;
     self
       .fs
       .ensure_dir(path.parent().expect("should have parent"))
@@ -299,8 +301,11 @@ impl ScopeWriteStrategy for SplitPackStrategy {
     writer
       .write_line(
         format!(
-          "{} {} {}",
-          meta.bucket_size, meta.pack_size, meta.generation
+          "{} {} {} {}",
+          meta.bucket_size,
+          meta.pack_size,
+          meta.generation,
+          meta.compression.id()
         )
         .as_str(),
       )
@@ -315,8 +320,8 @@ impl ScopeWriteStrategy for SplitPackStrategy {
             .iter()
             .map(|meta| {
               format!(
-                "{},{},{},{}",
-                meta.name, meta.hash, meta.size, meta.generation
+                "{},{},{},{},{}",
+                meta.name, meta.hash, meta.size, meta.generation, meta.checksum
               )
             })
             .join(" ")
@@ -334,16 +339,20 @@ impl ScopeWriteStrategy for SplitPackStrategy {
   }
 }
 
-async fn save_pack(pack: &Pack, strategy: &SplitPackStrategy) -> Result<String> {
+async fn save_pack(
+  pack: &Pack,
+  options: &PackOptions,
+  strategy: &SplitPackStrategy,
+) -> Result<String> {
   let keys = pack.keys.expect_value();
   let contents = pack.contents.expect_value();
   if keys.len() != contents.len() {
     panic!("pack keys and contents length not match");
   }
-  strategy.write_pack(pack).await?;
+  strategy.write_pack(pack, options).await?;
   let hash = strategy
     .get_pack_hash(
-      &redirect_to_path(&pack.path, &strategy.root, &strategy.temp_root)?,
+      &redirect_to_path(&pack.path, &strategy.root, strategy.temp_root())?,
       keys,
       contents,
     )
@@ -353,11 +362,17 @@ async fn save_pack(pack: &Pack, strategy: &SplitPackStrategy) -> Result<String>
 
 async fn batch_write_packs(
   packs: Vec<Pack>,
+  options: Arc<PackOptions>,
   strategy: &SplitPackStrategy,
 ) -> Result<Vec<(String, Pack)>> {
   let tasks = packs.into_iter().map(|pack| {
     let strategy = strategy.to_owned();
-    tokio::spawn(async move { save_pack(&pack, &strategy).await.map(|hash| (hash, pack)) })
+    let options = options.clone();
+    tokio::spawn(async move {
+      save_pack(&pack, &options, &strategy)
+        .await
+        .map(|hash| (hash, pack))
+    })
   });
 
   let res = BatchFSError::try_from_joined_result(
@@ -584,6 +599,7 @@ mod tests {
       let options = Arc::new(PackOptions {
         bucket_size: 1,
         pack_size: 36,
+        compression: Default::default(),
       });
       let mut scope = PackScope::empty(
         "scope_name",
@@ -604,6 +620,7 @@ mod tests {
       let options = Arc::new(PackOptions {
         bucket_size: 10,
         pack_size: 36,
+        compression: Default::default(),
       });
       let mut scope = PackScope::empty(
         "scope_name",
@@ -624,6 +641,7 @@ mod tests {
       let options = Arc::new(PackOptions {
         bucket_size: 1,
         pack_size: 2100,
+        compression: Default::default(),
       });
       let mut scope = PackScope::empty(
         "scope_name",
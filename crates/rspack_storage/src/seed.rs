@@ -0,0 +1,103 @@
+use std::sync::Arc;
+
+use rustc_hash::FxHashMap as HashMap;
+use tokio::sync::{Mutex, oneshot::Receiver};
+
+use crate::{ItemKey, ItemPairs, ItemValue, Storage, error::Result};
+
+/// Storage which starts from a read-only seed storage and writes to a local storage
+///
+/// The seed storage is only used when the local storage is empty, e.g. a fresh checkout
+/// with a cache directory produced by CI. In this case all of the scopes are loaded from
+/// the seed and copied to the local storage, so they will be persisted locally with the
+/// next save. The seed storage is never written.
+///
+/// The data from seed is validated by the caller as the local data, e.g. the build
+/// dependencies and snapshot of persistent cache, and the caller should `reset` the
+/// storage if the validation failed, which will drop the seed.
+#[derive(Debug)]
+pub struct SeedStorage {
+  seed: Arc<dyn Storage>,
+  local: Arc<dyn Storage>,
+  /// Whether the scopes are loaded from seed, `None` means not decided yet.
+  use_seed: Mutex<Option<bool>>,
+  /// The keys copied from seed to local, which are removed from local when `reset`.
+  seeded_keys: Mutex<HashMap<&'static str, Vec<ItemKey>>>,
+}
+
+impl SeedStorage {
+  pub fn new(seed: Arc<dyn Storage>, local: Arc<dyn Storage>) -> Self {
+    Self {
+      seed,
+      local,
+      use_seed: Default::default(),
+      seeded_keys: Default::default(),
+    }
+  }
+
+  async fn use_seed(&self) -> bool {
+    let mut use_seed = self.use_seed.lock().await;
+    if let Some(value) = *use_seed {
+      return value;
+    }
+    // mixing the scopes of different storages is not safe,
+    // so the seed is only used when there is nothing in local
+    let value = self
+      .local
+      .scopes()
+      .await
+      .map(|scopes| scopes.is_empty())
+      .unwrap_or_default();
+    *use_seed = Some(value);
+    value
+  }
+}
+
+#[async_trait::async_trait]
+impl Storage for SeedStorage {
+  async fn load(&self, scope: &'static str) -> Result<ItemPairs> {
+    let local = self.local.load(scope).await?;
+    if !self.use_seed().await {
+      return Ok(local);
+    }
+    let seed = self.seed.load(scope).await?;
+    let mut seeded_keys = self.seeded_keys.lock().await;
+    let scope_keys = seeded_keys.entry(scope).or_default();
+    for (key, value) in &seed {
+      self
+        .local
+        .set(scope, key.as_ref().clone(), value.as_ref().clone());
+      scope_keys.push(key.as_ref().clone());
+    }
+    Ok(seed)
+  }
+  fn set(&self, scope: &'static str, key: ItemKey, value: ItemValue) {
+    self.local.set(scope, key, value);
+  }
+  fn remove(&self, scope: &'static str, key: &[u8]) {
+    self.local.remove(scope, key);
+  }
+  fn trigger_save(&self) -> Result<Receiver<Result<()>>> {
+    self.local.trigger_save()
+  }
+  async fn reset(&self) {
+    // the copied items are pending in local, drop them so the seed is not persisted
+    for (scope, keys) in std::mem::take(&mut *self.seeded_keys.lock().await) {
+      for key in keys {
+        self.local.remove(scope, &key);
+      }
+    }
+    self.local.reset().await;
+    *self.use_seed.lock().await = Some(false);
+  }
+  async fn scopes(&self) -> Result<Vec<String>> {
+    if self.use_seed().await {
+      self.seed.scopes().await
+    } else {
+      self.local.scopes().await
+    }
+  }
+  fn take_evicted_size(&self) -> u64 {
+    self.local.take_evicted_size()
+  }
+}
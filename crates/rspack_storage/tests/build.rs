@@ -33,8 +33,10 @@ mod test_storage_build {
       fs,
       bucket_size: 10,
       pack_size: 200,
+      compression: Default::default(),
       expire: 7 * 24 * 60 * 60 * 1000,
       clean: true,
+      max_size: None,
       fresh_generation: Some(1),
       release_generation: Some(2),
     }
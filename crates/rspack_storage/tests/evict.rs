@@ -0,0 +1,203 @@
+#[cfg(test)]
+mod test_storage_evict {
+  use std::{path::PathBuf, sync::Arc};
+
+  use rspack_fs::{MemoryFileSystem, NativeFileSystem};
+  use rspack_paths::{AssertUtf8, Utf8Path, Utf8PathBuf};
+  use rspack_storage::{
+    BridgeFileSystem, FileSystem, PackStorage, PackStorageOptions, Result, Storage,
+  };
+
+  pub fn get_native_path(p: &str) -> (PathBuf, PathBuf) {
+    let base = std::env::temp_dir()
+      .join("rspack_test/storage/test_storage_evict")
+      .join(p);
+    (base.join("cache"), base.join("temp"))
+  }
+
+  pub fn get_memory_path(p: &str) -> (PathBuf, PathBuf) {
+    let base = PathBuf::from("/rspack_test/storage/test_storage_evict/").join(p);
+    (base.join("cache"), base.join("temp"))
+  }
+
+  async fn get_dir_size(dir: &Utf8Path, fs: &dyn FileSystem) -> Result<u64> {
+    let mut size = 0;
+    let mut stack = vec![dir.to_path_buf()];
+    while let Some(path) = stack.pop() {
+      let meta = fs.metadata(&path).await?;
+      if meta.is_directory {
+        stack.extend(fs.read_dir(&path).await?.into_iter().map(|n| path.join(n)));
+      } else {
+        size += meta.size;
+      }
+    }
+    Ok(size)
+  }
+
+  async fn test_build(
+    version: &str,
+    max_size: Option<u64>,
+    root: &Utf8PathBuf,
+    temp_root: &Utf8PathBuf,
+    fs: Arc<dyn FileSystem>,
+  ) -> Result<u64> {
+    let storage = PackStorage::new(PackStorageOptions {
+      version: version.to_string(),
+      root: root.into(),
+      temp_root: temp_root.into(),
+      fs: fs.clone(),
+      bucket_size: 2,
+      pack_size: 200,
+      compression: Default::default(),
+      expire: 7 * 24 * 60 * 60 * 1000,
+      clean: true,
+      max_size,
+      fresh_generation: Some(1),
+      release_generation: Some(2),
+    });
+    let data = storage.load("test_scope").await?;
+    for i in data.len()..100 {
+      storage.set(
+        "test_scope",
+        format!("key_{i:0>3}").as_bytes().to_vec(),
+        format!("val_{i:0>3}").as_bytes().to_vec(),
+      );
+    }
+    // touch the scope to refresh the last used time
+    storage.set(
+      "test_scope",
+      "key_touch".as_bytes().to_vec(),
+      version.as_bytes().to_vec(),
+    );
+    let rx = storage.trigger_save()?;
+    rx.await.expect("should save")?;
+    assert!(
+      fs.exists(&root.join(version).join("test_scope/scope_meta"))
+        .await?
+    );
+    Ok(storage.take_evicted_size())
+  }
+
+  async fn wait_next_tick() {
+    tokio::time::sleep(std::time::Duration::from_millis(10)).await;
+  }
+
+  #[tokio::test]
+  #[cfg_attr(miri, ignore)]
+  async fn test_evict_least_recently_used() -> Result<()> {
+    let cases = [
+      (
+        get_native_path("test_evict_native"),
+        Arc::new(BridgeFileSystem(Arc::new(NativeFileSystem::new(false)))),
+      ),
+      (
+        get_memory_path("test_evict_memory"),
+        Arc::new(BridgeFileSystem(Arc::new(MemoryFileSystem::default()))),
+      ),
+    ];
+
+    for ((root, temp_root), fs) in cases {
+      let root = root.assert_utf8();
+      let temp_root = temp_root.assert_utf8();
+      fs.remove_dir(&root).await.expect("should remove root");
+      fs.remove_dir(&temp_root)
+        .await
+        .expect("should remove temp root");
+
+      assert_eq!(
+        test_build("v1", None, &root, &temp_root, fs.clone()).await?,
+        0
+      );
+      wait_next_tick().await;
+      assert_eq!(
+        test_build("v2", None, &root, &temp_root, fs.clone()).await?,
+        0
+      );
+      wait_next_tick().await;
+      // v1 is used again, v2 becomes the least recently used one
+      assert_eq!(
+        test_build("v1", None, &root, &temp_root, fs.clone()).await?,
+        0
+      );
+      wait_next_tick().await;
+
+      let v1_size = get_dir_size(&root.join("v1"), fs.as_ref()).await?;
+      let v2_size = get_dir_size(&root.join("v2"), fs.as_ref()).await?;
+      let max_size = v1_size * 5 / 2;
+      assert_eq!(
+        test_build("v3", Some(max_size), &root, &temp_root, fs.clone()).await?,
+        v2_size
+      );
+      assert!(fs.exists(&root.join("v1")).await?);
+      assert!(!fs.exists(&root.join("v2")).await?);
+      assert!(fs.exists(&root.join("v3")).await?);
+
+      // the current version is never evicted
+      let v1_size = get_dir_size(&root.join("v1"), fs.as_ref()).await?;
+      let v3_size = get_dir_size(&root.join("v3"), fs.as_ref()).await?;
+      wait_next_tick().await;
+      assert_eq!(
+        test_build("v4", Some(1), &root, &temp_root, fs.clone()).await?,
+        v1_size + v3_size
+      );
+      assert!(!fs.exists(&root.join("v1")).await?);
+      assert!(!fs.exists(&root.join("v3")).await?);
+      assert!(fs.exists(&root.join("v4/test_scope/scope_meta")).await?);
+    }
+    Ok(())
+  }
+
+  #[tokio::test]
+  #[cfg_attr(miri, ignore)]
+  async fn test_evict_skip_locked_versions() -> Result<()> {
+    let cases = [
+      (
+        get_native_path("test_evict_locked_native"),
+        Arc::new(BridgeFileSystem(Arc::new(NativeFileSystem::new(false)))),
+      ),
+      (
+        get_memory_path("test_evict_locked_memory"),
+        Arc::new(BridgeFileSystem(Arc::new(MemoryFileSystem::default()))),
+      ),
+    ];
+
+    for ((root, temp_root), fs) in cases {
+      let root = root.assert_utf8();
+      let temp_root = temp_root.assert_utf8();
+      fs.remove_dir(&root).await.expect("should remove root");
+      fs.remove_dir(&temp_root)
+        .await
+        .expect("should remove temp root");
+
+      assert_eq!(
+        test_build("v1", None, &root, &temp_root, fs.clone()).await?,
+        0
+      );
+      wait_next_tick().await;
+      assert_eq!(
+        test_build("v2", Some(u64::MAX), &root, &temp_root, fs.clone()).await?,
+        0
+      );
+      wait_next_tick().await;
+
+      // the size of the version is recorded when it is saved with a size budget
+      let v2_size = get_dir_size(&root.join("v2"), fs.as_ref()).await?;
+      let mut reader = fs.read_file(&root.join("v2/storage_size")).await?;
+      assert_eq!(reader.read_line().await?.parse::<u64>().ok(), Some(v2_size));
+
+      // v1 is being saved by another process
+      let mut writer = fs.write_file(&root.join("v1/move.lock")).await?;
+      writer.write_line(root.join("v1").as_str()).await?;
+      writer.flush().await?;
+
+      assert_eq!(
+        test_build("v3", Some(1), &root, &temp_root, fs.clone()).await?,
+        v2_size
+      );
+      assert!(fs.exists(&root.join("v1")).await?);
+      assert!(!fs.exists(&root.join("v2")).await?);
+      assert!(fs.exists(&root.join("v3")).await?);
+    }
+    Ok(())
+  }
+}
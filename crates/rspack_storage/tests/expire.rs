@@ -33,8 +33,10 @@ mod test_storage_expire {
       fs: fs.clone(),
       bucket_size: 2,
       pack_size: 200,
+      compression: Default::default(),
       expire: 0,
       clean: true,
+      max_size: None,
       fresh_generation: Some(1),
       release_generation: Some(2),
     });
@@ -70,8 +72,10 @@ mod test_storage_expire {
       fs: fs.clone(),
       bucket_size: 2,
       pack_size: 200,
+      compression: Default::default(),
       expire: 0,
       clean: true,
+      max_size: None,
       fresh_generation: Some(1),
       release_generation: Some(2),
     });
@@ -97,8 +101,10 @@ mod test_storage_expire {
       fs: fs.clone(),
       bucket_size: 2,
       pack_size: 200,
+      compression: Default::default(),
       expire: 7 * 24 * 60 * 60 * 1000,
       clean: true,
+      max_size: None,
       fresh_generation: Some(1),
       release_generation: Some(2),
     });
@@ -96,8 +96,10 @@ mod test_storage_lock {
       fs: fs.clone(),
       bucket_size: 1,
       pack_size: 100,
+      compression: Default::default(),
       expire: 7 * 24 * 60 * 60 * 1000,
       clean: true,
+      max_size: None,
       fresh_generation: Some(1),
       release_generation: Some(2),
     });
@@ -133,8 +135,10 @@ mod test_storage_lock {
       fs: fs.clone(),
       bucket_size: 1,
       pack_size: 100,
+      compression: Default::default(),
       expire: 7 * 24 * 60 * 60 * 1000,
       clean: true,
+      max_size: None,
       fresh_generation: Some(1),
       release_generation: Some(2),
     });
@@ -155,8 +159,10 @@ mod test_storage_lock {
       fs: fs.clone(),
       bucket_size: 1,
       pack_size: 100,
+      compression: Default::default(),
       expire: 7 * 24 * 60 * 60 * 1000,
       clean: true,
+      max_size: None,
       fresh_generation: Some(1),
       release_generation: Some(2),
     });
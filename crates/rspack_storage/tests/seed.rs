@@ -0,0 +1,277 @@
+#[cfg(test)]
+mod test_storage_seed {
+  use std::{path::PathBuf, sync::Arc};
+
+  use rspack_fs::{FileMetadata, MemoryFileSystem, NativeFileSystem};
+  use rspack_paths::{AssertUtf8, Utf8Path, Utf8PathBuf};
+  use rspack_storage::{
+    BridgeFileSystem, FSError, FSOperation, FSResult, FileSystem, LoadOnlyPackStorageOptions,
+    PackStorage, PackStorageOptions, Reader, Result, SeedStorage, Storage, Writer,
+  };
+  use rustc_hash::FxHashSet as HashSet;
+
+  /// File system of a read-only directory, all of the writing fails
+  #[derive(Debug)]
+  struct ReadonlyFileSystem(Arc<dyn FileSystem>);
+
+  fn readonly_error(path: &Utf8Path, opt: FSOperation) -> FSError {
+    FSError::from_message(path, opt, "read-only file system".to_string())
+  }
+
+  #[async_trait::async_trait]
+  impl FileSystem for ReadonlyFileSystem {
+    async fn exists(&self, path: &Utf8Path) -> FSResult<bool> {
+      self.0.exists(path).await
+    }
+    async fn remove_dir(&self, path: &Utf8Path) -> FSResult<()> {
+      Err(readonly_error(path, FSOperation::Remove))
+    }
+    async fn ensure_dir(&self, path: &Utf8Path) -> FSResult<()> {
+      Err(readonly_error(path, FSOperation::Dir))
+    }
+    async fn write_file(&self, path: &Utf8Path) -> FSResult<Writer> {
+      Err(readonly_error(path, FSOperation::Write))
+    }
+    async fn read_file(&self, path: &Utf8Path) -> FSResult<Reader> {
+      self.0.read_file(path).await
+    }
+    async fn read_dir(&self, path: &Utf8Path) -> FSResult<HashSet<String>> {
+      self.0.read_dir(path).await
+    }
+    async fn metadata(&self, path: &Utf8Path) -> FSResult<FileMetadata> {
+      self.0.metadata(path).await
+    }
+    async fn remove_file(&self, path: &Utf8Path) -> FSResult<()> {
+      Err(readonly_error(path, FSOperation::Remove))
+    }
+    async fn move_file(&self, from: &Utf8Path, _to: &Utf8Path) -> FSResult<()> {
+      Err(readonly_error(from, FSOperation::Move))
+    }
+  }
+
+  pub fn get_native_path(p: &str) -> PathBuf {
+    std::env::temp_dir()
+      .join("rspack_test/storage/test_storage_seed")
+      .join(p)
+  }
+
+  pub fn get_memory_path(p: &str) -> PathBuf {
+    PathBuf::from("/rspack_test/storage/test_storage_seed/").join(p)
+  }
+
+  fn create_storage(dir: &Utf8PathBuf, fs: Arc<dyn FileSystem>) -> Arc<dyn Storage> {
+    Arc::new(PackStorage::new(PackStorageOptions {
+      version: "xxx".to_string(),
+      root: dir.join("cache").into(),
+      temp_root: dir.join("temp").into(),
+      fs,
+      bucket_size: 5,
+      pack_size: 200,
+      compression: Default::default(),
+      expire: 7 * 24 * 60 * 60 * 1000,
+      clean: false,
+      max_size: None,
+      fresh_generation: Some(1),
+      release_generation: Some(2),
+    }))
+  }
+
+  fn create_seed_storage(dir: &Utf8PathBuf, fs: Arc<dyn FileSystem>) -> Arc<dyn Storage> {
+    Arc::new(PackStorage::new_load_only(LoadOnlyPackStorageOptions {
+      version: "xxx".to_string(),
+      root: dir.join("cache").into(),
+      fs,
+      bucket_size: 5,
+      pack_size: 200,
+      compression: Default::default(),
+    }))
+  }
+
+  async fn test_build_seed(seed: &Utf8PathBuf, fs: Arc<dyn FileSystem>) -> Result<()> {
+    let storage = create_storage(seed, fs);
+    assert!(storage.load("test_scope").await?.is_empty());
+    for i in 0..100 {
+      storage.set(
+        "test_scope",
+        format!("key_{i:0>3}").as_bytes().to_vec(),
+        format!("val_{i:0>3}").as_bytes().to_vec(),
+      );
+    }
+    let rx = storage.trigger_save()?;
+    rx.await.expect("should save")?;
+    Ok(())
+  }
+
+  async fn test_start_from_seed(
+    seed: &Utf8PathBuf,
+    local: &Utf8PathBuf,
+    fs: Arc<dyn FileSystem>,
+  ) -> Result<()> {
+    let storage = SeedStorage::new(
+      create_storage(seed, fs.clone()),
+      create_storage(local, fs.clone()),
+    );
+    assert_eq!(storage.scopes().await?, vec!["test_scope".to_string()]);
+    assert_eq!(storage.load("test_scope").await?.len(), 100);
+    storage.set(
+      "test_scope",
+      "key_100".as_bytes().to_vec(),
+      "val_100".as_bytes().to_vec(),
+    );
+    storage.remove("test_scope", "key_000".as_bytes());
+    let rx = storage.trigger_save()?;
+    rx.await.expect("should save")?;
+
+    // the seed is not modified
+    assert_eq!(
+      create_storage(seed, fs.clone())
+        .load("test_scope")
+        .await?
+        .len(),
+      100
+    );
+    // the seed and the local changes are persisted in local
+    let data = create_storage(local, fs.clone()).load("test_scope").await?;
+    assert_eq!(data.len(), 100);
+    assert!(data.iter().any(|(key, _)| key.as_slice() == b"key_100"));
+    assert!(!data.iter().any(|(key, _)| key.as_slice() == b"key_000"));
+    Ok(())
+  }
+
+  async fn test_prefer_local(
+    seed: &Utf8PathBuf,
+    local: &Utf8PathBuf,
+    fs: Arc<dyn FileSystem>,
+  ) -> Result<()> {
+    let storage = SeedStorage::new(
+      create_storage(seed, fs.clone()),
+      create_storage(local, fs.clone()),
+    );
+    let data = storage.load("test_scope").await?;
+    assert_eq!(data.len(), 100);
+    assert!(!data.iter().any(|(key, _)| key.as_slice() == b"key_000"));
+    Ok(())
+  }
+
+  async fn test_readonly_seed(
+    seed: &Utf8PathBuf,
+    local: &Utf8PathBuf,
+    fs: Arc<dyn FileSystem>,
+  ) -> Result<()> {
+    let readonly_fs: Arc<dyn FileSystem> = Arc::new(ReadonlyFileSystem(fs.clone()));
+    let storage = SeedStorage::new(
+      create_seed_storage(seed, readonly_fs.clone()),
+      create_storage(local, fs.clone()),
+    );
+    assert_eq!(storage.load("test_scope").await?.len(), 100);
+    let rx = storage.trigger_save()?;
+    rx.await.expect("should save")?;
+    assert_eq!(
+      create_storage(local, fs.clone())
+        .load("test_scope")
+        .await?
+        .len(),
+      100
+    );
+    // the load-only storage can not be saved
+    assert!(
+      create_seed_storage(seed, readonly_fs.clone())
+        .trigger_save()
+        .is_err()
+    );
+
+    // the lock file left by an unfinished save can not be recovered without writing
+    let lock_file = seed.join("cache/xxx/move.lock");
+    let mut writer = fs.write_file(&lock_file).await?;
+    writer.write_line(seed.join("cache/xxx").as_str()).await?;
+    writer.flush().await?;
+    assert!(
+      create_seed_storage(seed, readonly_fs)
+        .load("test_scope")
+        .await
+        .is_err()
+    );
+    assert!(fs.exists(&lock_file).await?);
+    fs.remove_file(&lock_file).await?;
+    Ok(())
+  }
+
+  async fn test_reset_drop_seed(
+    seed: &Utf8PathBuf,
+    local: &Utf8PathBuf,
+    fs: Arc<dyn FileSystem>,
+  ) -> Result<()> {
+    let storage = SeedStorage::new(
+      create_storage(seed, fs.clone()),
+      create_storage(local, fs.clone()),
+    );
+    // e.g. the build dependencies in seed are invalid
+    storage.reset().await;
+    assert!(storage.load("test_scope").await?.is_empty());
+    assert!(storage.scopes().await?.is_empty());
+    Ok(())
+  }
+
+  async fn test_reset_after_load(
+    seed: &Utf8PathBuf,
+    local: &Utf8PathBuf,
+    fs: Arc<dyn FileSystem>,
+  ) -> Result<()> {
+    let storage = SeedStorage::new(
+      create_storage(seed, fs.clone()),
+      create_storage(local, fs.clone()),
+    );
+    assert_eq!(storage.load("test_scope").await?.len(), 100);
+    // e.g. the snapshot in seed is invalid
+    storage.reset().await;
+    storage.set(
+      "test_scope",
+      "key_100".as_bytes().to_vec(),
+      "val_100".as_bytes().to_vec(),
+    );
+    let rx = storage.trigger_save()?;
+    rx.await.expect("should save")?;
+
+    // only the items set after reset are persisted in local
+    let data = create_storage(local, fs.clone()).load("test_scope").await?;
+    assert_eq!(data.len(), 1);
+    assert_eq!(data[0].0.as_slice(), b"key_100");
+    Ok(())
+  }
+
+  #[tokio::test]
+  #[cfg_attr(miri, ignore)]
+  async fn test_seed_storage() -> Result<()> {
+    let cases = [
+      (
+        get_native_path("test_seed_native"),
+        Arc::new(BridgeFileSystem(Arc::new(NativeFileSystem::new(false)))),
+      ),
+      (
+        get_memory_path("test_seed_memory"),
+        Arc::new(BridgeFileSystem(Arc::new(MemoryFileSystem::default()))),
+      ),
+    ];
+
+    for (base, fs) in cases {
+      let base = base.assert_utf8();
+      fs.remove_dir(&base).await.expect("should remove base");
+      let seed = base.join("seed");
+      let local = base.join("local");
+
+      test_build_seed(&seed, fs.clone()).await?;
+      test_start_from_seed(&seed, &local, fs.clone()).await?;
+      test_prefer_local(&seed, &local, fs.clone()).await?;
+
+      fs.remove_dir(&local).await.expect("should remove local");
+      test_readonly_seed(&seed, &local, fs.clone()).await?;
+
+      fs.remove_dir(&local).await.expect("should remove local");
+      test_reset_drop_seed(&seed, &local, fs.clone()).await?;
+
+      fs.remove_dir(&local).await.expect("should remove local");
+      test_reset_after_load(&seed, &local, fs.clone()).await?;
+    }
+    Ok(())
+  }
+}
@@ -0,0 +1,124 @@
+#[cfg(test)]
+mod test_storage_stats {
+  use std::{path::PathBuf, sync::Arc};
+
+  use rspack_fs::{MemoryFileSystem, NativeFileSystem};
+  use rspack_paths::{AssertUtf8, Utf8PathBuf};
+  use rspack_storage::{
+    BridgeFileSystem, FileSystem, PackCompression, PackStorage, PackStorageOptions, Result,
+    Storage, remove_expired_versions,
+  };
+
+  pub fn get_native_path(p: &str) -> (PathBuf, PathBuf) {
+    let base = std::env::temp_dir()
+      .join("rspack_test/storage/test_storage_stats")
+      .join(p);
+    (base.join("cache"), base.join("temp"))
+  }
+
+  pub fn get_memory_path(p: &str) -> (PathBuf, PathBuf) {
+    let base = PathBuf::from("/rspack_test/storage/test_storage_stats/").join(p);
+    (base.join("cache"), base.join("temp"))
+  }
+
+  fn create_storage(
+    version: &str,
+    expire: u64,
+    root: &Utf8PathBuf,
+    temp_root: &Utf8PathBuf,
+    fs: Arc<dyn FileSystem>,
+  ) -> PackStorage {
+    PackStorage::new(PackStorageOptions {
+      version: version.to_string(),
+      root: root.into(),
+      temp_root: temp_root.into(),
+      fs,
+      bucket_size: 2,
+      pack_size: 200,
+      compression: Default::default(),
+      expire,
+      clean: false,
+      max_size: None,
+      fresh_generation: Some(1),
+      release_generation: Some(2),
+    })
+  }
+
+  async fn test_build(
+    version: &str,
+    expire: u64,
+    root: &Utf8PathBuf,
+    temp_root: &Utf8PathBuf,
+    fs: Arc<dyn FileSystem>,
+  ) -> Result<()> {
+    let storage = create_storage(version, expire, root, temp_root, fs);
+    assert!(storage.stats().await?.is_none());
+    storage.load("test_scope").await?;
+    for i in 0..100 {
+      storage.set(
+        "test_scope",
+        format!("key_{i:0>3}").as_bytes().to_vec(),
+        format!("val_{i:0>3}").as_bytes().to_vec(),
+      );
+    }
+    let rx = storage.trigger_save()?;
+    rx.await.expect("should save")?;
+    Ok(())
+  }
+
+  async fn test_stats(
+    version: &str,
+    root: &Utf8PathBuf,
+    temp_root: &Utf8PathBuf,
+    fs: Arc<dyn FileSystem>,
+  ) -> Result<()> {
+    let storage = create_storage(version, 0, root, temp_root, fs);
+    let stats = storage.stats().await?.expect("should have stats");
+    assert_eq!(stats.scopes, vec!["test_scope".to_string()]);
+    assert!(stats.last_used > 0);
+    let scope_stats = storage.scope_stats("test_scope").await?;
+    assert_eq!(scope_stats.keys, 100);
+    assert!(scope_stats.packs > 1);
+    // 7 bytes for each key and value
+    assert!(scope_stats.size > 100 * 14);
+    assert_eq!(scope_stats.compression, PackCompression::None);
+    Ok(())
+  }
+
+  #[tokio::test]
+  #[cfg_attr(miri, ignore)]
+  async fn test_storage_stats() -> Result<()> {
+    let cases = [
+      (
+        get_native_path("test_stats_native"),
+        Arc::new(BridgeFileSystem(Arc::new(NativeFileSystem::new(false)))),
+      ),
+      (
+        get_memory_path("test_stats_memory"),
+        Arc::new(BridgeFileSystem(Arc::new(MemoryFileSystem::default()))),
+      ),
+    ];
+
+    for ((root, temp_root), fs) in cases {
+      let root = root.assert_utf8();
+      let temp_root = temp_root.assert_utf8();
+      fs.remove_dir(&root).await.expect("should remove root");
+      fs.remove_dir(&temp_root)
+        .await
+        .expect("should remove temp root");
+
+      test_build("expired", 0, &root, &temp_root, fs.clone()).await?;
+      test_build("alive", 60 * 1000, &root, &temp_root, fs.clone()).await?;
+      test_stats("alive", &root, &temp_root, fs.clone()).await?;
+
+      tokio::time::sleep(std::time::Duration::from_millis(10)).await;
+      assert_eq!(
+        remove_expired_versions(root.clone().into(), fs.clone()).await?,
+        vec!["expired".to_string()]
+      );
+      assert!(!fs.exists(&root.join("expired")).await?);
+      assert!(fs.exists(&root.join("alive")).await?);
+    }
+    Ok(())
+  }
+}
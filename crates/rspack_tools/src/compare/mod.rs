@@ -3,13 +3,13 @@ mod snapshot;
 
 use std::{collections::VecDeque, sync::Arc};
 
-use rspack_core::cache::persistent::storage::{Storage, StorageOptions, create_storage};
+use rspack_core::cache::persistent::storage::Storage;
 use rspack_error::{Result, error};
 use rspack_fs::{NativeFileSystem, ReadableFileSystem};
 use rspack_paths::Utf8PathBuf;
 use rustc_hash::{FxHashMap as HashMap, FxHashSet as HashSet};
 
-use crate::{debug_info::DebugInfo, utils::ensure_iter_equal};
+use crate::{debug_info::DebugInfo, inspect::create_version_storage, utils::ensure_iter_equal};
 
 pub fn find_relative_cache_path(root_path: &Utf8PathBuf) -> HashSet<String> {
   let fs = NativeFileSystem::new(false);
@@ -42,13 +42,15 @@ pub fn find_relative_cache_path(root_path: &Utf8PathBuf) -> HashSet<String> {
 
 /// Load all version storages from a directory path
 /// Returns a HashMap where key is version name and value is Storage
-pub fn load_storages_from_path(path: &Utf8PathBuf) -> HashMap<String, Arc<dyn Storage>> {
-  let fs = Arc::new(NativeFileSystem::new(false));
+pub async fn load_storages_from_path(
+  path: &Utf8PathBuf,
+) -> Result<HashMap<String, Arc<dyn Storage>>> {
+  let fs = NativeFileSystem::new(false);
   let mut storages = HashMap::default();
 
   // Read directory entries
   let Ok(versions) = fs.read_dir_sync(path.as_path()) else {
-    return storages;
+    return Ok(storages);
   };
 
   // Collect version directories (skip hidden files)
@@ -59,18 +61,12 @@ pub fn load_storages_from_path(path: &Utf8PathBuf) -> HashMap<String, Arc<dyn St
     }
 
     // Create storage for this version
-    let storage = create_storage(
-      StorageOptions::FileSystem {
-        directory: path.clone().into(),
-      },
-      v.clone(),
-      fs.clone(),
-    );
+    let storage = create_version_storage(path, &v).await?;
 
     storages.insert(v, storage);
   }
 
-  storages
+  Ok(storages)
 }
 
 /// Compare cache dir from two directories and return whether they are equal
@@ -97,8 +93,8 @@ pub async fn compare_cache_dir(path1: Utf8PathBuf, path2: Utf8PathBuf) -> Result
       .with_field("path2", cache_path2.as_ref());
 
     // Load storages from both paths
-    let storages1 = load_storages_from_path(&cache_path1);
-    let mut storages2 = load_storages_from_path(&cache_path2);
+    let storages1 = load_storages_from_path(&cache_path1).await?;
+    let mut storages2 = load_storages_from_path(&cache_path2).await?;
 
     // Check if versions are identical
     ensure_iter_equal(
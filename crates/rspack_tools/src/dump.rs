@@ -0,0 +1,57 @@
+use std::{fmt::Write, sync::Arc};
+
+use rspack_core::{
+  ModuleIdentifier,
+  cache::persistent::{codec::CacheCodec, occasion::make::MakeOccasion},
+};
+use rspack_error::{Result, error};
+use rspack_paths::Utf8PathBuf;
+
+use crate::inspect::create_version_storage;
+
+/// Decode a module entry of the make occasion and dump it as text
+///
+/// The `key` is the module identifier used as the storage key of the make scope.
+pub async fn dump_cache_key(path: Utf8PathBuf, version: &str, key: &str) -> Result<String> {
+  let storage = create_version_storage(&path, version).await?;
+  // Use a dummy path for codec since we're only deserializing
+  let codec = Arc::new(CacheCodec::new(Some(Utf8PathBuf::from("/"))));
+  let artifact = MakeOccasion::new(storage, codec).recovery().await?;
+  let mg = &artifact.module_graph;
+
+  let identifier = ModuleIdentifier::from(key);
+  let module = mg
+    .module_by_identifier(&identifier)
+    .ok_or_else(|| error!("module `{key}` not found in version `{version}` of {path}"))?;
+
+  let mut output = String::new();
+  let _ = writeln!(output, "identifier: {identifier}");
+  let _ = writeln!(output, "type: {}", module.module_type());
+  if let Some(layer) = module.get_layer() {
+    let _ = writeln!(output, "layer: {layer}");
+  }
+  let _ = writeln!(output, "dependencies:");
+  for dep_id in module.get_dependencies() {
+    let dep = mg.dependency_by_id(dep_id);
+    match dep.as_module_dependency() {
+      Some(module_dep) => {
+        let _ = writeln!(
+          output,
+          "  {} {}",
+          dep.dependency_type(),
+          module_dep.request()
+        );
+      }
+      None => {
+        let _ = writeln!(output, "  {}", dep.dependency_type());
+      }
+    }
+  }
+  let _ = writeln!(output, "blocks: {}", module.get_blocks().len());
+  let _ = writeln!(output, "outgoing modules:");
+  for connection in mg.get_outgoing_connections(&identifier) {
+    let _ = writeln!(output, "  {}", connection.module_identifier());
+  }
+  let _ = writeln!(output, "build info: {:#?}", module.build_info());
+  Ok(output)
+}
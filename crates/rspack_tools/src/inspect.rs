@@ -0,0 +1,176 @@
+use std::{fmt, sync::Arc};
+
+use itertools::Itertools;
+use rspack_core::cache::persistent::storage::{
+  Storage, StorageCompression, StorageOptions, create_storage,
+};
+use rspack_error::Result;
+use rspack_fs::{NativeFileSystem, ReadableFileSystem};
+use rspack_paths::Utf8PathBuf;
+use rspack_storage::{
+  BridgeFileSystem, PackCompression, PackStorage, PackStorageOptions, ScopeStats,
+};
+use rspack_util::{current_time, size::format_size};
+
+/// Create a pack storage to read the version of a cache directory
+///
+/// The pack options only affect writing, so the reader can use the defaults of `create_storage`.
+pub fn create_pack_storage(path: &Utf8PathBuf, version: &str) -> PackStorage {
+  PackStorage::new(PackStorageOptions {
+    root: path.clone().into(),
+    temp_root: path.join(".temp").into(),
+    fs: Arc::new(BridgeFileSystem(Arc::new(NativeFileSystem::new(false)))),
+    bucket_size: 20,
+    pack_size: 500 * 1024,
+    compression: Default::default(),
+    expire: 0,
+    clean: false,
+    max_size: None,
+    version: version.to_string(),
+    fresh_generation: None,
+    release_generation: None,
+  })
+}
+
+/// Create a storage to load the version of a cache directory
+///
+/// Loading validates the compression of the scopes, so the storage uses the compression
+/// recorded in the scope meta. All scopes of a version are written with the same options.
+pub async fn create_version_storage(path: &Utf8PathBuf, version: &str) -> Result<Arc<dyn Storage>> {
+  let pack_storage = create_pack_storage(path, version);
+  let mut compression = None;
+  if let Some(name) = pack_storage
+    .stats()
+    .await?
+    .and_then(|stats| stats.scopes.into_iter().next())
+  {
+    // scopes are loaded by static names, leaking is fine in this short-lived process
+    let static_name: &'static str = Box::leak(name.into_boxed_str());
+    compression = match pack_storage.scope_stats(static_name).await?.compression {
+      PackCompression::None => None,
+      PackCompression::Zstd => Some(StorageCompression::Zstd),
+      PackCompression::Lz4 => Some(StorageCompression::Lz4),
+    };
+  }
+  Ok(create_storage(
+    StorageOptions::FileSystem {
+      directory: path.clone().into(),
+      compression,
+      max_size: None,
+      seed_directory: None,
+    },
+    version.to_string(),
+    Arc::new(NativeFileSystem::new(false)),
+  ))
+}
+
+/// Information of a scope in a cache version
+#[derive(Debug)]
+pub struct ScopeInfo {
+  pub name: String,
+  pub stats: std::result::Result<ScopeStats, String>,
+}
+
+/// Information of a cache version
+#[derive(Debug)]
+pub struct VersionInfo {
+  pub version: String,
+  pub expire_time: u64,
+  pub last_used: u64,
+  pub scopes: Vec<ScopeInfo>,
+}
+
+impl VersionInfo {
+  fn size(&self) -> usize {
+    self
+      .scopes
+      .iter()
+      .filter_map(|scope| scope.stats.as_ref().ok())
+      .map(|stats| stats.size)
+      .sum()
+  }
+}
+
+/// Report of a cache directory
+#[derive(Debug, Default)]
+pub struct InspectReport {
+  pub versions: Vec<VersionInfo>,
+  /// Directories without a valid root meta, they will be removed by cleaning
+  pub unknown: Vec<String>,
+}
+
+impl fmt::Display for InspectReport {
+  fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+    let now = current_time();
+    for version in &self.versions {
+      let expired = if now > version.expire_time {
+        ", expired"
+      } else {
+        ""
+      };
+      writeln!(
+        f,
+        "version {} ({}, last used at {}, expire at {}{expired})",
+        version.version,
+        format_size(version.size() as f64),
+        version.last_used,
+        version.expire_time
+      )?;
+      for scope in &version.scopes {
+        match &scope.stats {
+          Ok(stats) => writeln!(
+            f,
+            "  scope {}: {} packs, {} keys, {}",
+            scope.name,
+            stats.packs,
+            stats.keys,
+            format_size(stats.size as f64)
+          )?,
+          Err(err) => writeln!(f, "  scope {}: read failed, {err}", scope.name)?,
+        }
+      }
+    }
+    for dir in &self.unknown {
+      writeln!(f, "unknown {dir}")?;
+    }
+    Ok(())
+  }
+}
+
+/// Inspect the versions and scopes of a cache directory without loading the pack contents
+pub async fn inspect_cache_dir(path: Utf8PathBuf) -> Result<InspectReport> {
+  let fs = NativeFileSystem::new(false);
+  let mut report = InspectReport::default();
+  for version in fs.read_dir_sync(&path)?.into_iter().sorted() {
+    // skip hidden dirs and lock files
+    if version.starts_with('.') || version.contains(".lock") {
+      continue;
+    }
+
+    let storage = create_pack_storage(&path, &version);
+    let Some(stats) = storage.stats().await? else {
+      report.unknown.push(version);
+      continue;
+    };
+
+    let mut scopes = vec![];
+    for name in stats.scopes {
+      // scopes are loaded by static names, leaking is fine in this short-lived process
+      let static_name: &'static str = Box::leak(name.clone().into_boxed_str());
+      scopes.push(ScopeInfo {
+        name,
+        stats: storage
+          .scope_stats(static_name)
+          .await
+          .map_err(|err| err.to_string()),
+      });
+    }
+    report.versions.push(VersionInfo {
+      version,
+      expire_time: stats.expire_time,
+      last_used: stats.last_used,
+      scopes,
+    });
+  }
+  Ok(report)
+}
@@ -1,5 +1,16 @@
 mod compare;
 mod debug_info;
+mod dump;
+mod inspect;
+mod prune;
+mod stats_diff;
 mod utils;
 
 pub use compare::compare_cache_dir;
+pub use dump::dump_cache_key;
+pub use inspect::{InspectReport, ScopeInfo, VersionInfo, inspect_cache_dir};
+pub use prune::prune_cache_dir;
+pub use stats_diff::{
+  ChunkMembershipChange, DuplicatedModule, EntriesDiff, SizeChange, SizeEntry, StatsDiff,
+  StatsJson, StatsJsonAsset, StatsJsonChunk, StatsJsonModule, diff_stats,
+};
@@ -1,7 +1,9 @@
 use clap::{Parser, Subcommand};
 use rspack_error::Diagnostic;
 use rspack_paths::Utf8PathBuf;
-use rspack_tools::compare_cache_dir;
+use rspack_tools::{
+  compare_cache_dir, diff_stats, dump_cache_key, inspect_cache_dir, prune_cache_dir,
+};
 
 /// Toolkit for debugging and testing rspack internals
 #[derive(Parser, Debug)]
@@ -25,13 +27,50 @@ enum Commands {
     #[arg(value_name = "CACHE2")]
     cache2: String,
   },
-}
+  /// List the versions and scopes of a rspack cache directory with their pack counts, key counts and sizes
+  Inspect {
+    /// Path to the cache directory, e.g. node_modules/.cache/rspack
+    #[arg(value_name = "CACHE")]
+    cache: String,
+  },
+  /// Decode a module entry of the make occasion from a rspack cache directory
+  DumpKey {
+    /// Path to the cache directory, e.g. node_modules/.cache/rspack
+    #[arg(value_name = "CACHE")]
+    cache: String,
 
-#[tokio::main]
-async fn main() {
-  let cli = Cli::parse();
+    /// Version of the cache, see the output of `inspect`
+    #[arg(value_name = "VERSION")]
+    version: String,
+
+    /// Module identifier used as the key
+    #[arg(value_name = "KEY")]
+    key: String,
+  },
+  /// Remove the expired and unknown versions of a rspack cache directory
+  Prune {
+    /// Path to the cache directory, e.g. node_modules/.cache/rspack
+    #[arg(value_name = "CACHE")]
+    cache: String,
+  },
+  /// Compare the stats json of two builds
+  DiffStats {
+    /// Path to the stats json of the base build
+    #[arg(value_name = "BASE")]
+    base: String,
+
+    /// Path to the stats json of the current build
+    #[arg(value_name = "CURRENT")]
+    current: String,
 
-  match cli.command {
+    /// Print the diff as json
+    #[arg(long)]
+    json: bool,
+  },
+}
+
+async fn run(command: Commands) -> rspack_error::Result<()> {
+  match command {
     Commands::Compare { cache1, cache2 } => {
       println!("Comparing cache directories:");
       println!("  Path 1: {cache1}");
@@ -41,17 +80,60 @@ async fn main() {
       let path1 = Utf8PathBuf::from(&cache1);
       let path2 = Utf8PathBuf::from(&cache2);
 
-      if let Err(err) = compare_cache_dir(path1, path2).await {
-        eprintln!(
-          "{}",
-          Diagnostic::from(err)
-            .render_report(true)
-            .expect("render error failed")
-        );
-        std::process::exit(1);
-      }
+      compare_cache_dir(path1, path2).await?;
 
       println!("✓ Cache directories are identical");
     }
+    Commands::Inspect { cache } => {
+      let report = inspect_cache_dir(Utf8PathBuf::from(&cache)).await?;
+      print!("{report}");
+    }
+    Commands::DumpKey {
+      cache,
+      version,
+      key,
+    } => {
+      let output = dump_cache_key(Utf8PathBuf::from(&cache), &version, &key).await?;
+      print!("{output}");
+    }
+    Commands::Prune { cache } => {
+      let removed = prune_cache_dir(Utf8PathBuf::from(&cache)).await?;
+      if removed.is_empty() {
+        println!("No expired versions");
+      }
+      for version in removed {
+        println!("Removed {version}");
+      }
+    }
+    Commands::DiffStats {
+      base,
+      current,
+      json,
+    } => {
+      let base = std::fs::read_to_string(&base)?;
+      let current = std::fs::read_to_string(&current)?;
+      let diff = diff_stats(&base, &current)?;
+      if json {
+        println!("{}", diff.to_json()?);
+      } else {
+        print!("{diff}");
+      }
+    }
+  }
+  Ok(())
+}
+
+#[tokio::main]
+async fn main() {
+  let cli = Cli::parse();
+
+  if let Err(err) = run(cli.command).await {
+    eprintln!(
+      "{}",
+      Diagnostic::from(err)
+        .render_report(true)
+        .expect("render error failed")
+    );
+    std::process::exit(1);
   }
 }
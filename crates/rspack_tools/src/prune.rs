@@ -0,0 +1,14 @@
+use std::sync::Arc;
+
+use rspack_error::Result;
+use rspack_fs::NativeFileSystem;
+use rspack_paths::Utf8PathBuf;
+use rspack_storage::{BridgeFileSystem, remove_expired_versions};
+
+/// Remove the expired and unknown versions of a cache directory, returns the removed versions
+///
+/// This follows the same rules as the cleaning after the cache is saved.
+pub async fn prune_cache_dir(path: Utf8PathBuf) -> Result<Vec<String>> {
+  let fs = Arc::new(BridgeFileSystem(Arc::new(NativeFileSystem::new(false))));
+  Ok(remove_expired_versions(path.into(), fs).await?)
+}
@@ -0,0 +1,454 @@
+use std::{collections::BTreeMap, fmt};
+
+use itertools::Itertools;
+use rspack_error::{Result, error};
+use rspack_util::size::format_size;
+use rustc_hash::FxHashMap as HashMap;
+use serde::{Deserialize, Serialize};
+
+/// Chunk id in the stats json, numeric ids are kept as numbers by the js side
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Deserialize)]
+#[serde(untagged)]
+enum StatsId {
+  String(String),
+  Number(i64),
+}
+
+impl fmt::Display for StatsId {
+  fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+    match self {
+      Self::String(id) => write!(f, "{id}"),
+      Self::Number(id) => write!(f, "{id}"),
+    }
+  }
+}
+
+/// The fields of `StatsAsset` used by the diff
+#[derive(Debug, Deserialize)]
+pub struct StatsJsonAsset {
+  pub name: String,
+  #[serde(default)]
+  pub size: f64,
+}
+
+/// The fields of `StatsChunk` used by the diff
+#[derive(Debug, Deserialize)]
+pub struct StatsJsonChunk {
+  #[serde(default)]
+  id: Option<StatsId>,
+  #[serde(default)]
+  pub names: Vec<String>,
+  #[serde(default)]
+  pub size: f64,
+}
+
+impl StatsJsonChunk {
+  /// Chunks are matched by their names, unnamed chunks fall back to their id
+  fn key(&self) -> String {
+    if self.names.is_empty() {
+      self
+        .id
+        .as_ref()
+        .map(ToString::to_string)
+        .unwrap_or_default()
+    } else {
+      self.names.join(",")
+    }
+  }
+}
+
+/// The fields of `StatsModule` used by the diff
+#[derive(Debug, Deserialize)]
+pub struct StatsJsonModule {
+  #[serde(default)]
+  pub identifier: Option<String>,
+  #[serde(default)]
+  pub name: Option<String>,
+  #[serde(default)]
+  pub size: f64,
+  #[serde(default)]
+  chunks: Option<Vec<StatsId>>,
+}
+
+impl StatsJsonModule {
+  /// Modules are matched by their readable names which don't contain the absolute context,
+  /// so builds from different checkouts can be compared
+  fn key(&self) -> Option<&str> {
+    self.name.as_deref().or(self.identifier.as_deref())
+  }
+}
+
+/// The parts of a stats json used by the diff, other fields are ignored
+///
+/// `assets`, `chunks` and `modules` need to be enabled in the stats options to be compared.
+#[derive(Debug, Default, Deserialize)]
+pub struct StatsJson {
+  #[serde(default)]
+  pub assets: Vec<StatsJsonAsset>,
+  #[serde(default)]
+  pub chunks: Vec<StatsJsonChunk>,
+  #[serde(default)]
+  pub modules: Vec<StatsJsonModule>,
+}
+
+impl StatsJson {
+  pub fn from_json(json: &str) -> Result<Self> {
+    serde_json::from_str(json).map_err(|err| error!("failed to parse stats json: {err}"))
+  }
+
+  /// Chunk keys of each module, sorted
+  fn module_chunks(&self) -> BTreeMap<&str, Vec<String>> {
+    let chunk_keys: HashMap<&StatsId, String> = self
+      .chunks
+      .iter()
+      .filter_map(|chunk| Some((chunk.id.as_ref()?, chunk.key())))
+      .collect();
+    self
+      .modules
+      .iter()
+      .filter_map(|module| {
+        let chunks = module
+          .chunks
+          .iter()
+          .flatten()
+          .map(|id| {
+            chunk_keys
+              .get(id)
+              .cloned()
+              .unwrap_or_else(|| id.to_string())
+          })
+          .sorted()
+          .dedup()
+          .collect();
+        Some((module.key()?, chunks))
+      })
+      .collect()
+  }
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct SizeEntry {
+  pub name: String,
+  pub size: f64,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct SizeChange {
+  pub name: String,
+  pub before: f64,
+  pub after: f64,
+  pub delta: f64,
+}
+
+/// Added, removed and resized entries of a kind, matched by name
+#[derive(Debug, Default, Serialize)]
+pub struct EntriesDiff {
+  pub added: Vec<SizeEntry>,
+  pub removed: Vec<SizeEntry>,
+  pub changed: Vec<SizeChange>,
+}
+
+impl EntriesDiff {
+  fn new<'a>(
+    base: impl IntoIterator<Item = (&'a str, f64)>,
+    current: impl IntoIterator<Item = (&'a str, f64)>,
+  ) -> Self {
+    let base: BTreeMap<_, _> = base.into_iter().collect();
+    let current: BTreeMap<_, _> = current.into_iter().collect();
+    let mut diff = Self::default();
+    for (&name, &after) in &current {
+      match base.get(name) {
+        None => diff.added.push(SizeEntry {
+          name: name.to_string(),
+          size: after,
+        }),
+        Some(&before) if before != after => diff.changed.push(SizeChange {
+          name: name.to_string(),
+          before,
+          after,
+          delta: after - before,
+        }),
+        Some(_) => {}
+      }
+    }
+    for (&name, &size) in &base {
+      if !current.contains_key(name) {
+        diff.removed.push(SizeEntry {
+          name: name.to_string(),
+          size,
+        });
+      }
+    }
+    // largest first, the maps already sorted them by name for ties
+    diff.added.sort_by(|a, b| b.size.total_cmp(&a.size));
+    diff.removed.sort_by(|a, b| b.size.total_cmp(&a.size));
+    diff
+      .changed
+      .sort_by(|a, b| b.delta.abs().total_cmp(&a.delta.abs()));
+    diff
+  }
+
+  pub fn is_empty(&self) -> bool {
+    self.added.is_empty() && self.removed.is_empty() && self.changed.is_empty()
+  }
+
+  fn delta(&self) -> f64 {
+    self.added.iter().map(|entry| entry.size).sum::<f64>()
+      - self.removed.iter().map(|entry| entry.size).sum::<f64>()
+      + self.changed.iter().map(|change| change.delta).sum::<f64>()
+  }
+}
+
+/// Modules moved into or out of a chunk which exists in both builds
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ChunkMembershipChange {
+  pub chunk: String,
+  pub added_modules: Vec<String>,
+  pub removed_modules: Vec<String>,
+}
+
+/// A module which is in more than one chunk now but was not before
+#[derive(Debug, Serialize)]
+pub struct DuplicatedModule {
+  pub name: String,
+  pub size: f64,
+  pub chunks: Vec<String>,
+}
+
+/// Difference between the stats json of two builds
+#[derive(Debug, Default, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct StatsDiff {
+  pub assets: EntriesDiff,
+  pub chunks: EntriesDiff,
+  pub modules: EntriesDiff,
+  pub chunk_membership: Vec<ChunkMembershipChange>,
+  pub duplicated_modules: Vec<DuplicatedModule>,
+}
+
+impl StatsDiff {
+  pub fn new(base: &StatsJson, current: &StatsJson) -> Self {
+    let assets = EntriesDiff::new(
+      base.assets.iter().map(|a| (a.name.as_str(), a.size)),
+      current.assets.iter().map(|a| (a.name.as_str(), a.size)),
+    );
+    let base_chunk_keys = base.chunks.iter().map(StatsJsonChunk::key).collect_vec();
+    let current_chunk_keys = current.chunks.iter().map(StatsJsonChunk::key).collect_vec();
+    let chunks = EntriesDiff::new(
+      base_chunk_keys
+        .iter()
+        .zip(&base.chunks)
+        .map(|(key, chunk)| (key.as_str(), chunk.size)),
+      current_chunk_keys
+        .iter()
+        .zip(&current.chunks)
+        .map(|(key, chunk)| (key.as_str(), chunk.size)),
+    );
+    let modules = EntriesDiff::new(
+      base.modules.iter().filter_map(|m| Some((m.key()?, m.size))),
+      current
+        .modules
+        .iter()
+        .filter_map(|m| Some((m.key()?, m.size))),
+    );
+
+    let base_module_chunks = base.module_chunks();
+    let current_module_chunks = current.module_chunks();
+
+    let mut membership: BTreeMap<&str, (Vec<String>, Vec<String>)> = BTreeMap::new();
+    let base_chunks_by_key = invert(&base_module_chunks);
+    let current_chunks_by_key = invert(&current_module_chunks);
+    for (chunk, current_modules) in &current_chunks_by_key {
+      let Some(base_modules) = base_chunks_by_key.get(chunk) else {
+        continue;
+      };
+      let added = current_modules
+        .iter()
+        .filter(|module| !base_modules.contains(*module))
+        .map(|module| module.to_string())
+        .collect_vec();
+      let removed = base_modules
+        .iter()
+        .filter(|module| !current_modules.contains(*module))
+        .map(|module| module.to_string())
+        .collect_vec();
+      if !added.is_empty() || !removed.is_empty() {
+        membership.insert(chunk, (added, removed));
+      }
+    }
+    // chunks which lost all of their modules are not in the inverted map of the current build
+    for (chunk, base_modules) in &base_chunks_by_key {
+      if !current_chunks_by_key.contains_key(chunk)
+        && current_chunk_keys.iter().any(|key| key == chunk)
+      {
+        membership.insert(
+          chunk,
+          (
+            vec![],
+            base_modules
+              .iter()
+              .map(|module| module.to_string())
+              .collect(),
+          ),
+        );
+      }
+    }
+    let chunk_membership = membership
+      .into_iter()
+      .map(
+        |(chunk, (added_modules, removed_modules))| ChunkMembershipChange {
+          chunk: chunk.to_string(),
+          added_modules,
+          removed_modules,
+        },
+      )
+      .collect();
+
+    let current_sizes: HashMap<&str, f64> = current
+      .modules
+      .iter()
+      .filter_map(|m| Some((m.key()?, m.size)))
+      .collect();
+    let mut duplicated_modules = current_module_chunks
+      .iter()
+      .filter(|(module, chunks)| {
+        chunks.len() > 1
+          && base_module_chunks
+            .get(*module)
+            .is_none_or(|base_chunks| base_chunks.len() <= 1)
+      })
+      .map(|(module, chunks)| DuplicatedModule {
+        name: module.to_string(),
+        size: current_sizes.get(module).copied().unwrap_or_default(),
+        chunks: chunks.clone(),
+      })
+      .collect_vec();
+    duplicated_modules.sort_by(|a, b| b.size.total_cmp(&a.size));
+
+    Self {
+      assets,
+      chunks,
+      modules,
+      chunk_membership,
+      duplicated_modules,
+    }
+  }
+
+  pub fn is_empty(&self) -> bool {
+    self.assets.is_empty()
+      && self.chunks.is_empty()
+      && self.modules.is_empty()
+      && self.chunk_membership.is_empty()
+      && self.duplicated_modules.is_empty()
+  }
+
+  pub fn to_json(&self) -> Result<String> {
+    serde_json::to_string_pretty(self)
+      .map_err(|err| error!("failed to serialize stats diff: {err}"))
+  }
+}
+
+/// Module names of each chunk, sorted
+fn invert<'a>(
+  module_chunks: &'a BTreeMap<&'a str, Vec<String>>,
+) -> BTreeMap<&'a str, Vec<&'a str>> {
+  let mut chunks: BTreeMap<&str, Vec<&str>> = BTreeMap::new();
+  for (module, module_chunks) in module_chunks {
+    for chunk in module_chunks {
+      chunks.entry(chunk.as_str()).or_default().push(module);
+    }
+  }
+  chunks
+}
+
+fn format_delta(delta: f64) -> String {
+  if delta < 0.0 {
+    format!("-{}", format_size(-delta))
+  } else {
+    format!("+{}", format_size(delta))
+  }
+}
+
+fn write_entries(f: &mut fmt::Formatter, title: &str, diff: &EntriesDiff) -> fmt::Result {
+  if diff.is_empty() {
+    return Ok(());
+  }
+  writeln!(
+    f,
+    "{title}: {} added, {} removed, {} changed ({})",
+    diff.added.len(),
+    diff.removed.len(),
+    diff.changed.len(),
+    format_delta(diff.delta())
+  )?;
+  for entry in &diff.added {
+    writeln!(f, "  + {} ({})", entry.name, format_size(entry.size))?;
+  }
+  for entry in &diff.removed {
+    writeln!(f, "  - {} ({})", entry.name, format_size(entry.size))?;
+  }
+  for change in &diff.changed {
+    writeln!(
+      f,
+      "  ~ {} ({} -> {}, {})",
+      change.name,
+      format_size(change.before),
+      format_size(change.after),
+      format_delta(change.delta)
+    )?;
+  }
+  Ok(())
+}
+
+impl fmt::Display for StatsDiff {
+  fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+    if self.is_empty() {
+      return writeln!(f, "No differences");
+    }
+    write_entries(f, "assets", &self.assets)?;
+    write_entries(f, "chunks", &self.chunks)?;
+    write_entries(f, "modules", &self.modules)?;
+    if !self.chunk_membership.is_empty() {
+      writeln!(
+        f,
+        "chunk membership: {} changed",
+        self.chunk_membership.len()
+      )?;
+      for change in &self.chunk_membership {
+        writeln!(f, "  {}", change.chunk)?;
+        for module in &change.added_modules {
+          writeln!(f, "    + {module}")?;
+        }
+        for module in &change.removed_modules {
+          writeln!(f, "    - {module}")?;
+        }
+      }
+    }
+    if !self.duplicated_modules.is_empty() {
+      writeln!(
+        f,
+        "duplicated modules: {} new",
+        self.duplicated_modules.len()
+      )?;
+      for module in &self.duplicated_modules {
+        writeln!(
+          f,
+          "  {} ({}) in {}",
+          module.name,
+          format_size(module.size),
+          module.chunks.join(", ")
+        )?;
+      }
+    }
+    Ok(())
+  }
+}
+
+/// Compare the stats json of a base build with the current build
+pub fn diff_stats(base: &str, current: &str) -> Result<StatsDiff> {
+  Ok(StatsDiff::new(
+    &StatsJson::from_json(base)?,
+    &StatsJson::from_json(current)?,
+  ))
+}
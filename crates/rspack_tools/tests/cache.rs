@@ -0,0 +1,122 @@
+#[cfg(test)]
+mod tests {
+  use std::sync::Arc;
+
+  use rspack::builder::Builder as _;
+  use rspack_core::{
+    CacheOptions, Compiler, Mode,
+    cache::persistent::{
+      PersistentCacheOptions,
+      codec::CacheCodec,
+      occasion::MetaOccasion,
+      storage::{StorageCompression, StorageOptions, create_storage},
+    },
+  };
+  use rspack_fs::{MemoryFileSystem, NativeFileSystem};
+  use rspack_paths::{Utf8Path, Utf8PathBuf};
+  use rspack_storage::PackCompression;
+  use rspack_tasks::within_compiler_context_for_testing;
+  use rspack_tools::{compare_cache_dir, dump_cache_key, inspect_cache_dir, prune_cache_dir};
+
+  fn create_cache_dir(name: &str) -> Utf8PathBuf {
+    let dir = Utf8PathBuf::from_path_buf(std::env::temp_dir())
+      .expect("should be utf8 path")
+      .join("rspack_test/tools")
+      .join(name);
+    let _ = std::fs::remove_dir_all(&dir);
+    dir
+  }
+
+  fn compressed_storage_options(directory: &Utf8Path) -> StorageOptions {
+    StorageOptions::FileSystem {
+      directory: directory.as_std_path().to_path_buf(),
+      compression: Some(StorageCompression::Zstd),
+      max_size: None,
+      seed_directory: None,
+    }
+  }
+
+  /// Build the fixture with a compressed persistent cache, returns the identifier of the entry module
+  async fn build(cache_dir: &Utf8Path) -> String {
+    let mut compiler = Compiler::builder()
+      .context(Utf8Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/fixtures/cache"))
+      .mode(Mode::None)
+      .entry("main", "./src/index.js")
+      .cache(CacheOptions::Persistent(PersistentCacheOptions {
+        build_dependencies: vec![],
+        version: String::new(),
+        snapshot: Default::default(),
+        storage: compressed_storage_options(cache_dir),
+        portable: false,
+        readonly: false,
+        explain: false,
+      }))
+      .output_filesystem(Arc::new(MemoryFileSystem::default()))
+      .build()
+      .unwrap();
+    compiler.build().await.unwrap();
+    assert!(compiler.compilation.get_errors().next().is_none());
+    compiler
+      .compilation
+      .get_module_graph()
+      .modules_keys()
+      .find(|id| id.ends_with("index.js"))
+      .expect("should have entry module")
+      .to_string()
+  }
+
+  #[tokio::test(flavor = "multi_thread")]
+  async fn inspect_dump_and_prune_compressed_cache() {
+    let cache_dir = create_cache_dir("compressed");
+    let entry = within_compiler_context_for_testing(build(&cache_dir)).await;
+
+    let report = inspect_cache_dir(cache_dir.clone()).await.unwrap();
+    assert!(report.unknown.is_empty());
+    assert_eq!(report.versions.len(), 1);
+    let version = &report.versions[0];
+    assert!(!version.scopes.is_empty());
+    for scope in &version.scopes {
+      let stats = scope.stats.as_ref().expect("should read scope stats");
+      assert_eq!(stats.compression, PackCompression::Zstd, "{}", scope.name);
+    }
+
+    let output = within_compiler_context_for_testing(dump_cache_key(
+      cache_dir.clone(),
+      &version.version,
+      &entry,
+    ))
+    .await
+    .unwrap();
+    assert!(output.starts_with(&format!("identifier: {entry}\n")));
+    assert!(output.contains("./value"));
+
+    assert!(prune_cache_dir(cache_dir.clone()).await.unwrap().is_empty());
+    assert!(cache_dir.join(&version.version).exists());
+  }
+
+  #[tokio::test(flavor = "multi_thread")]
+  async fn compare_compressed_cache() {
+    let cache_dir = create_cache_dir("compare");
+    for name in ["a", "b"] {
+      within_compiler_context_for_testing(async {
+        let storage = create_storage(
+          compressed_storage_options(&cache_dir.join(name).join("rspack")),
+          "version".to_string(),
+          Arc::new(NativeFileSystem::new(false)),
+        );
+        MetaOccasion::new(storage.clone(), Arc::new(CacheCodec::new(None))).save();
+        storage
+          .trigger_save()
+          .unwrap()
+          .await
+          .expect("should save")
+          .unwrap();
+      })
+      .await;
+    }
+
+    compare_cache_dir(cache_dir.join("a"), cache_dir.join("b"))
+      .await
+      .unwrap();
+  }
+}
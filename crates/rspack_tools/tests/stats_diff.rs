@@ -0,0 +1,116 @@
+#[cfg(test)]
+mod tests {
+  use rspack_paths::Utf8Path;
+  use rspack_tools::{SizeEntry, StatsDiff, diff_stats};
+
+  fn read_fixture(name: &str) -> String {
+    std::fs::read_to_string(
+      Utf8Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("tests/fixtures/stats")
+        .join(name),
+    )
+    .unwrap()
+  }
+
+  fn diff() -> StatsDiff {
+    diff_stats(&read_fixture("base.json"), &read_fixture("current.json")).unwrap()
+  }
+
+  fn names(entries: &[SizeEntry]) -> Vec<&str> {
+    entries.iter().map(|entry| entry.name.as_str()).collect()
+  }
+
+  #[test]
+  fn diff_entries() {
+    let diff = diff();
+    assert_eq!(names(&diff.assets.added), vec!["new.js"]);
+    assert_eq!(names(&diff.assets.removed), vec!["old.js"]);
+    assert_eq!(diff.assets.changed.len(), 1);
+    let change = &diff.assets.changed[0];
+    assert_eq!(change.name, "main.js");
+    assert_eq!(
+      (change.before, change.after, change.delta),
+      (1000.0, 1200.0, 200.0)
+    );
+
+    assert_eq!(names(&diff.chunks.added), vec!["async"]);
+    assert_eq!(names(&diff.chunks.removed), vec!["removed"]);
+
+    assert_eq!(names(&diff.modules.added), vec!["./c.js"]);
+    assert_eq!(names(&diff.modules.removed), vec!["./gone.js"]);
+    assert_eq!(diff.modules.changed.len(), 1);
+    assert_eq!(diff.modules.changed[0].name, "./b.js");
+    assert_eq!(diff.modules.changed[0].delta, 50.0);
+  }
+
+  #[test]
+  fn diff_unnamed_chunks_by_id() {
+    let diff = diff();
+    let changed = diff
+      .chunks
+      .changed
+      .iter()
+      .map(|change| (change.name.as_str(), change.delta))
+      .collect::<Vec<_>>();
+    // sorted by the absolute delta
+    assert_eq!(
+      changed,
+      vec![("main", 100.0), ("other", -100.0), ("1", 80.0)]
+    );
+  }
+
+  #[test]
+  fn diff_chunk_membership() {
+    let membership = diff()
+      .chunk_membership
+      .into_iter()
+      .map(|change| (change.chunk, change.added_modules, change.removed_modules))
+      .collect::<Vec<_>>();
+    let modules = |modules: &[&str]| modules.iter().map(ToString::to_string).collect::<Vec<_>>();
+    assert_eq!(
+      membership,
+      vec![
+        ("1".to_string(), modules(&["./c.js", "./shared.js"]), vec![]),
+        (
+          "main".to_string(),
+          modules(&["./other.js"]),
+          modules(&["./gone.js"])
+        ),
+        // the chunk lost all of its modules, but still exists
+        ("other".to_string(), vec![], modules(&["./other.js"])),
+      ]
+    );
+  }
+
+  #[test]
+  fn diff_duplicated_modules() {
+    let diff = diff();
+    assert_eq!(diff.duplicated_modules.len(), 1);
+    let module = &diff.duplicated_modules[0];
+    assert_eq!(module.name, "./shared.js");
+    assert_eq!(module.size, 50.0);
+    assert_eq!(module.chunks, vec!["1", "main"]);
+
+    // already duplicated in the base build
+    let current = read_fixture("current.json");
+    assert!(
+      diff_stats(&current, &current)
+        .unwrap()
+        .duplicated_modules
+        .is_empty()
+    );
+  }
+
+  #[test]
+  fn diff_same_stats() {
+    let base = read_fixture("base.json");
+    let diff = diff_stats(&base, &base).unwrap();
+    assert!(diff.is_empty());
+    assert_eq!(diff.to_string(), "No differences\n");
+  }
+
+  #[test]
+  fn diff_output() {
+    insta::assert_snapshot!(diff().to_string());
+  }
+}
@@ -72,14 +72,19 @@ impl DiskWatcher {
 
   /// Watches the given path with the specified recursive mode.
   ///
+  /// If `allow_failure` is true, the paths failed to be watched are skipped and returned,
+  /// they will be retried in the next call.
+  ///
   /// # Returns
   ///
-  /// * `rspack_error::Result<()>` - Ok if successful, otherwise an error.
+  /// * `rspack_error::Result<HashSet<ArcPath>>` - The paths failed to be watched if successful, otherwise an error.
   pub fn watch(
     &mut self,
     patterns: impl Iterator<Item = WatchPattern>,
-  ) -> rspack_error::Result<()> {
+    allow_failure: bool,
+  ) -> rspack_error::Result<HashSet<ArcPath>> {
     let patterns: HashSet<WatchPattern> = patterns.collect();
+    let mut failed_paths = HashSet::default();
 
     let already_watched_paths = self
       .watch_patterns
@@ -109,16 +114,20 @@ impl DiskWatcher {
         continue;
       }
 
-      if let Some(watcher) = &mut self.inner {
-        watcher
-          .watch(&pattern.path, pattern.mode)
-          .map_err(|e| rspack_error::error!(e.to_string()))?;
+      if let Some(watcher) = &mut self.inner
+        && let Err(e) = watcher.watch(&pattern.path, pattern.mode)
+      {
+        if !allow_failure {
+          return Err(rspack_error::error!(e.to_string()));
+        }
+        failed_paths.insert(pattern.path);
+        continue;
       }
 
       self.watch_patterns.insert(pattern);
     }
 
-    Ok(())
+    Ok(failed_paths)
   }
 
   pub fn close(&mut self) {
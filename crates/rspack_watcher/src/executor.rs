@@ -1,6 +1,9 @@
-use std::sync::{
-  Arc,
-  atomic::{AtomicBool, Ordering},
+use std::{
+  sync::{
+    Arc,
+    atomic::{AtomicBool, Ordering},
+  },
+  time::Instant,
 };
 
 use rspack_util::fx_hash::FxHashSet as HashSet;
@@ -10,7 +13,7 @@ use tokio::sync::{
 };
 
 use super::{EventAggregateHandler, EventHandler, FsEventKind};
-use crate::EventBatch;
+use crate::{EventBatch, EventBatchStats};
 
 type ThreadSafetyReceiver<T> = ThreadSafety<UnboundedReceiver<T>>;
 type ThreadSafety<T> = Arc<Mutex<T>>;
@@ -19,6 +22,10 @@ type ThreadSafety<T> = Arc<Mutex<T>>;
 struct FilesData {
   changed: HashSet<String>,
   deleted: HashSet<String>,
+  /// The number of raw events coalesced into the changed and deleted files.
+  raw_events: usize,
+  /// The time when the first event of this batch was received.
+  first_event_at: Option<Instant>,
 }
 
 impl FilesData {
@@ -33,6 +40,7 @@ impl FilesData {
 /// deleted files, and coordinates the event handling logic.
 pub struct Executor {
   aggregate_timeout: u32,
+  debug: bool,
   rx: ThreadSafetyReceiver<EventBatch>,
   files_data: ThreadSafety<FilesData>,
   exec_aggregate_tx: UnboundedSender<ExecAggregateEvent>,
@@ -66,7 +74,13 @@ enum ExecEvent {
 
 impl Executor {
   /// Create a new `WatcherExecutor` with the given receiver and optional aggregate timeout.
-  pub fn new(rx: UnboundedReceiver<EventBatch>, aggregate_timeout: Option<u32>) -> Self {
+  ///
+  /// If `debug` is true, the statistics of each aggregated batch are logged.
+  pub fn new(
+    rx: UnboundedReceiver<EventBatch>,
+    aggregate_timeout: Option<u32>,
+    debug: bool,
+  ) -> Self {
     let (exec_aggregate_tx, exec_aggregate_rx) = mpsc::unbounded_channel::<ExecAggregateEvent>();
     let (exec_tx, exec_rx) = mpsc::unbounded_channel::<ExecEvent>();
 
@@ -83,6 +97,7 @@ impl Executor {
       execute_aggregate_handle: None,
       execute_handle: None,
       aggregate_timeout: aggregate_timeout.unwrap_or(DEFAULT_AGGREGATE_TIMEOUT),
+      debug,
     }
   }
 
@@ -138,15 +153,18 @@ impl Executor {
         while let Some(events) = rx.lock().await.recv().await {
           for event in &events {
             let path = event.path.to_string_lossy().to_string();
+            let mut files_data = files_data.lock().await;
+            files_data.raw_events += 1;
+            files_data.first_event_at.get_or_insert_with(Instant::now);
             match event.kind {
               FsEventKind::Change => {
-                files_data.lock().await.changed.insert(path);
+                files_data.changed.insert(path);
               }
               FsEventKind::Remove => {
-                files_data.lock().await.deleted.insert(path);
+                files_data.deleted.insert(path);
               }
               FsEventKind::Create => {
-                files_data.lock().await.changed.insert(path);
+                files_data.changed.insert(path);
               }
             }
           }
@@ -184,6 +202,7 @@ impl Executor {
       Arc::clone(&self.files_data),
       self.aggregate_timeout as u64,
       Arc::clone(&self.aggregate_running),
+      self.debug,
     ));
 
     self.execute_handle = Some(create_execute_task(
@@ -233,6 +252,7 @@ fn create_execute_aggregate_task(
   files: ThreadSafety<FilesData>,
   aggregate_timeout: u64,
   running: Arc<AtomicBool>,
+  debug: bool,
 ) -> tokio::task::JoinHandle<()> {
   let future = async move {
     loop {
@@ -248,8 +268,10 @@ fn create_execute_aggregate_task(
 
       if let ExecAggregateEvent::Execute = aggregate_rx {
         running.store(true, Ordering::Relaxed);
+        let wait_start = Instant::now();
         // Wait for the aggregate timeout before executing the handler
         tokio::time::sleep(tokio::time::Duration::from_millis(aggregate_timeout)).await;
+        let aggregate_wait = wait_start.elapsed();
 
         // Get the files to process
         let files = {
@@ -261,6 +283,21 @@ fn create_execute_aggregate_task(
           std::mem::take(&mut *files)
         };
 
+        let stats = EventBatchStats {
+          raw_events: files.raw_events,
+          changed_files: files.changed.len(),
+          deleted_files: files.deleted.len(),
+          aggregate_wait,
+          latency: files
+            .first_event_at
+            .map(|first_event_at| first_event_at.elapsed())
+            .unwrap_or_default(),
+        };
+        if debug {
+          tracing::debug!("{stats}");
+        }
+        event_handler.on_batch_stats(stats);
+
         // Call the event handler with the changed and deleted files
         event_handler.on_event_handle(files.changed, files.deleted);
         running.store(false, Ordering::Relaxed);
@@ -42,4 +42,41 @@ impl FsWatcherIgnored {
       FsWatcherIgnored::Regex(reg) => reg.test(&normalize_path(p)),
     }
   }
+
+  /// Returns the pattern which ignores the path, used to explain why a path is ignored.
+  pub fn ignored_by(&self, p: &str) -> Option<String> {
+    let p = normalize_path(p);
+    match self {
+      FsWatcherIgnored::None => None,
+      FsWatcherIgnored::Path(path) => glob_match(path, p.as_bytes()).then(|| path.clone()),
+      FsWatcherIgnored::Paths(paths) => paths
+        .iter()
+        .find(|path| glob_match(path, p.as_bytes()))
+        .cloned(),
+      FsWatcherIgnored::Regex(reg) => reg.test(&p).then(|| reg.to_source_string()),
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn test_ignored_by() {
+    let ignored = FsWatcherIgnored::Paths(vec!["**/node_modules/**".into(), "**/*.log".into()]);
+    assert_eq!(
+      ignored.ignored_by("/project/node_modules/lib/index.js"),
+      Some("**/node_modules/**".into())
+    );
+    assert_eq!(
+      ignored.ignored_by("C:\\project\\debug.log"),
+      Some("**/*.log".into())
+    );
+    assert_eq!(ignored.ignored_by("/project/src/index.js"), None);
+    assert_eq!(
+      FsWatcherIgnored::None.ignored_by("/project/debug.log"),
+      None
+    );
+  }
 }
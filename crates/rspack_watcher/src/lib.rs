@@ -3,16 +3,21 @@ mod disk_watcher;
 mod executor;
 mod ignored;
 mod paths;
+mod poller;
 mod scanner;
 mod trigger;
 
-use std::{sync::Arc, time::SystemTime};
+use std::{
+  sync::Arc,
+  time::{Duration, SystemTime},
+};
 
 use analyzer::{Analyzer, RecommendedAnalyzer};
 use disk_watcher::DiskWatcher;
 use executor::Executor;
 pub use ignored::FsWatcherIgnored;
 use paths::PathManager;
+use poller::{PollTargets, Poller};
 use rspack_error::Result;
 use rspack_paths::ArcPath;
 use rspack_util::fx_hash::FxHashSet as HashSet;
@@ -41,6 +46,35 @@ pub(crate) struct FsEvent {
 
 pub(crate) type EventBatch = Vec<FsEvent>;
 
+/// `EventBatchStats` describes how the raw file system events are coalesced into an aggregated batch.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct EventBatchStats {
+  /// The number of raw events received for this batch, including the duplicated ones.
+  pub raw_events: usize,
+  /// The number of unique changed files in this batch.
+  pub changed_files: usize,
+  /// The number of unique deleted files in this batch.
+  pub deleted_files: usize,
+  /// The time waited for the aggregate timeout.
+  pub aggregate_wait: Duration,
+  /// The time from the first event of this batch to the aggregated handler being called.
+  pub latency: Duration,
+}
+
+impl std::fmt::Display for EventBatchStats {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    write!(
+      f,
+      "coalesced {} events into {} changed and {} deleted files, aggregate wait {}ms, latency {}ms",
+      self.raw_events,
+      self.changed_files,
+      self.deleted_files,
+      self.aggregate_wait.as_millis(),
+      self.latency.as_millis()
+    )
+  }
+}
+
 /// `EventAggregateHandler` is a trait for handling aggregated file system events.
 /// It provides methods to handle changes and deletions of files, as well as errors.
 /// Implementors of this trait can define custom behavior for these events.
@@ -51,6 +85,11 @@ pub trait EventAggregateHandler {
   /// Handle a batch of file system events.
   fn on_event_handle(&self, _changed_files: HashSet<String>, _deleted_files: HashSet<String>);
 
+  /// Receive the statistics of a batch, it is called right before `on_event_handle`.
+  fn on_batch_stats(&self, _stats: EventBatchStats) {
+    // Default implementation does nothing.
+  }
+
   /// Handle an error that occurs during file system watching.
   fn on_error(&self, _error: rspack_error::Error) {
     // Default implementation does nothing.
@@ -71,6 +110,19 @@ pub trait EventHandler {
   }
 }
 
+/// `FsWatcherMode` decides how the file system watcher detects changes.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum FsWatcherMode {
+  /// Watch the paths with the native events of the OS.
+  #[default]
+  Native,
+  /// Poll the stats of the paths, for file systems without native events,
+  /// e.g. Docker bind mounts, NFS and some WSL setups.
+  Polling,
+  /// Watch the paths natively, and poll the paths where native watching failed.
+  Hybrid,
+}
+
 /// `FsWatcherOptions` contains options for configuring the file system watcher.
 #[derive(Debug, Default)]
 pub struct FsWatcherOptions {
@@ -80,13 +132,22 @@ pub struct FsWatcherOptions {
   /// The interval in milliseconds to poll for changes.
   pub poll_interval: Option<u32>,
 
+  /// How to detect the changes of the watched paths.
+  pub mode: FsWatcherMode,
+
   /// The timeout in milliseconds to aggregate events.
   pub aggregate_timeout: Option<u32>,
+
+  /// Whether to log the statistics of each batch and the paths skipped by the ignored patterns,
+  /// the logs are emitted as `tracing` events at the debug level.
+  pub debug: bool,
 }
 
 pub struct FsWatcher {
   path_manager: Arc<PathManager>,
   disk_watcher: DiskWatcher,
+  poller: Poller,
+  mode: FsWatcherMode,
   executor: Executor,
   scanner: Scanner,
   analyzer: RecommendedAnalyzer,
@@ -98,18 +159,25 @@ impl FsWatcher {
   pub fn new(options: FsWatcherOptions, ignored: FsWatcherIgnored) -> Self {
     let (tx, rx) = mpsc::unbounded_channel();
 
-    let path_manager = Arc::new(PathManager::new(ignored));
+    let path_manager = Arc::new(PathManager::new(ignored, options.debug));
     let trigger = Arc::new(Trigger::new(Arc::clone(&path_manager), tx.clone()));
     let disk_watcher = DiskWatcher::new(
       options.follow_symlinks,
       options.poll_interval,
       trigger.clone(),
     );
-    let executor = Executor::new(rx, options.aggregate_timeout);
+    let poller = Poller::new(
+      options.poll_interval,
+      Arc::clone(&path_manager),
+      trigger.clone(),
+    );
+    let executor = Executor::new(rx, options.aggregate_timeout, options.debug);
     let scanner = Scanner::new(tx, Arc::clone(&path_manager));
 
     Self {
       disk_watcher,
+      poller,
+      mode: options.mode,
       executor,
       path_manager,
       scanner,
@@ -151,6 +219,7 @@ impl FsWatcher {
   /// Closes the file system watcher, stopping all background tasks and releasing resources.
   pub async fn close(&mut self) -> Result<()> {
     self.disk_watcher.close();
+    self.poller.close();
     self.scanner.close();
     self.executor.close().await;
     self.trigger.take();
@@ -164,6 +233,11 @@ impl FsWatcher {
     }
   }
 
+  /// Returns the pattern which ignores the given path, or `None` if the path is not ignored.
+  pub fn explain_ignored(&self, path: &str) -> Option<String> {
+    self.path_manager.ignored.ignored_by(path)
+  }
+
   /// Pauses the file system watcher, stopping the execution of the event loop.
   pub fn pause(&self) -> Result<()> {
     self.executor.pause();
@@ -181,8 +255,24 @@ impl FsWatcher {
     self.path_manager.update(files, directories, missing)?;
     self.scanner.scan(start_time);
 
-    let watch_patterns = self.analyzer.analyze(self.path_manager.access());
-    self.disk_watcher.watch(watch_patterns.into_iter())?;
+    match self.mode {
+      FsWatcherMode::Native => {
+        let watch_patterns = self.analyzer.analyze(self.path_manager.access());
+        self.disk_watcher.watch(watch_patterns.into_iter(), false)?;
+      }
+      FsWatcherMode::Polling => {
+        self.poller.watch(PollTargets::All);
+      }
+      FsWatcherMode::Hybrid => {
+        let watch_patterns = self.analyzer.analyze(self.path_manager.access());
+        let failed_paths = self.disk_watcher.watch(watch_patterns.into_iter(), true)?;
+        self.poller.watch(if failed_paths.is_empty() {
+          PollTargets::None
+        } else {
+          PollTargets::Roots(failed_paths)
+        });
+      }
+    }
 
     Ok(())
   }
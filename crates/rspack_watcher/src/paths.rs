@@ -111,12 +111,23 @@ where
 
 impl PathUpdater {
   /// Update the paths in the given set.
-  fn update(self, watch_tracker: &PathTracker, ignored: &FsWatcherIgnored) -> Result<()> {
+  ///
+  /// If `debug` is true, the ignored paths are logged with the patterns ignoring them.
+  fn update(
+    self,
+    watch_tracker: &PathTracker,
+    ignored: &FsWatcherIgnored,
+    debug: bool,
+  ) -> Result<()> {
     let added_paths = self.added;
     let removed_paths = self.removed;
 
     for added in added_paths {
-      if ignored.should_be_ignored(added.to_str().expect("Path should be valid UTF-8")) {
+      let added_str = added.to_str().expect("Path should be valid UTF-8");
+      if ignored.should_be_ignored(added_str) {
+        if debug && let Some(pattern) = ignored.ignored_by(added_str) {
+          tracing::debug!("{added_str} is ignored by pattern {pattern}");
+        }
         continue; // Skip ignored paths
       }
 
@@ -186,16 +197,19 @@ pub(crate) struct PathManager {
   directories: PathTracker,
   missing: PathTracker,
   pub ignored: FsWatcherIgnored,
+  /// Whether to log the ignored paths.
+  debug: bool,
 }
 
 impl PathManager {
   /// Create a new `PathManager` with an optional ignored paths filter.
-  pub fn new(ignored: FsWatcherIgnored) -> Self {
+  pub fn new(ignored: FsWatcherIgnored, debug: bool) -> Self {
     Self {
       files: PathTracker::default(),
       directories: PathTracker::default(),
       missing: PathTracker::default(),
       ignored,
+      debug,
     }
   }
 
@@ -213,9 +227,9 @@ impl PathManager {
     directories: (impl Iterator<Item = ArcPath>, impl Iterator<Item = ArcPath>),
     missing: (impl Iterator<Item = ArcPath>, impl Iterator<Item = ArcPath>),
   ) -> Result<()> {
-    PathUpdater::from(files).update(&self.files, &self.ignored)?;
-    PathUpdater::from(directories).update(&self.directories, &self.ignored)?;
-    PathUpdater::from(missing).update(&self.missing, &self.ignored)?;
+    PathUpdater::from(files).update(&self.files, &self.ignored, self.debug)?;
+    PathUpdater::from(directories).update(&self.directories, &self.ignored, self.debug)?;
+    PathUpdater::from(missing).update(&self.missing, &self.ignored, self.debug)?;
 
     Ok(())
   }
@@ -250,7 +264,7 @@ mod tests {
 
     let path_tracker = PathTracker::default();
 
-    updater.update(&path_tracker, &ignored).unwrap();
+    updater.update(&path_tracker, &ignored, false).unwrap();
 
     let all = path_tracker.all;
 
@@ -305,7 +319,7 @@ mod tests {
       "**/node_modules/**".to_string(),
       "**/.git/**".to_string(),
     ]);
-    let path_manager = PathManager::new(ignored);
+    let path_manager = PathManager::new(ignored, false);
     let files = (
       vec![ArcPath::from(Utf8Path::new("src/index.js"))].into_iter(),
       vec![].into_iter(),
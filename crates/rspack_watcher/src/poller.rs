@@ -0,0 +1,296 @@
+use std::{
+  sync::{Arc, Mutex},
+  time::Duration,
+};
+
+use rspack_fs::FileMetadata;
+use rspack_paths::ArcPath;
+use rspack_util::fx_hash::{FxHashMap as HashMap, FxHashSet as HashSet};
+use tokio::time::MissedTickBehavior;
+
+use crate::{FsEventKind, paths::PathManager, trigger::Trigger};
+
+const DEFAULT_POLL_INTERVAL: u32 = 1000; // Default interval in milliseconds
+
+/// The paths polled by the [`Poller`].
+#[derive(Debug, Default, Clone)]
+pub(crate) enum PollTargets {
+  /// Poll nothing.
+  #[default]
+  None,
+  /// Poll all of the registered paths.
+  All,
+  /// Poll the registered paths under these roots, e.g. the roots failed to be watched natively.
+  Roots(HashSet<ArcPath>),
+}
+
+impl PollTargets {
+  fn contains(&self, path: &ArcPath) -> bool {
+    match self {
+      Self::None => false,
+      Self::All => true,
+      Self::Roots(roots) => roots.iter().any(|root| path.starts_with(root)),
+    }
+  }
+}
+
+/// The part of the file metadata used to detect changes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct Stat {
+  mtime_ms: u64,
+  size: u64,
+  is_directory: bool,
+}
+
+impl Stat {
+  fn read(path: &ArcPath) -> Option<Self> {
+    let metadata = FileMetadata::try_from(std::fs::metadata(path).ok()?).ok()?;
+    Some(Self {
+      mtime_ms: metadata.mtime_ms,
+      size: metadata.size,
+      is_directory: metadata.is_directory,
+    })
+  }
+}
+
+#[derive(Debug, Default)]
+struct PollState {
+  targets: PollTargets,
+  /// The stats of the last poll, `None` until the baseline is collected.
+  stats: Option<HashMap<ArcPath, Stat>>,
+  /// Increased by each `watch`, so the scans started before it are dropped.
+  generation: usize,
+}
+
+/// `Poller` detects the changes of the registered paths by comparing their stats periodically,
+/// for the file systems where native events never arrive, e.g. Docker bind mounts and NFS.
+///
+/// The changes are reported through the same [`Trigger`] as the native events, so they produce
+/// identical event batches. For directories, only their direct entries are compared.
+pub struct Poller {
+  interval: Duration,
+  path_manager: Arc<PathManager>,
+  trigger: Arc<Trigger>,
+  state: Arc<Mutex<PollState>>,
+  handle: Option<tokio::task::JoinHandle<()>>,
+}
+
+impl Poller {
+  pub fn new(
+    poll_interval: Option<u32>,
+    path_manager: Arc<PathManager>,
+    trigger: Arc<Trigger>,
+  ) -> Self {
+    Self {
+      interval: Duration::from_millis(u64::from(poll_interval.unwrap_or(DEFAULT_POLL_INTERVAL))),
+      path_manager,
+      trigger,
+      state: Default::default(),
+      handle: None,
+    }
+  }
+
+  /// Polls the given targets, the current stats are used as the baseline.
+  pub fn watch(&mut self, targets: PollTargets) {
+    let is_none = matches!(targets, PollTargets::None);
+    let generation = {
+      let mut state = self.state.lock().expect("should get lock");
+      let generation = state.generation + 1;
+      *state = PollState {
+        targets: targets.clone(),
+        stats: is_none.then(HashMap::default),
+        generation,
+      };
+      generation
+    };
+    if is_none {
+      return;
+    }
+
+    // the baseline scan calls the blocking fs apis as the polls, so it runs on a blocking thread
+    let path_manager = Arc::clone(&self.path_manager);
+    let state = Arc::clone(&self.state);
+    tokio::task::spawn_blocking(move || {
+      let stats = collect_stats(&path_manager, &targets);
+      let mut state = state.lock().expect("should get lock");
+      // a poll finished earlier has set the baseline
+      if state.generation == generation && state.stats.is_none() {
+        state.stats = Some(stats);
+      }
+    });
+
+    if self.handle.is_none() {
+      self.handle = Some(self.spawn());
+    }
+  }
+
+  fn spawn(&self) -> tokio::task::JoinHandle<()> {
+    let path_manager = Arc::clone(&self.path_manager);
+    let trigger = Arc::clone(&self.trigger);
+    let state = Arc::clone(&self.state);
+    let interval = self.interval;
+
+    tokio::spawn(async move {
+      let mut interval = tokio::time::interval(interval);
+      interval.set_missed_tick_behavior(MissedTickBehavior::Delay);
+      // the first tick completes immediately
+      interval.tick().await;
+      loop {
+        interval.tick().await;
+        let (generation, targets) = {
+          let state = state.lock().expect("should get lock");
+          (state.generation, state.targets.clone())
+        };
+        // the scan calls the blocking fs apis, so it runs outside of the lock and the runtime
+        let path_manager = Arc::clone(&path_manager);
+        let Ok(stats) =
+          tokio::task::spawn_blocking(move || collect_stats(&path_manager, &targets)).await
+        else {
+          continue;
+        };
+        let events = {
+          let mut state = state.lock().expect("should get lock");
+          if state.generation != generation {
+            continue;
+          }
+          let events = state
+            .stats
+            .as_ref()
+            .map(|prev| diff_stats(prev, &stats))
+            .unwrap_or_default();
+          state.stats = Some(stats);
+          events
+        };
+        for (path, kind) in events {
+          trigger.on_event(&path, kind);
+        }
+      }
+    })
+  }
+
+  pub fn close(&mut self) {
+    if let Some(handle) = self.handle.take() {
+      handle.abort();
+    }
+  }
+}
+
+/// Read the stats of the registered paths in targets, and the direct entries of the registered directories.
+fn collect_stats(path_manager: &PathManager, targets: &PollTargets) -> HashMap<ArcPath, Stat> {
+  let mut stats = HashMap::default();
+  if matches!(targets, PollTargets::None) {
+    return stats;
+  }
+
+  let accessor = path_manager.access();
+  for path in accessor.files().0.iter().chain(accessor.missing().0.iter()) {
+    let path: &ArcPath = &path;
+    if targets.contains(path)
+      && let Some(stat) = Stat::read(path)
+    {
+      stats.insert(path.clone(), stat);
+    }
+  }
+
+  for dir in accessor.directories().0.iter() {
+    let dir: &ArcPath = &dir;
+    if !targets.contains(dir) {
+      continue;
+    }
+    let Some(stat) = Stat::read(dir) else {
+      continue;
+    };
+    stats.insert(dir.clone(), stat);
+    if !stat.is_directory {
+      continue;
+    }
+    let Ok(entries) = std::fs::read_dir(dir) else {
+      continue;
+    };
+    for entry in entries.flatten() {
+      let path = ArcPath::from(entry.path());
+      if path_manager
+        .ignored
+        .should_be_ignored(&path.to_string_lossy())
+      {
+        continue;
+      }
+      if let Some(stat) = Stat::read(&path) {
+        stats.insert(path, stat);
+      }
+    }
+  }
+  stats
+}
+
+/// Compare the stats of two polls, and return the events of changed paths.
+fn diff_stats(
+  prev: &HashMap<ArcPath, Stat>,
+  current: &HashMap<ArcPath, Stat>,
+) -> Vec<(ArcPath, FsEventKind)> {
+  let mut events = vec![];
+  for (path, stat) in current {
+    match prev.get(path) {
+      None => events.push((path.clone(), FsEventKind::Create)),
+      Some(prev_stat) if prev_stat != stat => events.push((path.clone(), FsEventKind::Change)),
+      _ => {}
+    }
+  }
+  for path in prev.keys() {
+    if !current.contains_key(path) {
+      events.push((path.clone(), FsEventKind::Remove));
+    }
+  }
+  events
+}
+
+#[cfg(test)]
+mod tests {
+  use std::path::Path;
+
+  use super::*;
+
+  fn stat(mtime_ms: u64) -> Stat {
+    Stat {
+      mtime_ms,
+      size: 1,
+      is_directory: false,
+    }
+  }
+
+  #[test]
+  fn test_diff_stats() {
+    let a = ArcPath::from(Path::new("/a"));
+    let b = ArcPath::from(Path::new("/b"));
+    let c = ArcPath::from(Path::new("/c"));
+    let d = ArcPath::from(Path::new("/d"));
+
+    let mut prev = HashMap::default();
+    prev.insert(a.clone(), stat(1));
+    prev.insert(b.clone(), stat(1));
+    prev.insert(c.clone(), stat(1));
+    let mut current = HashMap::default();
+    current.insert(a, stat(1));
+    current.insert(b.clone(), stat(2));
+    current.insert(d.clone(), stat(1));
+
+    let events = diff_stats(&prev, &current);
+    assert_eq!(events.len(), 3);
+    assert!(events.contains(&(b, FsEventKind::Change)));
+    assert!(events.contains(&(c, FsEventKind::Remove)));
+    assert!(events.contains(&(d, FsEventKind::Create)));
+  }
+
+  #[test]
+  fn test_poll_targets() {
+    let roots = PollTargets::Roots(
+      [ArcPath::from(Path::new("/mnt/project"))]
+        .into_iter()
+        .collect(),
+    );
+    assert!(roots.contains(&ArcPath::from(Path::new("/mnt/project/src/index.js"))));
+    assert!(!roots.contains(&ArcPath::from(Path::new("/home/project/src/index.js"))));
+    assert!(PollTargets::All.contains(&ArcPath::from(Path::new("/a"))));
+    assert!(!PollTargets::None.contains(&ArcPath::from(Path::new("/a"))));
+  }
+}
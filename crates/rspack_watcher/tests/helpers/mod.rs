@@ -12,7 +12,7 @@ use std::{
 
 use rspack_paths::{ArcPath, Utf8PathBuf};
 use rspack_util::fx_hash::FxHashSet;
-use rspack_watcher::{EventAggregateHandler, EventHandler, FsWatcher};
+use rspack_watcher::{EventAggregateHandler, EventBatchStats, EventHandler, FsWatcher};
 use tempfile::TempDir;
 use tokio::sync::RwLock;
 
@@ -32,6 +32,7 @@ pub struct TestHelper {
 pub struct AggregatedEvent {
   pub changed_files: FxHashSet<String>,
   pub deleted_files: FxHashSet<String>,
+  pub stats: Option<EventBatchStats>,
 }
 
 impl AggregatedEvent {
@@ -163,6 +164,10 @@ impl TestHelper {
     .unwrap();
   }
 
+  pub fn dir(&self, name: &str) {
+    std::fs::create_dir_all(self.join(name)).unwrap();
+  }
+
   pub fn collect_events(
     &self,
     rx: Receiver<Event>,
@@ -250,7 +255,7 @@ impl TestHelper {
               paths_to_iter!(directories),
               paths_to_iter!(missing),
               SystemTime::now(),
-              Box::new(AggregateHandler(tx.clone())),
+              Box::new(AggregateHandler(tx.clone(), Default::default())),
               Box::new(ChangeHandler(tx)),
             )
             .await;
@@ -262,7 +267,7 @@ impl TestHelper {
       }
     });
 
-    struct AggregateHandler(Sender<Event>);
+    struct AggregateHandler(Sender<Event>, Mutex<Option<EventBatchStats>>);
 
     impl EventAggregateHandler for AggregateHandler {
       fn on_event_handle(
@@ -273,8 +278,13 @@ impl TestHelper {
         let _ = self.0.send(Event::Aggregated(AggregatedEvent {
           changed_files,
           deleted_files,
+          stats: self.1.lock().unwrap().take(),
         }));
       }
+
+      fn on_batch_stats(&self, stats: EventBatchStats) {
+        *self.1.lock().unwrap() = Some(stats);
+      }
     }
 
     struct ChangeHandler(Sender<Event>);
@@ -3,7 +3,7 @@
 use std::sync::atomic::AtomicU8;
 
 use rspack_paths::ArcPath;
-use rspack_watcher::{FsWatcher, FsWatcherOptions};
+use rspack_watcher::{FsWatcher, FsWatcherMode, FsWatcherOptions};
 
 mod helpers;
 
@@ -96,3 +96,101 @@ fn should_watch_a_single_file() {
     },
   );
 }
+
+#[test]
+fn should_poll_a_single_file() {
+  let mut helper = h!(FsWatcherOptions {
+    aggregate_timeout: Some(100),
+    poll_interval: Some(100),
+    mode: FsWatcherMode::Polling,
+    ..Default::default()
+  });
+  helper.file("a");
+
+  let rx = watch!(helper, "a");
+
+  helper.tick(|| {
+    helper.file("a");
+  });
+
+  let change_events = c!();
+  let aggregated_events = c!();
+  helper.collect_events(
+    rx,
+    |file, _| {
+      file.assert_path(helper.join("a"));
+      add!(change_events);
+    },
+    |changes, abort| {
+      changes.assert_changed(helper.join("a"));
+      assert!(load!(change_events) > 0);
+      add!(aggregated_events);
+      *abort = true;
+    },
+  );
+  assert_eq!(load!(aggregated_events), 1);
+}
+
+#[test]
+fn should_report_the_stats_of_a_batch() {
+  let mut helper = h!(FsWatcherOptions {
+    aggregate_timeout: Some(100),
+    ..Default::default()
+  });
+  helper.file("a");
+  helper.file("b");
+
+  let rx = watch!(helper, "a", "b");
+
+  helper.tick(|| {
+    helper.file("a");
+    helper.file("a");
+    helper.file("b");
+  });
+
+  let aggregated_events = c!();
+  helper.collect_events(
+    rx,
+    |_, _| {},
+    |changes, abort| {
+      let stats = changes.stats.expect("should report stats");
+      assert_eq!(stats.changed_files, changes.changed_files.len());
+      assert_eq!(stats.deleted_files, changes.deleted_files.len());
+      assert!(stats.raw_events >= stats.changed_files + stats.deleted_files);
+      assert!(stats.aggregate_wait >= std::time::Duration::from_millis(100));
+      assert!(stats.latency >= stats.aggregate_wait);
+      add!(aggregated_events);
+      *abort = true;
+    },
+  );
+  assert_eq!(load!(aggregated_events), 1);
+}
+
+#[test]
+fn should_poll_the_entries_of_a_directory() {
+  let mut helper = h!(FsWatcherOptions {
+    aggregate_timeout: Some(100),
+    poll_interval: Some(100),
+    mode: FsWatcherMode::Polling,
+    ..Default::default()
+  });
+  helper.dir("dir");
+
+  let rx = watch!(dirs @ helper, "dir");
+
+  helper.tick(|| {
+    helper.file("dir/a");
+  });
+
+  let aggregated_events = c!();
+  helper.collect_events(
+    rx,
+    |_, _| {},
+    |changes, abort| {
+      changes.assert_changed(helper.join("dir"));
+      add!(aggregated_events);
+      *abort = true;
+    },
+  );
+  assert_eq!(load!(aggregated_events), 1);
+}
@@ -177,6 +177,7 @@ export interface KnownNormalizedStatsOptions {
   usedExports: boolean;
   providedExports: boolean;
   optimizationBailout: boolean;
+  profile: boolean;
   depth: boolean;
   assets: boolean;
   chunks: boolean;
@@ -361,11 +361,11 @@ class Compiler {
   }
 
   get recordsInputPath() {
-    return unsupported('Compiler.recordsInputPath');
+    return this.options.recordsInputPath || null;
   }
 
   get recordsOutputPath() {
-    return unsupported('Compiler.recordsOutputPath');
+    return this.options.recordsOutputPath || null;
   }
 
   get managedPaths() {
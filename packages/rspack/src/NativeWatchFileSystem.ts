@@ -175,7 +175,9 @@ export default class NativeWatchFileSystem implements WatchFileSystem {
     const nativeWatcherOptions: binding.NativeWatcherOptions = {
       followSymlinks: options.followSymlinks,
       aggregateTimeout: options.aggregateTimeout,
-      pollInterval: typeof options.poll === 'boolean' ? 0 : options.poll,
+      pollInterval: typeof options.poll === 'number' ? options.poll : undefined,
+      // native events never arrive in some file systems, e.g. Docker bind mounts and NFS
+      mode: options.poll ? 'polling' : undefined,
       ignored: toJsWatcherIgnored(options.ignored),
     };
     const nativeWatcher = new binding.NativeWatcher(nativeWatcherOptions);
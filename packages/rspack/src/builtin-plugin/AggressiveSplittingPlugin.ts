@@ -0,0 +1,44 @@
+import {
+  type BuiltinPlugin,
+  BuiltinPluginName,
+  type RawAggressiveSplittingPluginOptions,
+  type RawSplitChunkSizes,
+} from '@rspack/binding';
+import { createBuiltinPlugin, RspackBuiltinPlugin } from './base';
+
+export type AggressiveSplittingPluginOptions = {
+  /**
+   * The minimum size of the chunks created by splitting, in bytes.
+   * @default 30 * 1024
+   */
+  minSize?: number | Record<string, number>;
+  /**
+   * The size of the chunks to be split, in bytes.
+   * @default 50 * 1024
+   */
+  maxSize?: number | Record<string, number>;
+};
+
+const toRawSizes = (
+  sizes?: number | Record<string, number>,
+): number | RawSplitChunkSizes | undefined =>
+  typeof sizes === 'object' ? { sizes } : sizes;
+
+export class AggressiveSplittingPlugin extends RspackBuiltinPlugin {
+  name = BuiltinPluginName.AggressiveSplittingPlugin;
+  affectedHooks = 'compilation' as const;
+
+  constructor(private options: AggressiveSplittingPluginOptions = {}) {
+    super();
+  }
+
+  raw(): BuiltinPlugin {
+    const { minSize, maxSize } = this.options;
+    const rawOptions: RawAggressiveSplittingPluginOptions = {
+      minSize: toRawSizes(minSize),
+      maxSize: toRawSizes(maxSize),
+      defaultSizeTypes: ['javascript', 'unknown'],
+    };
+    return createBuiltinPlugin(this.name, rawOptions);
+  }
+}
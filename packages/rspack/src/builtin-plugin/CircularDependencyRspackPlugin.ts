@@ -1,3 +1,4 @@
+import path from 'node:path';
 import {
   type BuiltinPlugin,
   BuiltinPluginName,
@@ -32,6 +33,45 @@ export type CircularDependencyRspackPluginOptions = {
    * it is tested against the entire identifier.
    */
   ignoredConnections?: [string | RegExp, string | RegExp][];
+  /**
+   * How the cycles are reported.
+   * - `'cycles'`: report every cycle reachable from the entry modules.
+   * - `'components'`: report every strongly connected component once, with
+   *   its size and a shortest cycle through it. This is much faster on large
+   *   tangled graphs, where the number of cycles explodes. Ignored connections
+   *   split the components, and a component whose every cycle goes through an
+   *   ignored connection is passed to `onIgnored` with a shortest cycle.
+   * @default 'cycles'
+   */
+  mode?: 'cycles' | 'components';
+  /**
+   * When `true`, only static ESM imports, which are evaluated when the module
+   * is initialized, count for creating cycles. Dynamic `import()`, `require()`
+   * and type-only imports are ignored.
+   * @default false
+   */
+  initOnly?: boolean;
+  /**
+   * The maximum number of cycles to find, including the ignored ones. The
+   * detection stops once it is reached, and a warning is added to the
+   * compilation.
+   */
+  maxCycles?: number;
+  /**
+   * The path of a JSON file recording the known cycles by the relative paths
+   * of their modules. Cycles in the baseline are not reported, any other
+   * detected cycle is reported as an error, and a warning lists the cycles in
+   * the baseline which are no longer detected. The file is created with the
+   * detected cycles if it does not exist. Relative paths are resolved against
+   * the context.
+   */
+  baseline?: string;
+  /**
+   * When `true`, the baseline file is rewritten with the detected cycles
+   * instead of being checked.
+   * @default false
+   */
+  updateBaseline?: boolean;
   /**
    * Called once for every detected cycle. Providing this handler overrides the
    * default behavior of adding diagnostics to the compilation.
@@ -70,12 +110,29 @@ export class CircularDependencyRspackPlugin extends RspackBuiltinPlugin {
   }
 
   raw(compiler: Compiler): BuiltinPlugin {
-    const { failOnError, exclude, ignoredConnections } = this._options;
+    const {
+      failOnError,
+      exclude,
+      ignoredConnections,
+      mode,
+      initOnly,
+      maxCycles,
+      baseline,
+      updateBaseline,
+    } = this._options;
 
     const rawOptions: RawCircularDependencyRspackPluginOptions = {
       failOnError,
       exclude,
       ignoredConnections,
+      mode,
+      initOnly,
+      maxCycles,
+      baselinePath:
+        baseline === undefined
+          ? undefined
+          : path.resolve(compiler.context, baseline),
+      updateBaseline,
       onDetected: this._options.onDetected
         ? (entripoint: Module, modules: string[]) => {
             const compilation: Compilation =
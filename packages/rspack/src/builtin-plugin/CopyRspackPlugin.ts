@@ -35,6 +35,7 @@ export const CopyRspackPlugin = create(
       const originalTransform = pattern.transform;
       if (originalTransform) {
         if (typeof originalTransform === 'object') {
+          pattern.cache ??= originalTransform.cache;
           pattern.transform = (input, absoluteFilename) =>
             Promise.resolve(
               originalTransform.transformer(input, absoluteFilename),
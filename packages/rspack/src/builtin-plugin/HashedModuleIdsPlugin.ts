@@ -0,0 +1,37 @@
+import {
+  BuiltinPluginName,
+  type RawHashedModuleIdsPluginOptions,
+} from '@rspack/binding';
+
+import { create } from './base';
+
+export type HashedModuleIdsPluginOptions = {
+  /**
+   * The context directory to make the identifiers of modules relative to.
+   * @default compiler.context
+   */
+  context?: string;
+  /**
+   * The hash function to hash the identifiers of modules.
+   * @default 'md4'
+   */
+  hashFunction?: 'md4' | 'xxhash64' | 'xxhash128' | 'sha256' | 'blake3';
+  /**
+   * The encoding of the hash.
+   * @default 'base64'
+   */
+  hashDigest?: 'base64' | 'base64url' | 'hex' | 'base36' | 'base62';
+  /**
+   * The prefix length of the hash used as the module id, which is extended on conflicts.
+   * @default 4
+   */
+  hashDigestLength?: number;
+};
+
+export const HashedModuleIdsPlugin = create(
+  BuiltinPluginName.HashedModuleIdsPlugin,
+  (
+    options: HashedModuleIdsPluginOptions = {},
+  ): RawHashedModuleIdsPluginOptions => ({ ...options }),
+  'compilation',
+);
@@ -0,0 +1,12 @@
+import {
+  BuiltinPluginName,
+  type RawRecordIdsPluginOptions,
+} from '@rspack/binding';
+
+import { create } from './base';
+
+export const RecordIdsPlugin = create(
+  BuiltinPluginName.RecordIdsPlugin,
+  (options: RawRecordIdsPluginOptions) => ({ ...options }),
+  'compilation',
+);
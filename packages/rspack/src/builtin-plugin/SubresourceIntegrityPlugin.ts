@@ -16,6 +16,7 @@ const require = createRequire(import.meta.url);
 
 const PLUGIN_NAME = 'SubresourceIntegrityPlugin';
 const NATIVE_HTML_PLUGIN = 'HtmlRspackPlugin';
+const DEFAULT_MANIFEST_FILENAME = 'integrity-manifest.json';
 
 const HTTP_PROTOCOL_REGEX = /^https?:/;
 
@@ -66,6 +67,7 @@ export type SubresourceIntegrityPluginOptions = {
   ];
   htmlPlugin?: string | false;
   enabled?: 'auto' | boolean;
+  manifest?: boolean | string;
 };
 
 export type NativeSubresourceIntegrityPluginOptions = Omit<
@@ -96,6 +98,7 @@ const NativeSubresourceIntegrityPlugin = create(
       hashFuncNames: options.hashFuncNames,
       htmlPlugin,
       integrityCallback: options.integrityCallback,
+      manifest: options.manifest,
     };
   },
 );
@@ -113,6 +116,10 @@ export class SubresourceIntegrityPlugin extends NativeSubresourceIntegrityPlugin
       hashFuncNames: options.hashFuncNames ?? ['sha384'],
       htmlPlugin: options.htmlPlugin ?? NATIVE_HTML_PLUGIN,
       enabled: options.enabled ?? 'auto',
+      manifest:
+        options.manifest === true
+          ? DEFAULT_MANIFEST_FILENAME
+          : options.manifest || undefined,
     };
     super({
       ...finalOptions,
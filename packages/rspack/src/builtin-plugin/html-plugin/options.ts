@@ -69,9 +69,48 @@ export type HtmlRspackPluginOptions = {
 
   /**
    * Allows to control how chunks should be sorted before they are included to the HTML.
+   * - `'manual'`: follow the order of `chunks`.
+   * - `'dependency'`: chunks of an entry are injected after the chunks of the entries it depends on.
+   * - a function receiving the included entry names and returning them in injection order.
    * @default "auto"
    */
-  chunksSortMode?: 'auto' | 'manual';
+  chunksSortMode?:
+    | 'auto'
+    | 'manual'
+    | 'dependency'
+    | ((chunkNames: string[]) => string[]);
+
+  /**
+   * Generate resource hints for the async chunks of the included entries.
+   * `preload` links are generated for chunks imported with `webpackPreload` (`modulepreload` when `scriptLoading` is `'module'`),
+   * `prefetch` links are generated for all async chunks when `prefetch` is enabled.
+   */
+  resourceHints?: {
+    /** @default true */
+    preload?: boolean;
+    /** @default false */
+    prefetch?: boolean;
+    /** Only generate hints for the matched assets. */
+    include?: string | RegExp | (string | RegExp)[];
+    /** Skip hints for the matched assets. */
+    exclude?: string | RegExp | (string | RegExp)[];
+  };
+
+  /**
+   * Inline the matched JS and CSS files of the included entries into the HTML.
+   * The inlined files are removed from the output when no other asset references them.
+   */
+  inline?: {
+    /**
+     * Inline the runtime chunks.
+     * @default false
+     */
+    runtimeChunk?: boolean;
+    /** Inline the CSS files not larger than this size in bytes. */
+    cssSizeLimit?: number;
+    /** Inline the matched files. */
+    test?: string | RegExp | (string | RegExp)[];
+  };
 
   /**
    * Controls whether to minify the output, disabled by default.
@@ -210,6 +210,8 @@ const HtmlRspackPluginImpl = create(
       chunks: c.chunks,
       excludeChunks: c.excludeChunks,
       chunksSortMode,
+      resourceHints: c.resourceHints,
+      inline: c.inline,
       minify: c.minify,
       meta,
       scriptLoading,
@@ -1,4 +1,5 @@
 export * from './APIPlugin';
+export * from './AggressiveSplittingPlugin';
 export * from './ArrayPushCallbackChunkFormatPlugin';
 export * from './AssetModulesPlugin';
 export * from './AsyncWebAssemblyModulesPlugin';
@@ -35,6 +36,7 @@ export * from './FetchCompileAsyncWasmPlugin';
 export * from './FileUriPlugin';
 export * from './FlagDependencyExportsPlugin';
 export * from './FlagDependencyUsagePlugin';
+export * from './HashedModuleIdsPlugin';
 export * from './HotModuleReplacementPlugin';
 export * from './HttpExternalsRspackPlugin';
 export * from './HttpUriPlugin';
@@ -66,6 +68,7 @@ export * from './OccurrenceChunkIdsPlugin';
 export * from './ProgressPlugin';
 export * from './ProvidePlugin';
 export * from './RealContentHashPlugin';
+export * from './RecordIdsPlugin';
 export * from './RemoveDuplicateModulesPlugin';
 export * from './RemoveEmptyChunksPlugin';
 export * from './RsdoctorPlugin';
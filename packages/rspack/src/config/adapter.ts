@@ -105,6 +105,7 @@ export const getRawOptions = (
     node: getRawNode(options.node),
     amd: options.amd ? JSON.stringify(options.amd || {}) : undefined,
     bail: options.bail!,
+    profile: options.profile!,
     __references: {},
   };
 };
@@ -90,6 +90,9 @@ export const applyRspackOptionsDefaults = (
   D(options, 'lazyCompilation', false);
   // IGNORE(bail): bail is default to false in webpack, but it's set in `Compilation`
   D(options, 'bail', false);
+  D(options, 'profile', false);
+  D(options, 'recordsInputPath', false);
+  D(options, 'recordsOutputPath', false);
 
   // IGNORE(cache): cache is default to { type: "memory" } in webpack when the mode is development,
   // but Rspack currently does not support this option
@@ -70,7 +70,10 @@ import type {
   Path,
   Performance,
   Plugins,
+  Profile,
   PublicPath,
+  RecordsInputPath,
+  RecordsOutputPath,
   Resolve,
   RspackOptions,
   RuleSetRules,
@@ -299,9 +302,18 @@ export const getNormalizedRspackOptions = (
             config.context || process.cwd(),
             cache.storage?.directory || 'node_modules/.cache/rspack',
           ),
+          compression: cache.storage?.compression,
+          maxSize: cache.storage?.maxSize,
+          seedDirectory:
+            cache.storage?.seedDirectory &&
+            path.resolve(
+              config.context || process.cwd(),
+              cache.storage.seedDirectory,
+            ),
         },
         portable: cache.portable,
         readonly: cache.readonly,
+        explain: cache.explain,
       };
     }),
     stats: nestedConfig(config.stats, (stats) => {
@@ -357,6 +369,15 @@ export const getNormalizedRspackOptions = (
     devServer: config.devServer,
     amd: config.amd,
     bail: config.bail,
+    profile: config.profile,
+    recordsInputPath:
+      config.recordsInputPath !== undefined
+        ? config.recordsInputPath
+        : config.recordsPath,
+    recordsOutputPath:
+      config.recordsOutputPath !== undefined
+        ? config.recordsOutputPath
+        : config.recordsPath,
     lazyCompilation: optionalNestedConfig(config.lazyCompilation, (options) =>
       options === true ? {} : options,
     ),
@@ -607,8 +628,12 @@ export type CacheNormalized =
       storage: {
         type: 'filesystem';
         directory: string;
+        compression?: 'zstd' | 'lz4';
+        maxSize?: number;
+        seedDirectory?: string;
       };
       portable?: boolean;
+      explain?: boolean;
     };
 
 export interface ExperimentsNormalized {
@@ -665,4 +690,7 @@ export interface RspackOptionsNormalized {
   performance?: Performance;
   amd?: Amd;
   bail?: Bail;
+  profile?: Profile;
+  recordsInputPath?: RecordsInputPath;
+  recordsOutputPath?: RecordsOutputPath;
 }
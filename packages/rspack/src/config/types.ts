@@ -350,7 +350,12 @@ export type HashDigest = string;
 export type HashDigestLength = number;
 
 /** The hashing algorithm to use. */
-export type HashFunction = 'md4' | 'xxhash64' | 'sha256';
+export type HashFunction =
+  | 'md4'
+  | 'xxhash64'
+  | 'xxhash128'
+  | 'sha256'
+  | 'blake3';
 
 /** An optional salt to update the hash. */
 export type HashSalt = string;
@@ -1829,6 +1834,22 @@ export type CacheStorageOptions = {
    * @default 'node_modules/.cache/rspack'
    */
   directory?: string;
+  /**
+   * Compress the contents of cache pack files, smaller cache directories at the cost of some CPU time.
+   * @default undefined
+   */
+  compression?: 'zstd' | 'lz4';
+  /**
+   * Max total size of the cache directory in bytes, the caches of the least recently used versions will be removed once exceeded.
+   * @default undefined
+   */
+  maxSize?: number;
+  /**
+   * Read-only cache directory to start from when the cache directory is empty, e.g. a cache directory produced by CI.
+   * @description The seed is validated by the build dependencies and snapshot as the local cache, and it is never written.
+   * @default undefined
+   */
+  seedDirectory?: string;
 };
 
 /**
@@ -1869,6 +1890,12 @@ export type PersistentCacheOptions = {
    * @default false
    */
   readonly?: boolean;
+  /**
+   * Log why the cache was invalidated. When enabled, each invalidated path is logged with the strategy that decided it.
+   * @description This is useful to find non-deterministic inputs that cause unexpected cache misses.
+   * @default false
+   */
+  explain?: boolean;
 };
 
 /**
@@ -2119,6 +2146,12 @@ export type StatsOptions = {
    * @default false
    */
   optimizationBailout?: boolean;
+  /**
+   * Enables or disables the display of the time spent on modules and loaders,
+   * only available when the `profile` option is enabled.
+   * @default false
+   */
+  profile?: boolean;
   /**
    * Enables or disables grouping of modules by type.
    */
@@ -2973,6 +3006,36 @@ export type Amd = false | Record<string, any>;
 export type Bail = boolean;
 //#endregion
 
+//#region Profile
+/**
+ * Collect the time spent on each module, including resolving, loaders, parsing,
+ * code generation and hashing. The result is available in the `profile` section of stats.
+ * @default false
+ * */
+export type Profile = boolean;
+//#endregion
+
+//#region Records
+/**
+ * The file to read and write the module and chunk ids, which keeps the ids stable across builds.
+ * Relative paths are resolved against `context`.
+ * @default false
+ * */
+export type RecordsPath = false | string;
+
+/**
+ * The file to read the module and chunk ids from, defaults to `recordsPath`.
+ * @default false
+ * */
+export type RecordsInputPath = false | string;
+
+/**
+ * The file to write the module and chunk ids to, defaults to `recordsPath`.
+ * @default false
+ * */
+export type RecordsOutputPath = false | string;
+//#endregion
+
 //#region Performance
 /** Options to control how Rspack notifies you of assets and entry points that exceed a specific file limit.   */
 export type Performance =
@@ -2996,6 +3059,18 @@ export type Performance =
        * @default 250000
        */
       maxEntrypointSize?: number;
+      /**
+       * Total size of the assets loaded by an async chunk group, e.g. an `import()` (in bytes).
+       */
+      maxAsyncChunkGroupSize?: number;
+      /**
+       * Size limits (in bytes) for the assets matching a glob, the first match takes precedence over `maxAssetSize`.
+       */
+      assetSizeLimits?: { test: string; maxSize: number }[];
+      /**
+       * Compare the compressed size of assets instead of the raw size against the limits.
+       */
+      compression?: 'gzip' | 'brotli' | 'zstd';
     };
 //#endregion
 
@@ -3133,6 +3208,22 @@ export type RspackOptions = {
    * Whether to fail on the first error.
    */
   bail?: Bail;
+  /**
+   * Whether to collect the time spent on each module.
+   */
+  profile?: Profile;
+  /**
+   * The file to read and write the module and chunk ids.
+   */
+  recordsPath?: RecordsPath;
+  /**
+   * The file to read the module and chunk ids from.
+   */
+  recordsInputPath?: RecordsInputPath;
+  /**
+   * The file to write the module and chunk ids to.
+   */
+  recordsOutputPath?: RecordsOutputPath;
   /**
    * Performance optimization options.
    */
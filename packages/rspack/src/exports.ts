@@ -216,6 +216,7 @@ interface Webworker {
 export const webworker: Webworker = { WebWorkerTemplatePlugin };
 
 import {
+  AggressiveSplittingPlugin,
   CssChunkingPlugin,
   LimitChunkCountPlugin,
   RemoveDuplicateModulesPlugin,
@@ -226,18 +227,32 @@ import {
   SplitChunksPlugin,
 } from './builtin-plugin';
 
+export type { AggressiveSplittingPluginOptions } from './builtin-plugin';
+
 interface Optimize {
+  AggressiveSplittingPlugin: typeof AggressiveSplittingPlugin;
   LimitChunkCountPlugin: typeof LimitChunkCountPlugin;
   RuntimeChunkPlugin: typeof RuntimeChunkPlugin;
   SplitChunksPlugin: typeof SplitChunksPlugin;
 }
 
 export const optimize: Optimize = {
+  AggressiveSplittingPlugin,
   LimitChunkCountPlugin,
   RuntimeChunkPlugin,
   SplitChunksPlugin,
 };
 
+import { HashedModuleIdsPlugin } from './builtin-plugin';
+
+export type { HashedModuleIdsPluginOptions } from './builtin-plugin';
+
+interface Ids {
+  HashedModuleIdsPlugin: typeof HashedModuleIdsPlugin;
+}
+
+export const ids: Ids = { HashedModuleIdsPlugin };
+
 import { ModuleFederationPlugin } from './container/ModuleFederationPlugin';
 
 export type { ModuleFederationPluginOptions } from './container/ModuleFederationPlugin';
@@ -8,6 +8,7 @@
  * https://github.com/webpack/webpack/blob/main/LICENSE
  */
 import fs from 'node:fs';
+import path from 'node:path';
 
 import type {
   Compiler,
@@ -59,6 +60,7 @@ import {
   NoEmitOnErrorsPlugin,
   OccurrenceChunkIdsPlugin,
   RealContentHashPlugin,
+  RecordIdsPlugin,
   RemoveEmptyChunksPlugin,
   RuntimeChunkPlugin,
   RuntimePlugin,
@@ -383,6 +385,17 @@ export class RspackOptionsApply {
           throw new Error(`chunkIds: ${chunkIds} is not implemented`);
       }
     }
+    const { recordsInputPath, recordsOutputPath } = options;
+    if (recordsInputPath || recordsOutputPath) {
+      new RecordIdsPlugin({
+        inputPath: recordsInputPath
+          ? path.resolve(options.context, recordsInputPath)
+          : undefined,
+        outputPath: recordsOutputPath
+          ? path.resolve(options.context, recordsOutputPath)
+          : undefined,
+      }).apply(compiler);
+    }
     if (options.optimization.nodeEnv) {
       new DefinePlugin({
         'process.env.NODE_ENV': JSON.stringify(options.optimization.nodeEnv),
@@ -868,6 +868,12 @@ const SIMPLE_EXTRACTORS: SimpleExtractors = {
       const statsCompilation = context.getStatsCompilation(compilation);
       object.hash = statsCompilation.hash;
     },
+    profile: (object, compilation, context: KnownStatsFactoryContext) => {
+      const statsCompilation = context.getStatsCompilation(compilation);
+      if (statsCompilation.profile) {
+        object.profile = statsCompilation.profile;
+      }
+    },
     version: (object) => {
       object.version = WEBPACK_VERSION;
       object.rspackVersion = RSPACK_VERSION;
@@ -1282,6 +1288,9 @@ const SIMPLE_EXTRACTORS: SimpleExtractors = {
       object.failed = commonAttributes.failed;
       object.errors = commonAttributes.errors;
       object.warnings = commonAttributes.warnings;
+      if (module.profile) {
+        object.profile = module.profile;
+      }
     },
     ids: (object, module) => {
       const { commonAttributes } = module;
@@ -227,6 +227,7 @@ const DEFAULTS: StatsDefault = {
   usedExports: OFF_FOR_TO_STRING,
   providedExports: OFF_FOR_TO_STRING,
   optimizationBailout: OFF_FOR_TO_STRING,
+  profile: OFF_FOR_TO_STRING,
   children: OFF_FOR_TO_STRING,
   source: NORMAL_OFF,
   moduleTrace: NORMAL_ON,
@@ -133,12 +133,41 @@ export type KnownStatsModule = {
   modules?: StatsModule[];
   filteredModules?: number;
   source?: string | Buffer;
+  profile?: StatsProfile;
 };
 
+/**
+ * Time spent on a module in milliseconds, only available when `profile` is enabled.
+ */
 export type KnownStatsProfile = {
   total: number;
   resolving: number;
+  factory: number;
   building: number;
+  loaders: StatsLoaderProfile[];
+  parsing: number;
+  codeGeneration: number;
+  hashing: number;
+};
+
+export type StatsProfile = KnownStatsProfile & Record<string, any>;
+
+export type StatsLoaderProfile = {
+  loader: string;
+  pitch: number;
+  normal: number;
+};
+
+/**
+ * Modules and loaders sorted by the time spent on them, only available when `profile` is enabled.
+ */
+export type StatsCompilationProfile = {
+  modules: {
+    identifier: string;
+    name: string;
+    profile: StatsProfile;
+  }[];
+  loaders: StatsLoaderProfile[];
 };
 
 export type StatsModule = KnownStatsModule & Record<string, any>;
@@ -283,6 +312,7 @@ export type KnownStatsCompilation = {
   filteredModules?: number;
   children?: StatsCompilation[];
   logging?: Record<string, StatsLogging>;
+  profile?: StatsCompilationProfile;
 
   // TODO: not aligned with webpack
   // env?: any;
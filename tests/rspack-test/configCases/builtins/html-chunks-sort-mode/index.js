@@ -0,0 +1,22 @@
+import vendor from "./vendor";
+
+const fs = require("fs");
+const path = require("path");
+
+function getScripts(file) {
+	const htmlContent = fs.readFileSync(path.join(__dirname, file), "utf-8");
+	return Array.from(htmlContent.matchAll(/<script [^>]*src="\/?([^"]+)"/g), match => match[1]);
+}
+
+it("should inject chunks after the chunks of their parent chunk groups", () => {
+	expect(vendor).toBe("vendor");
+	expect(getScripts("dependency.html")).toEqual(["runtime.js", "vendor.js", "main.js"]);
+});
+
+it("should inject chunks in the order returned by the sort function", () => {
+	expect(getScripts("function.html")).toEqual(["runtime.js", "vendor.js", "main.js"]);
+});
+
+it("should keep the entry order by default", () => {
+	expect(getScripts("auto.html")).toEqual(["main.js", "runtime.js", "vendor.js"]);
+});
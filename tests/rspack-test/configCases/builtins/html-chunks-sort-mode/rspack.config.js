@@ -0,0 +1,28 @@
+const { rspack } = require("@rspack/core");
+
+/**@type {import('@rspack/core').Configuration} */
+module.exports = {
+	entry: {
+		main: { import: "./index.js", dependOn: "vendor" },
+		vendor: "./vendor.js"
+	},
+	output: {
+		filename: "[name].js"
+	},
+	optimization: {
+		runtimeChunk: "single"
+	},
+	plugins: [
+		new rspack.HtmlRspackPlugin({
+			filename: "auto.html"
+		}),
+		new rspack.HtmlRspackPlugin({
+			filename: "dependency.html",
+			chunksSortMode: "dependency"
+		}),
+		new rspack.HtmlRspackPlugin({
+			filename: "function.html",
+			chunksSortMode: chunkNames => chunkNames.slice().reverse()
+		})
+	]
+};
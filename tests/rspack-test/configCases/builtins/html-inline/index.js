@@ -0,0 +1,21 @@
+const fs = require("fs");
+const path = require("path");
+
+it("should inline the runtime chunk and small css files", () => {
+	const htmlContent = fs.readFileSync(path.join(__dirname, "inline.html"), "utf-8");
+	expect(htmlContent).toMatch(/<script>[^]*__INLINED_ENTRY__[^]*<\/script>/);
+	expect(htmlContent).toMatch(/<style>[^]*inlined-style[^]*<\/style>/);
+	expect(htmlContent).not.toMatch(/<script [^>]*src=/);
+	expect(htmlContent).not.toMatch(/<link [^>]*rel="stylesheet"/);
+});
+
+it("should remove the inlined assets that are not referenced", () => {
+	// mentioning the file names here doesn't keep them
+	expect(fs.existsSync(path.join(__dirname, "other.js"))).toBe(false);
+	expect(fs.existsSync(path.join(__dirname, "other.css"))).toBe(false);
+});
+
+it("should keep the assets referenced by other html files", () => {
+	const htmlContent = fs.readFileSync(path.join(__dirname, "index.html"), "utf-8");
+	expect(htmlContent).toMatch(/<script [^>]*src="\/?main\.js"/);
+});
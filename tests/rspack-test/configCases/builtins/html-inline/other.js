@@ -0,0 +1,3 @@
+import "./other.css";
+
+window.__INLINED_ENTRY__ = true;
@@ -0,0 +1,34 @@
+const { rspack } = require("@rspack/core");
+
+/**@type {import('@rspack/core').Configuration} */
+module.exports = {
+	entry: {
+		main: "./index.js",
+		other: "./other.js"
+	},
+	output: {
+		filename: "[name].js"
+	},
+	module: {
+		rules: [
+			{
+				test: /\.css$/,
+				type: "css/auto"
+			}
+		]
+	},
+	plugins: [
+		new rspack.HtmlRspackPlugin({
+			filename: "inline.html",
+			chunks: ["other"],
+			inline: {
+				runtimeChunk: true,
+				cssSizeLimit: 1024
+			}
+		}),
+		new rspack.HtmlRspackPlugin({
+			filename: "index.html",
+			chunks: ["main"]
+		})
+	]
+};
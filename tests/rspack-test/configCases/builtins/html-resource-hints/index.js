@@ -0,0 +1,27 @@
+const fs = require("fs");
+const path = require("path");
+
+export function load() {
+	return Promise.all([
+		import(/* webpackChunkName: "preloaded", webpackPreload: true */ "./preloaded"),
+		import(/* webpackChunkName: "prefetched" */ "./prefetched"),
+		import(/* webpackChunkName: "excluded" */ "./excluded")
+	]);
+}
+
+it("should generate resource hints for async chunks", () => {
+	const htmlPath = path.join(__dirname, "./index.html");
+	const htmlContent = fs.readFileSync(htmlPath, "utf-8");
+	expect(htmlContent).toMatch(
+		/<link as="script" crossorigin="anonymous" href="\/?preloaded\.js" rel="preload">/
+	);
+	expect(htmlContent).toMatch(
+		/<link as="script" crossorigin="anonymous" href="\/?prefetched\.js" rel="prefetch">/
+	);
+	expect(htmlContent).toMatch(
+		/<link as="script" crossorigin="anonymous" href="\/?nested\.js" rel="prefetch">/
+	);
+	expect(htmlContent).not.toContain("excluded.js");
+	// files injected by the entry don't need hints
+	expect(htmlContent).not.toMatch(/href="\/?main\.js"/);
+});
@@ -0,0 +1,5 @@
+export default "prefetched";
+
+export function load() {
+	return import(/* webpackChunkName: "nested" */ "./nested");
+}
@@ -0,0 +1,22 @@
+const { rspack } = require("@rspack/core");
+
+/**@type {import('@rspack/core').Configuration} */
+module.exports = {
+	entry: {
+		main: "./index.js"
+	},
+	output: {
+		filename: "[name].js",
+		chunkFilename: "[name].js",
+		crossOriginLoading: "anonymous"
+	},
+	plugins: [
+		new rspack.HtmlRspackPlugin({
+			resourceHints: {
+				preload: true,
+				prefetch: true,
+				exclude: /excluded/
+			}
+		})
+	]
+};
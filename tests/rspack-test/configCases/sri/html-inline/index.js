@@ -0,0 +1,21 @@
+const fs = require("fs");
+const path = require("path");
+
+it("should add integrity to the referenced scripts only", () => {
+	const htmlContent = fs.readFileSync(path.join(__dirname, "index.html"), "utf-8");
+	expect(htmlContent).toMatch(/<script [^>]*integrity="sha384-[^"]+"[^>]*src="\/?main\.js"/);
+	expect(htmlContent).toMatch(/<script>[^]*__INLINED_ENTRY__[^]*<\/script>/);
+	expect(htmlContent).not.toMatch(/<script [^>]*src="\/?other/);
+});
+
+it("should inline the runtime with the resolved integrity of the async chunks", () => {
+	const htmlContent = fs.readFileSync(path.join(__dirname, "index.html"), "utf-8");
+	const inlinedScript = htmlContent.match(/<script>([^]*?__INLINED_ENTRY__[^]*?)<\/script>/)[1];
+	expect(inlinedScript).toMatch(/sha384-[A-Za-z0-9+\/=]+/);
+	expect(inlinedScript).not.toContain("CHUNK-SRI-HASH");
+});
+
+it("should remove the inlined assets", () => {
+	expect(fs.existsSync(path.join(__dirname, "other.js"))).toBe(false);
+	expect(fs.existsSync(path.join(__dirname, "async.js"))).toBe(true);
+});
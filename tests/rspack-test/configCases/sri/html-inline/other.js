@@ -0,0 +1,3 @@
+window.__INLINED_ENTRY__ = true;
+
+import(/* webpackChunkName: "async" */ "./async");
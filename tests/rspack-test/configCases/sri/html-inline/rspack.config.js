@@ -0,0 +1,29 @@
+const { rspack } = require("@rspack/core");
+
+/** @type {import("@rspack/core").Configuration} */
+module.exports = {
+	target: "web",
+	entry: {
+		main: "./index.js",
+		other: "./other.js"
+	},
+	output: {
+		filename: "[name].js",
+		crossOriginLoading: "anonymous"
+	},
+	externals: {
+		path: "require('path')",
+		fs: "require('fs')"
+	},
+	node: {
+		__dirname: false
+	},
+	plugins: [
+		new rspack.HtmlRspackPlugin({
+			inline: {
+				test: /^other/
+			}
+		}),
+		new rspack.SubresourceIntegrityPlugin()
+	]
+};
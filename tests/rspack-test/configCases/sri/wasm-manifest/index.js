@@ -0,0 +1,5 @@
+const loadWasm = () => import("./wasm.wat");
+
+it("should fetch async wasm with integrity", () => {
+  expect(typeof loadWasm).toBe("function");
+});
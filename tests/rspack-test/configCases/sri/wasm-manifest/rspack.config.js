@@ -0,0 +1,58 @@
+const { SubresourceIntegrityPlugin } = require("@rspack/core");
+const crypto = require("crypto");
+const fs = require("fs");
+const path = require("path");
+
+/** @type {import("@rspack/core").Configuration} */
+module.exports = (_, { testPath }) => ({
+  target: "web",
+  output: {
+    crossOriginLoading: "use-credentials",
+    webassemblyModuleFilename: "[id].[hash].wasm",
+  },
+  module: {
+    rules: [
+      {
+        test: /\.wat$/,
+        loader: "wast-loader",
+        type: "webassembly/async"
+      }
+    ]
+  },
+  experiments: {
+    asyncWebAssembly: true
+  },
+  plugins: [
+    new SubresourceIntegrityPlugin({
+      hashFuncNames: ["sha384"],
+      enabled: true,
+      manifest: true,
+    }),
+    {
+      apply(compiler) {
+        compiler.hooks.afterEmit.tap("AfterEmitPlugin", () => {
+          const content = fs.readFileSync(path.resolve(testPath, "bundle0.js"), "utf-8");
+          expect(content).toContain("sriWasmHashes");
+          expect(content).toMatch(/credentials:\s*"include"/);
+
+          const manifest = JSON.parse(
+            fs.readFileSync(path.resolve(testPath, "integrity-manifest.json"), "utf-8")
+          );
+          const wasmFile = Object.keys(manifest).find(file => file.endsWith(".wasm"));
+          expect(wasmFile).toBeDefined();
+          expect(Object.keys(manifest)).toContain("bundle0.js");
+          expect(Object.keys(manifest)).not.toContain("integrity-manifest.json");
+
+          for (const [file, integrity] of Object.entries(manifest)) {
+            const hash = crypto
+              .createHash("sha384")
+              .update(fs.readFileSync(path.resolve(testPath, file)))
+              .digest("base64");
+            expect(integrity).toBe(`sha384-${hash}`);
+          }
+          expect(content).toContain(manifest[wasmFile]);
+        });
+      },
+    }
+  ],
+});
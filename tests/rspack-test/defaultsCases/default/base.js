@@ -418,6 +418,9 @@ module.exports = {
 			  },
 			  performance: false,
 			  plugins: Array [],
+			  profile: false,
+			  recordsInputPath: false,
+			  recordsOutputPath: false,
 			  resolve: Object {
 			    aliasFields: Array [],
 			    byDependency: Object {
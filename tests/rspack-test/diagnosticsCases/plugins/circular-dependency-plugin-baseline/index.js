@@ -0,0 +1,2 @@
+import './known/a';
+import './fresh/a';
@@ -0,0 +1,10 @@
+const { CircularDependencyRspackPlugin } = require("@rspack/core");
+
+/** @type {import("@rspack/core").Configuration} */
+module.exports = {
+	plugins: [
+		new CircularDependencyRspackPlugin({
+			baseline: "baseline.json"
+		})
+	]
+};
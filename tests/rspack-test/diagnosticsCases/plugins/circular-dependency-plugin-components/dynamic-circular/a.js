@@ -0,0 +1 @@
+export default { name: 'a', dep: import('./b') };
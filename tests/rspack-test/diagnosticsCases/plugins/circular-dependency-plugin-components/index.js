@@ -0,0 +1,5 @@
+import './tangled/a';
+import './require-circular/a';
+import './dynamic-circular/a';
+import './ignored/a';
+import './uncounted/a';
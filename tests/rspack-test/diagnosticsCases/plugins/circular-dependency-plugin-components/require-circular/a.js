@@ -0,0 +1 @@
+module.exports = { name: 'a', dep: require('./b').name };
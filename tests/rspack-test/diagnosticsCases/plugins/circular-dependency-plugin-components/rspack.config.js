@@ -0,0 +1,25 @@
+const { CircularDependencyRspackPlugin } = require("@rspack/core");
+const ignoredFn = rstest.fn();
+
+/** @type {import("@rspack/core").Configuration} */
+module.exports = {
+	plugins: [
+		new CircularDependencyRspackPlugin({
+			mode: "components",
+			initOnly: true,
+			exclude: /ignored/,
+			maxCycles: 2,
+			onIgnored(_entrypoint, modules) {
+				ignoredFn(modules.map(module => module.slice(module.lastIndexOf("/") + 1)));
+			}
+		}),
+		{
+			apply(compiler) {
+				compiler.hooks.done.tap("done", () => {
+					expect(ignoredFn).toHaveBeenCalledTimes(1);
+					expect(ignoredFn).toHaveBeenCalledWith(["a.js", "b.js", "a.js"]);
+				});
+			}
+		}
+	]
+};
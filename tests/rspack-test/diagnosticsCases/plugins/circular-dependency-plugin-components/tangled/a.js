@@ -0,0 +1,4 @@
+import b from './b';
+import c from './c';
+
+export default { name: 'a', deps: [b && b.name, c && c.name] };
@@ -0,0 +1,4 @@
+import a from './a';
+import c from './c';
+
+export default { name: 'b', deps: [a && a.name, c && c.name] };
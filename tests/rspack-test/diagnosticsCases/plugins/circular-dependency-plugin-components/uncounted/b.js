@@ -0,0 +1,3 @@
+import a from './a';
+
+export default { name: 'b', dep: a && a.name };
@@ -17,5 +17,7 @@ module.exports = {
 			./fixtures/b.js 94 bytes [built] [code generated]
 			./fixtures/c.js 72 bytes [built] [code generated]
 		`);
+		expect(stats?.toJson({ all: false, profile: true }).profile).toBeDefined();
+		expect(stats?.toJson({ all: false, profile: false }).profile).toBeUndefined();
 	}
 };
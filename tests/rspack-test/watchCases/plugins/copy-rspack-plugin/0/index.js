@@ -0,0 +1,29 @@
+const fs = require("fs");
+const path = require("path");
+
+const read = (name, dir = "static") =>
+	fs.readFileSync(path.join(__dirname, dir, name), "utf-8");
+
+it("should only transform changed files when the transform is cached", () => {
+	switch (WATCH_STEP) {
+		case "0":
+			expect(read("a.txt")).toBe("a0|1");
+			expect(read("b.txt")).toBe("b0|1");
+			expect(read("a.txt", "uncached")).toBe("a0|1");
+			break;
+		case "1":
+			expect(read("a.txt")).toBe("a0|1");
+			expect(read("b.txt")).toBe("b1|2");
+			expect(read("c.txt")).toBe("c1|1");
+			// the transformer runs again without opt-in to the cache
+			expect(read("a.txt", "uncached")).toBe("a0|2");
+			break;
+		case "2":
+			// only the mtime of `a.txt` changes, the content hash is still the same
+			expect(read("a.txt")).toBe("a0|1");
+			expect(read("b.txt")).toBe("b1|2");
+			expect(read("c.txt")).toBe("c1|1");
+			expect(read("a.txt", "uncached")).toBe("a0|3");
+			break;
+	}
+});
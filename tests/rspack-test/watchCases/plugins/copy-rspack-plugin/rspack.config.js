@@ -0,0 +1,36 @@
+const path = require("path");
+const { rspack } = require("@rspack/core");
+
+/** @type {function(any, any): import("@rspack/core").Configuration} */
+module.exports = (env, { srcPath }) => {
+	const createTransformer = () => {
+		const transformCounts = new Map();
+		return (input, absoluteFilename) => {
+			const name = path.basename(absoluteFilename);
+			const count = (transformCounts.get(name) || 0) + 1;
+			transformCounts.set(name, count);
+			return `${input.toString().trim()}|${count}`;
+		};
+	};
+	return {
+		plugins: [
+			new rspack.CopyRspackPlugin({
+				patterns: [
+					{
+						from: path.resolve(srcPath, "static"),
+						to: "static",
+						transform: {
+							transformer: createTransformer(),
+							cache: true
+						}
+					},
+					{
+						from: path.resolve(srcPath, "static"),
+						to: "uncached",
+						transform: createTransformer()
+					}
+				]
+			})
+		]
+	};
+};